@@ -1,15 +1,100 @@
+use crate::application::services::account_service::AccountService;
+use crate::application::services::analytics_service::AnalyticsService;
+use crate::application::services::announcement_service::AnnouncementService;
+use crate::application::services::candle_service::CandleService;
+use crate::application::services::futures_service::FuturesService;
+use crate::application::services::liquidity_service::LiquidityService;
 use crate::application::services::monitoring_service::MonitoringService;
+use crate::application::services::order_book_service::OrderBookService;
+use crate::application::services::portfolio_service::PortfolioService;
+use crate::application::services::private_feed_service::PrivateFeedService;
+use crate::application::services::status_service::StatusService;
 use std::sync::Arc;
 pub struct JobFactory {
     monitoring_service: Arc<dyn MonitoringService>,
+    account_service: Arc<dyn AccountService>,
+    futures_service: Arc<dyn FuturesService>,
+    announcement_service: Arc<dyn AnnouncementService>,
+    status_service: Arc<dyn StatusService>,
+    private_feed_service: Arc<dyn PrivateFeedService>,
+    order_book_service: Arc<dyn OrderBookService>,
+    portfolio_service: Arc<dyn PortfolioService>,
+    analytics_service: Arc<dyn AnalyticsService>,
+    candle_service: Arc<dyn CandleService>,
+    liquidity_service: Arc<dyn LiquidityService>,
     exchange: String,
+    futures_exchange: String,
 }
 
 impl JobFactory {
-    pub fn new(monitoring_service: Arc<dyn MonitoringService>, exchange: String) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        monitoring_service: Arc<dyn MonitoringService>,
+        account_service: Arc<dyn AccountService>,
+        futures_service: Arc<dyn FuturesService>,
+        announcement_service: Arc<dyn AnnouncementService>,
+        status_service: Arc<dyn StatusService>,
+        private_feed_service: Arc<dyn PrivateFeedService>,
+        order_book_service: Arc<dyn OrderBookService>,
+        portfolio_service: Arc<dyn PortfolioService>,
+        analytics_service: Arc<dyn AnalyticsService>,
+        candle_service: Arc<dyn CandleService>,
+        liquidity_service: Arc<dyn LiquidityService>,
+        exchange: String,
+        futures_exchange: String,
+    ) -> Self {
         Self {
             monitoring_service,
+            account_service,
+            futures_service,
+            announcement_service,
+            status_service,
+            private_feed_service,
+            order_book_service,
+            portfolio_service,
+            analytics_service,
+            candle_service,
+            liquidity_service,
             exchange,
+            futures_exchange,
+        }
+    }
+
+    pub fn create_status_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.fetch_and_save_status(&exchange).await;
+                service
+                    .record_job_run("Status fetch", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_futures_status_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.status_service.clone();
+        let exchange = self.futures_exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.fetch_and_save_futures_status(&exchange).await;
+                service
+                    .record_job_run("Futures status fetch", &exchange, started_at, &result)
+                    .await;
+            })
         }
     }
 
@@ -17,15 +102,19 @@ impl JobFactory {
         &self,
     ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
         let service = self.monitoring_service.clone();
+        let status_service = self.status_service.clone();
         let exchange = self.exchange.clone();
 
         move || {
             let service = service.clone();
+            let status_service = status_service.clone();
             let exchange = exchange.clone();
             Box::pin(async move {
-                if let Err(e) = service.fetch_and_save_currencies(&exchange).await {
-                    tracing::error!("Currency fetch failed: {}", e);
-                }
+                let started_at = chrono::Utc::now();
+                let result = service.fetch_and_save_currencies(&exchange).await;
+                status_service
+                    .record_job_run("Currency fetch", &exchange, started_at, &result)
+                    .await;
             })
         }
     }
@@ -34,15 +123,40 @@ impl JobFactory {
         &self,
     ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
         let service = self.monitoring_service.clone();
+        let status_service = self.status_service.clone();
         let exchange = self.exchange.clone();
 
         move || {
             let service = service.clone();
+            let status_service = status_service.clone();
             let exchange = exchange.clone();
             Box::pin(async move {
-                if let Err(e) = service.fetch_and_save_symbols(&exchange).await {
-                    tracing::error!("Symbol fetch failed: {}", e);
-                }
+                let started_at = chrono::Utc::now();
+                let result = service.fetch_and_save_symbols(&exchange).await;
+                status_service
+                    .record_job_run("Symbol fetch", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_markets_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.monitoring_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.fetch_and_save_markets(&exchange).await;
+                status_service
+                    .record_job_run("Market fetch", &exchange, started_at, &result)
+                    .await;
             })
         }
     }
@@ -51,15 +165,610 @@ impl JobFactory {
         &self,
     ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
         let service = self.monitoring_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.fetch_and_save_tickers(&exchange).await;
+                status_service
+                    .record_job_run("Ticker fetch", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_watchlist_stats_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.monitoring_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.fetch_and_save_watchlist_stats(&exchange).await;
+                status_service
+                    .record_job_run("Watchlist stats fetch", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_margin_metadata_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.monitoring_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.fetch_and_save_margin_metadata(&exchange).await;
+                status_service
+                    .record_job_run("Margin metadata fetch", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_lending_rates_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.monitoring_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.fetch_and_save_lending_rates(&exchange).await;
+                status_service
+                    .record_job_run("Lending rates fetch", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_fiat_prices_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.monitoring_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.fetch_and_save_fiat_prices(&exchange).await;
+                status_service
+                    .record_job_run("Fiat prices fetch", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_deposits_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.account_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.sync_deposits(&exchange).await;
+                status_service
+                    .record_job_run("Deposit sync", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_withdrawals_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.account_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.sync_withdrawals(&exchange).await;
+                status_service
+                    .record_job_run("Withdrawal sync", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_fills_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.account_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.sync_fills(&exchange).await;
+                status_service
+                    .record_job_run("Fill sync", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_orders_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.account_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.sync_orders(&exchange).await;
+                status_service
+                    .record_job_run("Order sync", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_ledger_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.account_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.sync_ledger(&exchange).await;
+                status_service
+                    .record_job_run("Ledger sync", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_balances_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.account_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.snapshot_balances(&exchange).await;
+                status_service
+                    .record_job_run("Balance snapshot", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_reconciliation_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.account_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.reconcile_balances(&exchange).await;
+                status_service
+                    .record_job_run("Balance reconciliation", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_futures_contracts_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.futures_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.futures_exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.fetch_and_save_contracts(&exchange).await;
+                status_service
+                    .record_job_run("Futures contract fetch", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_funding_rates_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.futures_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.futures_exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.fetch_and_save_funding_rates(&exchange).await;
+                status_service
+                    .record_job_run("Funding rate fetch", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_mark_prices_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.futures_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.futures_exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.fetch_and_save_mark_prices(&exchange).await;
+                status_service
+                    .record_job_run("Mark price fetch", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_open_interest_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.futures_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.futures_exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.fetch_and_save_open_interest(&exchange).await;
+                status_service
+                    .record_job_run("Open interest fetch", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_announcements_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.announcement_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.sync_announcements(&exchange).await;
+                status_service
+                    .record_job_run("Announcement sync", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_private_feed_task(&self) -> futures::future::BoxFuture<'static, ()> {
+        let service = self.private_feed_service.clone();
+        let exchange = self.exchange.clone();
+
+        Box::pin(async move { service.run(&exchange).await })
+    }
+
+    pub fn create_order_book_task(&self) -> futures::future::BoxFuture<'static, ()> {
+        let service = self.order_book_service.clone();
+        let exchange = self.exchange.clone();
+
+        Box::pin(async move { service.run(&exchange).await })
+    }
+
+    pub fn create_trade_feed_task(&self) -> futures::future::BoxFuture<'static, ()> {
+        let service = self.candle_service.clone();
+        let exchange = self.exchange.clone();
+
+        Box::pin(async move { service.run_trade_feed(&exchange).await })
+    }
+
+    pub fn create_order_book_snapshots_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.order_book_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.save_snapshots(&exchange).await;
+                status_service
+                    .record_job_run("Order book snapshot", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_sub_accounts_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.portfolio_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.sync_sub_accounts(&exchange).await;
+                status_service
+                    .record_job_run("Sub-account sync", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_portfolio_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.portfolio_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.snapshot_portfolio(&exchange).await;
+                status_service
+                    .record_job_run("Portfolio snapshot", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_triangular_arbitrage_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.analytics_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.scan_triangular_arbitrage(&exchange).await;
+                status_service
+                    .record_job_run("Triangular arbitrage scan", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_candles_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.candle_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = match service.sync_candles(&exchange).await {
+                    Ok(()) => service.update_indicators(&exchange).await,
+                    Err(e) => Err(e),
+                };
+                status_service
+                    .record_job_run("Candles and indicators", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_risk_metrics_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.analytics_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.compute_risk_metrics(&exchange).await;
+                status_service
+                    .record_job_run("Volatility and correlation", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_liquidity_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.liquidity_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.score_symbols(&exchange).await;
+                status_service
+                    .record_job_run("Liquidity scoring", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_portfolio_valuation_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.portfolio_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.value_portfolio(&exchange).await;
+                status_service
+                    .record_job_run("Portfolio valuation", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_trades_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.candle_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.sync_trades(&exchange).await;
+                status_service
+                    .record_job_run("Trades sync", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_candle_aggregation_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.candle_service.clone();
+        let status_service = self.status_service.clone();
+        let exchange = self.exchange.clone();
+
+        move || {
+            let service = service.clone();
+            let status_service = status_service.clone();
+            let exchange = exchange.clone();
+            Box::pin(async move {
+                let started_at = chrono::Utc::now();
+                let result = service.aggregate_candles(&exchange).await;
+                status_service
+                    .record_job_run("Candle aggregation", &exchange, started_at, &result)
+                    .await;
+            })
+        }
+    }
+
+    pub fn create_candle_consistency_job(
+        &self,
+    ) -> impl Fn() -> futures::future::BoxFuture<'static, ()> + Send + Sync + Clone + 'static {
+        let service = self.candle_service.clone();
+        let status_service = self.status_service.clone();
         let exchange = self.exchange.clone();
 
         move || {
             let service = service.clone();
+            let status_service = status_service.clone();
             let exchange = exchange.clone();
             Box::pin(async move {
-                if let Err(e) = service.fetch_and_save_tickers(&exchange).await {
-                    tracing::error!("Ticker fetch failed: {}", e);
-                }
+                let started_at = chrono::Utc::now();
+                let result = service.check_aggregated_candles(&exchange).await;
+                status_service
+                    .record_job_run("Candle consistency check", &exchange, started_at, &result)
+                    .await;
             })
         }
     }
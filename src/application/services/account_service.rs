@@ -0,0 +1,326 @@
+use crate::domain::entities::event::Event;
+use crate::domain::repositories::balance_snapshot_repository::BalanceSnapshotRepository;
+use crate::domain::repositories::deposit_repository::DepositRepository;
+use crate::domain::repositories::event_repository::EventRepository;
+use crate::domain::repositories::fill_repository::FillRepository;
+use crate::domain::repositories::ledger_repository::LedgerRepository;
+use crate::domain::repositories::order_repository::OrderRepository;
+use crate::domain::repositories::symbol_repository::SymbolRepository;
+use crate::domain::repositories::sync_cursor_repository::SyncCursorRepository;
+use crate::domain::repositories::withdrawal_repository::WithdrawalRepository;
+use crate::infrastructure::api::api_client::ApiClient;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+use rust_decimal::Decimal;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+const HISTORY_INITIAL_LOOKBACK: TimeDelta = TimeDelta::days(30);
+const FILLS_CURSOR: &str = "fills";
+const ORDERS_CURSOR: &str = "hf_orders";
+const LEDGER_CURSOR: &str = "ledgers";
+const LEDGER_SYNC_STEP: TimeDelta = TimeDelta::days(1);
+const BALANCE_MISMATCH_EVENT: &str = "balance_mismatch";
+
+#[async_trait]
+pub trait AccountService: Send + Sync {
+    async fn sync_deposits(&self, exchange: &str) -> Result<()>;
+    async fn sync_withdrawals(&self, exchange: &str) -> Result<()>;
+    async fn sync_fills(&self, exchange: &str) -> Result<()>;
+    async fn sync_orders(&self, exchange: &str) -> Result<()>;
+    async fn sync_ledger(&self, exchange: &str) -> Result<()>;
+    async fn snapshot_balances(&self, exchange: &str) -> Result<()>;
+    async fn reconcile_balances(&self, exchange: &str) -> Result<()>;
+}
+
+pub struct AccountServiceImpl {
+    api_client: Arc<dyn ApiClient>,
+    deposit_repo: Arc<dyn DepositRepository>,
+    withdrawal_repo: Arc<dyn WithdrawalRepository>,
+    fill_repo: Arc<dyn FillRepository>,
+    order_repo: Arc<dyn OrderRepository>,
+    symbol_repo: Arc<dyn SymbolRepository>,
+    sync_cursor_repo: Arc<dyn SyncCursorRepository>,
+    ledger_repo: Arc<dyn LedgerRepository>,
+    balance_snapshot_repo: Arc<dyn BalanceSnapshotRepository>,
+    event_repo: Arc<dyn EventRepository>,
+    trading_symbols: Vec<String>,
+}
+
+impl AccountServiceImpl {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        api_client: Arc<dyn ApiClient>,
+        deposit_repo: Arc<dyn DepositRepository>,
+        withdrawal_repo: Arc<dyn WithdrawalRepository>,
+        fill_repo: Arc<dyn FillRepository>,
+        order_repo: Arc<dyn OrderRepository>,
+        symbol_repo: Arc<dyn SymbolRepository>,
+        sync_cursor_repo: Arc<dyn SyncCursorRepository>,
+        ledger_repo: Arc<dyn LedgerRepository>,
+        balance_snapshot_repo: Arc<dyn BalanceSnapshotRepository>,
+        event_repo: Arc<dyn EventRepository>,
+        trading_symbols: Vec<String>,
+    ) -> Self {
+        Self {
+            api_client,
+            deposit_repo,
+            withdrawal_repo,
+            fill_repo,
+            order_repo,
+            symbol_repo,
+            sync_cursor_repo,
+            ledger_repo,
+            balance_snapshot_repo,
+            event_repo,
+            trading_symbols,
+        }
+    }
+
+    async fn known_symbols(&self, exchange: &str) -> Result<HashSet<String>> {
+        Ok(self
+            .symbol_repo
+            .find_all(exchange)
+            .await?
+            .into_iter()
+            .map(|s| s.symbol)
+            .collect())
+    }
+}
+
+#[async_trait]
+impl AccountService for AccountServiceImpl {
+    async fn sync_deposits(&self, exchange: &str) -> Result<()> {
+        let end = Utc::now();
+        let start = self
+            .deposit_repo
+            .find_sync_start(exchange)
+            .await?
+            .unwrap_or(end - HISTORY_INITIAL_LOOKBACK);
+
+        info!(
+            "Syncing deposits for exchange: {} from {} to {}",
+            exchange, start, end
+        );
+        let deposits = self.api_client.fetch_deposits(start, end).await?;
+        self.deposit_repo.save(exchange, &deposits).await?;
+        info!("Saved {} deposits", deposits.len());
+        Ok(())
+    }
+
+    async fn sync_withdrawals(&self, exchange: &str) -> Result<()> {
+        let end = Utc::now();
+        let start = self
+            .withdrawal_repo
+            .find_sync_start(exchange)
+            .await?
+            .unwrap_or(end - HISTORY_INITIAL_LOOKBACK);
+
+        info!(
+            "Syncing withdrawals for exchange: {} from {} to {}",
+            exchange, start, end
+        );
+        let withdrawals = self.api_client.fetch_withdrawals(start, end).await?;
+        self.withdrawal_repo.save(exchange, &withdrawals).await?;
+        info!("Saved {} withdrawals", withdrawals.len());
+        Ok(())
+    }
+
+    async fn sync_fills(&self, exchange: &str) -> Result<()> {
+        let end = Utc::now();
+        let start = self
+            .sync_cursor_repo
+            .find(exchange, FILLS_CURSOR, "")
+            .await?
+            .unwrap_or(end - HISTORY_INITIAL_LOOKBACK);
+
+        info!(
+            "Syncing fills for exchange: {} from {} to {}",
+            exchange, start, end
+        );
+        let fills = self.api_client.fetch_fills(start, end).await?;
+
+        let known_symbols = self.known_symbols(exchange).await?;
+        for fill in fills.iter().filter(|f| !known_symbols.contains(&f.symbol)) {
+            warn!(
+                "Fill '{}' references symbol '{}' that is not stored yet",
+                fill.trade_id, fill.symbol
+            );
+        }
+
+        self.fill_repo.save(exchange, &fills).await?;
+        self.sync_cursor_repo
+            .save(exchange, FILLS_CURSOR, "", end)
+            .await?;
+        info!("Saved {} fills", fills.len());
+        Ok(())
+    }
+
+    async fn sync_orders(&self, exchange: &str) -> Result<()> {
+        let known_symbols = self.known_symbols(exchange).await?;
+
+        for symbol in &self.trading_symbols {
+            if !known_symbols.contains(symbol) {
+                warn!("Skipping orders for unknown symbol '{}'", symbol);
+                continue;
+            }
+
+            let end = Utc::now();
+            let start = self
+                .sync_cursor_repo
+                .find(exchange, ORDERS_CURSOR, symbol)
+                .await?
+                .unwrap_or(end - HISTORY_INITIAL_LOOKBACK);
+
+            info!(
+                "Syncing orders for {} on exchange: {} from {} to {}",
+                symbol, exchange, start, end
+            );
+            let orders = self.api_client.fetch_orders(symbol, start, end).await?;
+            self.order_repo.save(exchange, &orders).await?;
+            self.sync_cursor_repo
+                .save(exchange, ORDERS_CURSOR, symbol, end)
+                .await?;
+            info!("Saved {} orders for {}", orders.len(), symbol);
+        }
+
+        Ok(())
+    }
+
+    async fn sync_ledger(&self, exchange: &str) -> Result<()> {
+        let end = Utc::now();
+        let mut start = self
+            .sync_cursor_repo
+            .find(exchange, LEDGER_CURSOR, "")
+            .await?
+            .unwrap_or(end - HISTORY_INITIAL_LOOKBACK);
+
+        info!(
+            "Syncing ledger for exchange: {} from {} to {}",
+            exchange, start, end
+        );
+        let mut total = 0;
+        while start < end {
+            let step_end = (start + LEDGER_SYNC_STEP).min(end);
+            let entries = self
+                .api_client
+                .fetch_ledger_entries(start, step_end)
+                .await?;
+            self.ledger_repo.save(exchange, &entries).await?;
+            self.sync_cursor_repo
+                .save(exchange, LEDGER_CURSOR, "", step_end)
+                .await?;
+
+            total += entries.len();
+            start = step_end;
+        }
+
+        info!("Saved {} ledger entries", total);
+        Ok(())
+    }
+
+    async fn snapshot_balances(&self, exchange: &str) -> Result<()> {
+        info!("Fetching account balances for exchange: {}", exchange);
+        let snapshot_at = Utc::now();
+        let balances = self.api_client.fetch_account_balances().await?;
+        self.balance_snapshot_repo
+            .save(exchange, snapshot_at, &balances)
+            .await?;
+        info!("Saved snapshot of {} account balances", balances.len());
+        Ok(())
+    }
+
+    async fn reconcile_balances(&self, exchange: &str) -> Result<()> {
+        let Some(ledger_synced_to) = self
+            .sync_cursor_repo
+            .find(exchange, LEDGER_CURSOR, "")
+            .await?
+        else {
+            info!("Ledger is not synced yet, skipping reconciliation");
+            return Ok(());
+        };
+
+        // Snapshots and ledger sync run on the same schedule, so the newest
+        // snapshot is usually ahead of the ledger; use the newest one the
+        // ledger fully covers instead.
+        let Some((snapshot_at, balances)) = self
+            .balance_snapshot_repo
+            .find_latest_before(exchange, ledger_synced_to)
+            .await?
+        else {
+            info!(
+                "No balance snapshot covered by the ledger (synced to {}), skipping reconciliation",
+                ledger_synced_to
+            );
+            return Ok(());
+        };
+
+        let snapshot: HashMap<(&str, &str), Decimal> = balances
+            .iter()
+            .map(|b| {
+                let balance = b.balance.trim().parse().with_context(|| {
+                    format!("Invalid snapshot balance for {}: {}", b.currency, b.balance)
+                })?;
+                Ok(((b.currency.as_str(), b.account_type.as_str()), balance))
+            })
+            .collect::<Result<_>>()?;
+
+        let rebuilt = self
+            .ledger_repo
+            .find_rebuilt_balances(exchange, snapshot_at)
+            .await?;
+
+        let ledger: HashMap<(&str, &str), Decimal> = rebuilt
+            .iter()
+            .map(|b| ((b.currency.as_str(), b.account_type.as_str()), b.balance))
+            .collect();
+        let keys: BTreeSet<(&str, &str)> = snapshot.keys().chain(ledger.keys()).copied().collect();
+
+        let mut events = Vec::new();
+        for (currency, account_type) in &keys {
+            let reported = snapshot
+                .get(&(*currency, *account_type))
+                .copied()
+                .unwrap_or_default();
+            let rebuilt_balance = ledger
+                .get(&(*currency, *account_type))
+                .copied()
+                .unwrap_or_default();
+
+            if reported != rebuilt_balance {
+                warn!(
+                    "Balance mismatch for {} ({}): ledger={} snapshot={}",
+                    currency, account_type, rebuilt_balance, reported
+                );
+                events.push(Event::new(
+                    BALANCE_MISMATCH_EVENT.to_string(),
+                    format!("{}/{}", currency, account_type),
+                    format!(
+                        "Ledger rebuilds {} but snapshot reports {}",
+                        rebuilt_balance, reported
+                    ),
+                    serde_json::json!({
+                        "currency": currency,
+                        "account_type": account_type,
+                        "ledger_balance": rebuilt_balance.normalize().to_string(),
+                        "snapshot_balance": reported.normalize().to_string(),
+                        "snapshot_at": snapshot_at,
+                    }),
+                ));
+            }
+        }
+
+        if !events.is_empty() {
+            self.event_repo.save(exchange, &events).await?;
+        }
+        info!(
+            "Reconciled {} balances against snapshot {}, {} mismatches",
+            keys.len(),
+            snapshot_at,
+            events.len()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use crate::domain::entities::candle::interval_duration;
+use crate::domain::entities::risk_metric::{CorrelationMetric, VolatilityMetric};
+use crate::domain::repositories::arbitrage_repository::ArbitrageRepository;
+use crate::domain::repositories::candle_repository::CandleRepository;
+use crate::domain::repositories::risk_metric_repository::RiskMetricRepository;
+use crate::domain::repositories::symbol_repository::SymbolRepository;
+use crate::domain::repositories::ticker_repository::TickerRepository;
+use crate::domain::services::risk_metrics::{correlation, log_returns, realized_volatility};
+use crate::domain::services::triangular_arbitrage::find_triangular_opportunities;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::info;
+
+#[async_trait]
+pub trait AnalyticsService: Send + Sync {
+    async fn scan_triangular_arbitrage(&self, exchange: &str) -> Result<()>;
+    async fn compute_risk_metrics(&self, exchange: &str) -> Result<()>;
+}
+
+pub struct AnalyticsServiceImpl {
+    symbol_repo: Arc<dyn SymbolRepository>,
+    ticker_repo: Arc<dyn TickerRepository>,
+    arbitrage_repo: Arc<dyn ArbitrageRepository>,
+    arbitrage_min_net_edge: Decimal,
+    candle_repo: Arc<dyn CandleRepository>,
+    risk_metric_repo: Arc<dyn RiskMetricRepository>,
+    basket: Vec<String>,
+    interval: String,
+    window_days: Vec<i32>,
+}
+
+impl AnalyticsServiceImpl {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        symbol_repo: Arc<dyn SymbolRepository>,
+        ticker_repo: Arc<dyn TickerRepository>,
+        arbitrage_repo: Arc<dyn ArbitrageRepository>,
+        arbitrage_min_net_edge: Decimal,
+        candle_repo: Arc<dyn CandleRepository>,
+        risk_metric_repo: Arc<dyn RiskMetricRepository>,
+        basket: Vec<String>,
+        interval: String,
+        window_days: Vec<i32>,
+    ) -> Self {
+        Self {
+            symbol_repo,
+            ticker_repo,
+            arbitrage_repo,
+            arbitrage_min_net_edge,
+            candle_repo,
+            risk_metric_repo,
+            basket,
+            interval,
+            window_days,
+        }
+    }
+}
+
+#[async_trait]
+impl AnalyticsService for AnalyticsServiceImpl {
+    async fn scan_triangular_arbitrage(&self, exchange: &str) -> Result<()> {
+        info!("Scanning triangular arbitrage for exchange: {}", exchange);
+        let symbols = self.symbol_repo.find_all(exchange).await?;
+        let tickers = self.ticker_repo.find_all(exchange).await?;
+
+        let opportunities =
+            find_triangular_opportunities(&symbols, &tickers, self.arbitrage_min_net_edge);
+        if let Some(best) = opportunities.first() {
+            info!(
+                "Found {} triangular opportunities, best {} with net edge {}",
+                opportunities.len(),
+                best.cycle,
+                best.net_edge
+            );
+        }
+
+        self.arbitrage_repo.save(exchange, &opportunities).await?;
+        Ok(())
+    }
+
+    async fn compute_risk_metrics(&self, exchange: &str) -> Result<()> {
+        info!(
+            "Computing volatility and correlation for exchange: {}",
+            exchange
+        );
+        let interval_secs = interval_duration(&self.interval)
+            .with_context(|| format!("Unsupported candle interval '{}'", self.interval))?
+            .num_seconds();
+        let computed_at = Utc::now();
+
+        for &days in &self.window_days {
+            let start = computed_at - TimeDelta::days(days as i64);
+            let mut returns = BTreeMap::new();
+            for symbol in &self.basket {
+                let candles = self
+                    .candle_repo
+                    .find_range(exchange, symbol, &self.interval, start, computed_at)
+                    .await?;
+                returns.insert(symbol.clone(), log_returns(&candles, interval_secs));
+            }
+
+            let volatility: Vec<VolatilityMetric> = returns
+                .iter()
+                .filter_map(|(symbol, series)| {
+                    let values: Vec<f64> = series.values().copied().collect();
+                    realized_volatility(&values, interval_secs).map(|vol| {
+                        VolatilityMetric::new(
+                            symbol.clone(),
+                            self.interval.clone(),
+                            days,
+                            values.len() as i32,
+                            vol,
+                            computed_at,
+                        )
+                    })
+                })
+                .collect();
+
+            let symbols: Vec<&String> = returns.keys().collect();
+            let mut correlations = Vec::new();
+            for (i, a) in symbols.iter().enumerate() {
+                for b in &symbols[i + 1..] {
+                    if let Some((value, samples)) = correlation(&returns[*a], &returns[*b]) {
+                        correlations.push(CorrelationMetric::new(
+                            (*a).clone(),
+                            (*b).clone(),
+                            self.interval.clone(),
+                            days,
+                            samples as i32,
+                            value,
+                            computed_at,
+                        ));
+                    }
+                }
+            }
+
+            self.risk_metric_repo
+                .save_volatility(exchange, &volatility)
+                .await?;
+            self.risk_metric_repo
+                .save_correlations(exchange, &correlations)
+                .await?;
+            info!(
+                "Saved {}-day volatility for {} symbols and {} correlation pairs",
+                days,
+                volatility.len(),
+                correlations.len()
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use crate::domain::entities::announcement::{
+    Announcement, AnnouncementLink, LINK_CURRENCY, LINK_SYMBOL,
+};
+use crate::domain::repositories::announcement_repository::AnnouncementRepository;
+use crate::domain::repositories::currency_repository::CurrencyRepository;
+use crate::domain::repositories::symbol_repository::SymbolRepository;
+use crate::domain::repositories::sync_cursor_repository::SyncCursorRepository;
+use crate::infrastructure::api::api_client::ApiClient;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+use std::collections::{BTreeSet, HashSet};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+const ANNOUNCEMENTS_CURSOR: &str = "announcements";
+const ANNOUNCEMENT_INITIAL_LOOKBACK: TimeDelta = TimeDelta::days(30);
+
+#[async_trait]
+pub trait AnnouncementService: Send + Sync {
+    async fn sync_announcements(&self, exchange: &str) -> Result<()>;
+}
+
+pub struct AnnouncementServiceImpl {
+    api_client: Arc<dyn ApiClient>,
+    announcement_repo: Arc<dyn AnnouncementRepository>,
+    symbol_repo: Arc<dyn SymbolRepository>,
+    currency_repo: Arc<dyn CurrencyRepository>,
+    sync_cursor_repo: Arc<dyn SyncCursorRepository>,
+    announcement_types: Vec<String>,
+}
+
+impl AnnouncementServiceImpl {
+    pub fn new(
+        api_client: Arc<dyn ApiClient>,
+        announcement_repo: Arc<dyn AnnouncementRepository>,
+        symbol_repo: Arc<dyn SymbolRepository>,
+        currency_repo: Arc<dyn CurrencyRepository>,
+        sync_cursor_repo: Arc<dyn SyncCursorRepository>,
+        announcement_types: Vec<String>,
+    ) -> Self {
+        Self {
+            api_client,
+            announcement_repo,
+            symbol_repo,
+            currency_repo,
+            sync_cursor_repo,
+            announcement_types,
+        }
+    }
+}
+
+#[async_trait]
+impl AnnouncementService for AnnouncementServiceImpl {
+    async fn sync_announcements(&self, exchange: &str) -> Result<()> {
+        info!("Syncing announcements for exchange: {}", exchange);
+
+        let symbols: HashSet<String> = self
+            .symbol_repo
+            .find_all(exchange)
+            .await?
+            .into_iter()
+            .map(|s| s.symbol)
+            .collect();
+        let currencies: HashSet<String> = self
+            .currency_repo
+            .find_codes(exchange)
+            .await?
+            .into_iter()
+            .collect();
+
+        let mut total = 0;
+        let mut linked = 0;
+        for ann_type in &self.announcement_types {
+            let end = Utc::now();
+            let start = self
+                .sync_cursor_repo
+                .find(exchange, ANNOUNCEMENTS_CURSOR, ann_type)
+                .await?
+                .unwrap_or(end - ANNOUNCEMENT_INITIAL_LOOKBACK);
+
+            let announcements = match self
+                .api_client
+                .fetch_announcements(ann_type, start, end)
+                .await
+            {
+                Ok(announcements) => announcements,
+                Err(e) => {
+                    warn!("Failed to fetch {} announcements: {}", ann_type, e);
+                    continue;
+                }
+            };
+
+            let links: Vec<AnnouncementLink> = announcements
+                .iter()
+                .flat_map(|a| announcement_links(a, &symbols, &currencies))
+                .collect();
+
+            self.announcement_repo
+                .save(exchange, &announcements)
+                .await?;
+            self.announcement_repo.save_links(exchange, &links).await?;
+            self.sync_cursor_repo
+                .save(exchange, ANNOUNCEMENTS_CURSOR, ann_type, end)
+                .await?;
+
+            total += announcements.len();
+            linked += links.len();
+        }
+
+        info!(
+            "Synced {} announcements with {} symbol/currency links",
+            total, linked
+        );
+        Ok(())
+    }
+}
+
+fn announcement_links(
+    announcement: &Announcement,
+    symbols: &HashSet<String>,
+    currencies: &HashSet<String>,
+) -> Vec<AnnouncementLink> {
+    let text = format!("{} {}", announcement.title, announcement.description);
+    let mut targets = BTreeSet::new();
+
+    for token in text
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '/'))
+        .filter(|token| !token.is_empty())
+    {
+        let pair = token.replace('/', "-");
+        if symbols.contains(&pair) {
+            targets.insert((LINK_SYMBOL, pair));
+        }
+        for part in token.split(['-', '/']) {
+            if currencies.contains(part) {
+                targets.insert((LINK_CURRENCY, part.to_string()));
+            }
+        }
+    }
+
+    targets
+        .into_iter()
+        .map(|(link_type, target)| {
+            AnnouncementLink::new(announcement.id, link_type.to_string(), target)
+        })
+        .collect()
+}
@@ -0,0 +1,545 @@
+use crate::application::services::monitoring_service::record_data_quality;
+use crate::domain::entities::candle::{
+    Candle, SOURCE_TICKERS, SOURCE_TRADES, interval_duration, interval_name,
+};
+use crate::domain::entities::data_quality::DataQualityIssue;
+use crate::domain::entities::indicator::IndicatorValue;
+use crate::domain::entities::trade::TradeGap;
+use crate::domain::repositories::aggregated_candle_repository::AggregatedCandleRepository;
+use crate::domain::repositories::candle_repository::CandleRepository;
+use crate::domain::repositories::event_repository::EventRepository;
+use crate::domain::repositories::indicator_repository::IndicatorRepository;
+use crate::domain::repositories::ticker_repository::TickerRepository;
+use crate::domain::repositories::trade_repository::TradeRepository;
+use crate::domain::services::candle_aggregation::{
+    bucket_start, candles_from_snapshots, candles_from_trades, compare_candles, overlaps_gap,
+    parse_interval, roll_up,
+};
+use crate::domain::services::indicators::{Indicator, IndicatorSpec};
+use crate::infrastructure::api::api_client::ApiClient;
+use crate::infrastructure::ws::market_stream_client::{MarketStreamClient, TradeStream};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+const CANDLE_BACKFILL: i32 = 500;
+// KuCoin returns at most this many candles per request.
+const CANDLE_PAGE_SIZE: i32 = 1500;
+const AGGREGATION_BACKFILL: TimeDelta = TimeDelta::hours(1);
+const CONSISTENCY_WINDOW: TimeDelta = TimeDelta::hours(1);
+// Kept below the 100 trades /market/histories returns, so trades still
+// buffered here never look like a gap to the REST backfill.
+const TRADE_FLUSH_SIZE: usize = 50;
+const TRADE_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+const RECONNECT_DELAY_MIN: Duration = Duration::from_secs(1);
+const RECONNECT_DELAY_MAX: Duration = Duration::from_secs(60);
+
+#[async_trait]
+pub trait CandleService: Send + Sync {
+    async fn sync_candles(&self, exchange: &str) -> Result<()>;
+    async fn update_indicators(&self, exchange: &str) -> Result<()>;
+    async fn run_trade_feed(&self, exchange: &str);
+    async fn sync_trades(&self, exchange: &str) -> Result<()>;
+    async fn aggregate_candles(&self, exchange: &str) -> Result<()>;
+    async fn check_aggregated_candles(&self, exchange: &str) -> Result<()>;
+}
+
+struct IndicatorState {
+    indicators: Vec<(String, Box<dyn Indicator>)>,
+    last_open_time: DateTime<Utc>,
+}
+
+pub struct CandleServiceImpl {
+    api_client: Arc<dyn ApiClient>,
+    stream_client: Arc<dyn MarketStreamClient>,
+    candle_repo: Arc<dyn CandleRepository>,
+    indicator_repo: Arc<dyn IndicatorRepository>,
+    trade_repo: Arc<dyn TradeRepository>,
+    ticker_repo: Arc<dyn TickerRepository>,
+    aggregated_candle_repo: Arc<dyn AggregatedCandleRepository>,
+    event_repo: Arc<dyn EventRepository>,
+    candle_symbols: Vec<String>,
+    candle_intervals: Vec<String>,
+    aggregated_intervals: Vec<String>,
+    indicators: Vec<IndicatorSpec>,
+    states: Mutex<HashMap<(String, String), IndicatorState>>,
+}
+
+impl CandleServiceImpl {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        api_client: Arc<dyn ApiClient>,
+        stream_client: Arc<dyn MarketStreamClient>,
+        candle_repo: Arc<dyn CandleRepository>,
+        indicator_repo: Arc<dyn IndicatorRepository>,
+        trade_repo: Arc<dyn TradeRepository>,
+        ticker_repo: Arc<dyn TickerRepository>,
+        aggregated_candle_repo: Arc<dyn AggregatedCandleRepository>,
+        event_repo: Arc<dyn EventRepository>,
+        candle_symbols: Vec<String>,
+        candle_intervals: Vec<String>,
+        aggregated_intervals: Vec<String>,
+        indicators: Vec<IndicatorSpec>,
+    ) -> Self {
+        Self {
+            api_client,
+            stream_client,
+            candle_repo,
+            indicator_repo,
+            trade_repo,
+            ticker_repo,
+            aggregated_candle_repo,
+            event_repo,
+            candle_symbols,
+            candle_intervals,
+            aggregated_intervals,
+            indicators,
+            states: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn consume_trades(
+        &self,
+        exchange: &str,
+        stream: &mut Box<dyn TradeStream>,
+    ) -> Result<()> {
+        let mut pending = Vec::new();
+        // The timer flushes a partial batch even when the stream goes quiet.
+        let mut flush = tokio::time::interval(TRADE_FLUSH_INTERVAL);
+        flush.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                trade = stream.next_trade() => match trade {
+                    Ok(trade) => {
+                        pending.push(trade);
+                        if pending.len() < TRADE_FLUSH_SIZE {
+                            continue;
+                        }
+                    }
+                    Err(e) => {
+                        self.trade_repo.save(exchange, &pending).await?;
+                        return Err(e);
+                    }
+                },
+                _ = flush.tick() => {
+                    if pending.is_empty() {
+                        continue;
+                    }
+                }
+            }
+
+            self.trade_repo.save(exchange, &pending).await?;
+            pending.clear();
+            flush.reset();
+        }
+    }
+
+    fn feed(&self, symbol: &str, interval: &str, candles: &[Candle]) -> Vec<IndicatorValue> {
+        let mut states = self.states.lock().unwrap_or_else(|e| e.into_inner());
+        let state = states
+            .entry((symbol.to_string(), interval.to_string()))
+            .or_insert_with(|| IndicatorState {
+                indicators: self
+                    .indicators
+                    .iter()
+                    .map(|s| (s.name(), s.build()))
+                    .collect(),
+                last_open_time: DateTime::<Utc>::MIN_UTC,
+            });
+
+        let mut values = Vec::new();
+        for candle in candles {
+            if candle.open_time <= state.last_open_time {
+                continue;
+            }
+            for (name, indicator) in state.indicators.iter_mut() {
+                for (output, value) in indicator.update(candle) {
+                    if !value.is_finite() {
+                        continue;
+                    }
+                    let indicator = if output == "value" {
+                        name.clone()
+                    } else {
+                        format!("{}.{}", name, output)
+                    };
+                    values.push(IndicatorValue::new(
+                        symbol.to_string(),
+                        interval.to_string(),
+                        indicator,
+                        candle.open_time,
+                        value,
+                    ));
+                }
+            }
+            state.last_open_time = candle.open_time;
+        }
+        values
+    }
+
+    fn aggregated_durations(&self) -> Result<Vec<(&str, TimeDelta)>> {
+        self.aggregated_intervals
+            .iter()
+            .map(|interval| {
+                let duration = parse_interval(interval)
+                    .with_context(|| format!("Unsupported aggregated interval '{}'", interval))?;
+                Ok((interval.as_str(), duration))
+            })
+            .collect()
+    }
+
+    async fn aggregate_symbol(
+        &self,
+        exchange: &str,
+        source: &str,
+        symbol: &str,
+        intervals: &[(&str, TimeDelta)],
+        now: DateTime<Utc>,
+    ) -> Result<usize> {
+        let Some(&(base_interval, base_duration)) = intervals.first() else {
+            return Ok(0);
+        };
+
+        // The latest stored bucket may still have been forming on the last
+        // run, so it is rebuilt together with everything after it.
+        let start = match self
+            .aggregated_candle_repo
+            .find_latest_open_time(exchange, source, symbol, base_interval)
+            .await?
+        {
+            Some(latest) => latest,
+            None => bucket_start(now - AGGREGATION_BACKFILL, base_duration),
+        };
+
+        // Buckets touched by a recorded trade gap are left unpublished, at
+        // every interval they roll up into.
+        let coarsest = intervals.last().map_or(base_duration, |&(_, d)| d);
+        let gaps = if source == SOURCE_TRADES {
+            self.trade_repo
+                .find_gaps(exchange, symbol, bucket_start(start, coarsest), now)
+                .await?
+        } else {
+            Vec::new()
+        };
+        let mut incomplete = 0;
+
+        let mut candles = if source == SOURCE_TRADES {
+            let trades = self
+                .trade_repo
+                .find_range(exchange, symbol, start, now)
+                .await?;
+            candles_from_trades(symbol, base_interval, base_duration, &trades)
+        } else {
+            let snapshots = self
+                .ticker_repo
+                .find_history(exchange, symbol, start, now)
+                .await?;
+            candles_from_snapshots(symbol, base_interval, base_duration, &snapshots)
+        };
+        incomplete += drop_incomplete(&mut candles, base_duration, &gaps);
+        self.aggregated_candle_repo
+            .save(exchange, source, &candles)
+            .await?;
+        let mut total = candles.len();
+
+        for &(interval, duration) in &intervals[1..] {
+            let rollup_start = bucket_start(start, duration);
+            let base = self
+                .aggregated_candle_repo
+                .find_range(exchange, source, symbol, base_interval, rollup_start, now)
+                .await?;
+            let mut rolled = roll_up(&base, interval, duration);
+            incomplete += drop_incomplete(&mut rolled, duration, &gaps);
+            self.aggregated_candle_repo
+                .save(exchange, source, &rolled)
+                .await?;
+            total += rolled.len();
+        }
+
+        if incomplete > 0 {
+            warn!(
+                "Left {} incomplete {} candles unpublished for {}",
+                incomplete, source, symbol
+            );
+        }
+        Ok(total)
+    }
+
+    fn last_open_time(&self, symbol: &str, interval: &str) -> Option<DateTime<Utc>> {
+        let states = self.states.lock().unwrap_or_else(|e| e.into_inner());
+        states
+            .get(&(symbol.to_string(), interval.to_string()))
+            .map(|s| s.last_open_time)
+    }
+}
+
+#[async_trait]
+impl CandleService for CandleServiceImpl {
+    async fn sync_candles(&self, exchange: &str) -> Result<()> {
+        let now = Utc::now();
+        let mut total = 0;
+        let mut issues = Vec::new();
+
+        for interval in &self.candle_intervals {
+            let duration = interval_duration(interval)
+                .with_context(|| format!("Unsupported candle interval '{}'", interval))?;
+
+            for symbol in &self.candle_symbols {
+                let start = match self
+                    .candle_repo
+                    .find_latest_open_time(exchange, symbol, interval)
+                    .await?
+                {
+                    Some(latest) => latest,
+                    None => now - duration * CANDLE_BACKFILL,
+                };
+
+                // Walk the window page by page; a failed page stops the
+                // symbol so the next run resumes from the last saved candle.
+                let mut page_start = start;
+                while page_start < now {
+                    let page_end = (page_start + duration * CANDLE_PAGE_SIZE).min(now);
+                    match self
+                        .api_client
+                        .fetch_candles(symbol, interval, page_start, page_end)
+                        .await
+                    {
+                        Ok(candles) => {
+                            self.candle_repo.save(exchange, &candles).await?;
+                            total += candles.len();
+                        }
+                        Err(e) => {
+                            match e.downcast_ref::<DataQualityIssue>() {
+                                Some(issue) => issues.push(issue.clone()),
+                                None => warn!(
+                                    "Failed to fetch {} candles for {}: {}",
+                                    interval, symbol, e
+                                ),
+                            }
+                            break;
+                        }
+                    }
+                    page_start = page_end;
+                }
+            }
+        }
+
+        record_data_quality(self.event_repo.as_ref(), exchange, &issues).await?;
+        info!("Saved {} candles", total);
+        Ok(())
+    }
+
+    async fn update_indicators(&self, exchange: &str) -> Result<()> {
+        if self.indicators.is_empty() {
+            return Ok(());
+        }
+        let now = Utc::now();
+        let mut total = 0;
+
+        for interval in &self.candle_intervals {
+            let duration = interval_duration(interval)
+                .with_context(|| format!("Unsupported candle interval '{}'", interval))?;
+
+            for symbol in &self.candle_symbols {
+                // After a restart the state is re-warmed from history; values
+                // up to the last published candle are already stored and must
+                // not be replaced by the warm-up run.
+                let (start, published_through) = match self.last_open_time(symbol, interval) {
+                    Some(last) => (last + duration, None),
+                    None => (
+                        now - duration * CANDLE_BACKFILL,
+                        self.indicator_repo
+                            .find_latest_open_time(exchange, symbol, interval)
+                            .await?,
+                    ),
+                };
+                // Only closed candles feed the indicators; the forming one is
+                // picked up on a later run once its interval has passed.
+                let closed_before = now - duration;
+                let candles = self
+                    .candle_repo
+                    .find_range(exchange, symbol, interval, start, closed_before)
+                    .await?;
+
+                let mut values = self.feed(symbol, interval, &candles);
+                if let Some(published_through) = published_through {
+                    values.retain(|v| v.open_time > published_through);
+                }
+                self.indicator_repo.save(exchange, &values).await?;
+                total += values.len();
+            }
+        }
+
+        info!("Saved {} indicator values", total);
+        Ok(())
+    }
+
+    async fn run_trade_feed(&self, exchange: &str) {
+        if self.candle_symbols.is_empty() {
+            return;
+        }
+        let mut delay = RECONNECT_DELAY_MIN;
+
+        loop {
+            match self
+                .stream_client
+                .connect_trades(&self.candle_symbols)
+                .await
+            {
+                Ok(mut stream) => {
+                    info!("Trade feed connected for exchange: {}", exchange);
+                    delay = RECONNECT_DELAY_MIN;
+                    if let Err(e) = self.consume_trades(exchange, &mut stream).await {
+                        warn!("Trade feed disconnected: {}", e);
+                    }
+                }
+                Err(e) => error!("Trade feed connection failed: {}", e),
+            }
+
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(RECONNECT_DELAY_MAX);
+        }
+    }
+
+    // Backfills what the trade feed missed while disconnected. The history
+    // endpoint only returns the latest 100 trades, so when even the oldest of
+    // them is newer than the last stored trade the stretch in between is
+    // recorded as a gap.
+    async fn sync_trades(&self, exchange: &str) -> Result<()> {
+        let detected_at = Utc::now();
+        let mut total = 0;
+        let mut gaps = Vec::new();
+        let mut issues = Vec::new();
+
+        for symbol in &self.candle_symbols {
+            let latest = self.trade_repo.find_latest(exchange, symbol).await?;
+            match self.api_client.fetch_trades(symbol).await {
+                Ok(trades) => {
+                    let oldest = trades.iter().min_by_key(|t| t.sequence);
+                    if let (Some(latest), Some(oldest)) = (&latest, oldest)
+                        && oldest.sequence > latest.sequence
+                    {
+                        warn!(
+                            "Trade gap on {} between sequences {} and {}",
+                            symbol, latest.sequence, oldest.sequence
+                        );
+                        gaps.push(TradeGap::new(latest, oldest, detected_at));
+                    }
+                    self.trade_repo.save(exchange, &trades).await?;
+                    total += trades.len();
+                }
+                Err(e) => match e.downcast_ref::<DataQualityIssue>() {
+                    Some(issue) => issues.push(issue.clone()),
+                    None => warn!("Failed to fetch trades for {}: {}", symbol, e),
+                },
+            }
+        }
+
+        record_data_quality(self.event_repo.as_ref(), exchange, &issues).await?;
+        if !gaps.is_empty() {
+            self.trade_repo.save_gaps(exchange, &gaps).await?;
+        }
+        info!("Saved {} trades, {} gaps", total, gaps.len());
+        Ok(())
+    }
+
+    async fn aggregate_candles(&self, exchange: &str) -> Result<()> {
+        let intervals = self.aggregated_durations()?;
+        let now = Utc::now();
+        let mut total = 0;
+
+        for source in [SOURCE_TRADES, SOURCE_TICKERS] {
+            for symbol in &self.candle_symbols {
+                total += self
+                    .aggregate_symbol(exchange, source, symbol, &intervals, now)
+                    .await?;
+            }
+        }
+
+        info!("Saved {} aggregated candles", total);
+        Ok(())
+    }
+
+    async fn check_aggregated_candles(&self, exchange: &str) -> Result<()> {
+        let now = Utc::now();
+        let mut checks = Vec::new();
+        let mut issues = Vec::new();
+
+        // Only intervals KuCoin publishes can be checked, and only trade-based
+        // candles carry the volume needed for a like-for-like comparison.
+        // Short forms such as "3m" are checked against KuCoin's "3min".
+        for (interval, duration) in self.aggregated_durations()? {
+            let Some(kucoin_interval) = interval_name(duration) else {
+                continue;
+            };
+            let window_end = bucket_start(now, duration);
+            let window_start = bucket_start(window_end - CONSISTENCY_WINDOW, duration);
+
+            for symbol in &self.candle_symbols {
+                let reference = match self
+                    .api_client
+                    .fetch_candles(symbol, kucoin_interval, window_start, window_end)
+                    .await
+                {
+                    Ok(candles) => candles,
+                    Err(e) => {
+                        match e.downcast_ref::<DataQualityIssue>() {
+                            Some(issue) => issues.push(issue.clone()),
+                            None => {
+                                warn!("Failed to fetch {} candles for {}: {}", interval, symbol, e)
+                            }
+                        }
+                        continue;
+                    }
+                };
+                let aggregated = self
+                    .aggregated_candle_repo
+                    .find_range(
+                        exchange,
+                        SOURCE_TRADES,
+                        symbol,
+                        interval,
+                        window_start,
+                        window_end,
+                    )
+                    .await?;
+
+                let check = compare_candles(
+                    symbol,
+                    interval,
+                    SOURCE_TRADES,
+                    window_start,
+                    window_end,
+                    &aggregated,
+                    &reference,
+                    now,
+                );
+                if check.missing > 0 || check.mismatched > 0 {
+                    warn!(
+                        "Aggregated {} candles for {} diverge from KuCoin: {} missing, {} mismatched of {}",
+                        interval,
+                        symbol,
+                        check.missing,
+                        check.mismatched,
+                        check.compared + check.missing
+                    );
+                }
+                checks.push(check);
+            }
+        }
+
+        self.aggregated_candle_repo
+            .save_checks(exchange, &checks)
+            .await?;
+        record_data_quality(self.event_repo.as_ref(), exchange, &issues).await
+    }
+}
+
+fn drop_incomplete(candles: &mut Vec<Candle>, duration: TimeDelta, gaps: &[TradeGap]) -> usize {
+    let before = candles.len();
+    candles.retain(|c| !overlaps_gap(c.open_time, duration, gaps));
+    before - candles.len()
+}
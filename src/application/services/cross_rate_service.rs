@@ -0,0 +1,79 @@
+use crate::domain::repositories::fiat_price_repository::FiatPriceRepository;
+use crate::domain::repositories::symbol_repository::SymbolRepository;
+use crate::domain::repositories::ticker_repository::TickerRepository;
+use crate::domain::services::cross_rate::{ConversionPath, PathPreference, RateGraph};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait CrossRateService: Send + Sync {
+    async fn graph(&self, exchange: &str) -> Result<RateGraph>;
+
+    /// The graph from the last tickers and fiat prices stored at or before `at`.
+    async fn graph_at(&self, exchange: &str, at: DateTime<Utc>) -> Result<RateGraph>;
+
+    async fn convert(
+        &self,
+        exchange: &str,
+        from: &str,
+        to: &str,
+        preference: PathPreference,
+    ) -> Result<Option<ConversionPath>>;
+}
+
+pub struct CrossRateServiceImpl {
+    symbol_repo: Arc<dyn SymbolRepository>,
+    ticker_repo: Arc<dyn TickerRepository>,
+    fiat_price_repo: Arc<dyn FiatPriceRepository>,
+}
+
+impl CrossRateServiceImpl {
+    pub fn new(
+        symbol_repo: Arc<dyn SymbolRepository>,
+        ticker_repo: Arc<dyn TickerRepository>,
+        fiat_price_repo: Arc<dyn FiatPriceRepository>,
+    ) -> Self {
+        Self {
+            symbol_repo,
+            ticker_repo,
+            fiat_price_repo,
+        }
+    }
+}
+
+#[async_trait]
+impl CrossRateService for CrossRateServiceImpl {
+    async fn graph(&self, exchange: &str) -> Result<RateGraph> {
+        let symbols = self.symbol_repo.find_all(exchange).await?;
+        let tickers = self.ticker_repo.find_all(exchange).await?;
+        let fiat_prices = self.fiat_price_repo.find_latest(exchange).await?;
+
+        Ok(RateGraph::new(&symbols, &tickers, &fiat_prices))
+    }
+
+    async fn graph_at(&self, exchange: &str, at: DateTime<Utc>) -> Result<RateGraph> {
+        let symbols = self.symbol_repo.find_all(exchange).await?;
+        let tickers = self.ticker_repo.find_all_at(exchange, at).await?;
+        let fiat_prices = self.fiat_price_repo.find_latest_at(exchange, at).await?;
+
+        Ok(RateGraph::new(&symbols, &tickers, &fiat_prices))
+    }
+
+    async fn convert(
+        &self,
+        exchange: &str,
+        from: &str,
+        to: &str,
+        preference: PathPreference,
+    ) -> Result<Option<ConversionPath>> {
+        let graph = self.graph(exchange).await?;
+        Ok(graph.find_path(
+            &from.to_uppercase(),
+            &to.to_uppercase(),
+            preference,
+            Utc::now(),
+        ))
+    }
+}
@@ -0,0 +1,81 @@
+use crate::domain::repositories::symbol_repository::SymbolRepository;
+use crate::domain::repositories::ticker_repository::TickerRepository;
+use crate::domain::services::fee_calculator::{FeeEstimate, VipFeeRates, estimate_fees};
+use anyhow::Result;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use std::fmt;
+use std::sync::Arc;
+
+/// The requested symbol is not listed on the exchange.
+#[derive(Debug, Clone)]
+pub struct UnknownSymbol {
+    pub exchange: String,
+    pub symbol: String,
+}
+
+impl fmt::Display for UnknownSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unknown symbol '{}' on exchange '{}'",
+            self.symbol, self.exchange
+        )
+    }
+}
+
+impl std::error::Error for UnknownSymbol {}
+
+#[async_trait]
+pub trait FeeService: Send + Sync {
+    async fn estimate(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        price: Decimal,
+        size: Decimal,
+        vip: Option<VipFeeRates>,
+    ) -> Result<FeeEstimate>;
+}
+
+pub struct FeeServiceImpl {
+    symbol_repo: Arc<dyn SymbolRepository>,
+    ticker_repo: Arc<dyn TickerRepository>,
+}
+
+impl FeeServiceImpl {
+    pub fn new(
+        symbol_repo: Arc<dyn SymbolRepository>,
+        ticker_repo: Arc<dyn TickerRepository>,
+    ) -> Self {
+        Self {
+            symbol_repo,
+            ticker_repo,
+        }
+    }
+}
+
+#[async_trait]
+impl FeeService for FeeServiceImpl {
+    async fn estimate(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        price: Decimal,
+        size: Decimal,
+        vip: Option<VipFeeRates>,
+    ) -> Result<FeeEstimate> {
+        let Some(symbol_info) = self.symbol_repo.find_by_symbol(exchange, symbol).await? else {
+            return Err(UnknownSymbol {
+                exchange: exchange.to_string(),
+                symbol: symbol.to_string(),
+            }
+            .into());
+        };
+        let Some(ticker) = self.ticker_repo.find_latest(exchange, symbol).await? else {
+            anyhow::bail!("No ticker stored for symbol '{}'", symbol)
+        };
+
+        Ok(estimate_fees(&symbol_info, &ticker, price, size, vip)?)
+    }
+}
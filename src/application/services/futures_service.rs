@@ -0,0 +1,220 @@
+use crate::domain::entities::event::Event;
+use crate::domain::entities::futures::FundingRate;
+use crate::domain::repositories::event_repository::EventRepository;
+use crate::domain::repositories::funding_rate_repository::FundingRateRepository;
+use crate::domain::repositories::futures_contract_repository::FuturesContractRepository;
+use crate::domain::repositories::mark_price_repository::MarkPriceRepository;
+use crate::domain::repositories::open_interest_repository::OpenInterestRepository;
+use crate::domain::repositories::sync_cursor_repository::SyncCursorRepository;
+use crate::infrastructure::api::futures_api_client::FuturesApiClient;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+const FUNDING_HISTORY_CURSOR: &str = "funding_rates";
+const FUNDING_BACKFILL_LOOKBACK: TimeDelta = TimeDelta::days(365);
+const FUNDING_BACKFILL_STEP: TimeDelta = TimeDelta::days(30);
+const FUNDING_BACKFILL_STEPS_PER_RUN: usize = 4;
+const FUNDING_ANOMALY_MIN_SAMPLES: i64 = 30;
+const FUNDING_ANOMALY_EVENT: &str = "funding_rate_anomaly";
+
+#[async_trait]
+pub trait FuturesService: Send + Sync {
+    async fn fetch_and_save_contracts(&self, exchange: &str) -> Result<()>;
+    async fn fetch_and_save_funding_rates(&self, exchange: &str) -> Result<()>;
+    async fn fetch_and_save_mark_prices(&self, exchange: &str) -> Result<()>;
+    async fn fetch_and_save_open_interest(&self, exchange: &str) -> Result<()>;
+}
+
+pub struct FuturesServiceImpl {
+    api_client: Arc<dyn FuturesApiClient>,
+    contract_repo: Arc<dyn FuturesContractRepository>,
+    funding_rate_repo: Arc<dyn FundingRateRepository>,
+    mark_price_repo: Arc<dyn MarkPriceRepository>,
+    open_interest_repo: Arc<dyn OpenInterestRepository>,
+    sync_cursor_repo: Arc<dyn SyncCursorRepository>,
+    event_repo: Arc<dyn EventRepository>,
+    funding_rate_alert_percentile: f64,
+}
+
+impl FuturesServiceImpl {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        api_client: Arc<dyn FuturesApiClient>,
+        contract_repo: Arc<dyn FuturesContractRepository>,
+        funding_rate_repo: Arc<dyn FundingRateRepository>,
+        mark_price_repo: Arc<dyn MarkPriceRepository>,
+        open_interest_repo: Arc<dyn OpenInterestRepository>,
+        sync_cursor_repo: Arc<dyn SyncCursorRepository>,
+        event_repo: Arc<dyn EventRepository>,
+        funding_rate_alert_percentile: f64,
+    ) -> Self {
+        Self {
+            api_client,
+            contract_repo,
+            funding_rate_repo,
+            mark_price_repo,
+            open_interest_repo,
+            sync_cursor_repo,
+            event_repo,
+            funding_rate_alert_percentile,
+        }
+    }
+
+    async fn backfill_funding_history(&self, exchange: &str, symbol: &str) -> Result<usize> {
+        let now = Utc::now();
+        let mut start = self
+            .sync_cursor_repo
+            .find(exchange, FUNDING_HISTORY_CURSOR, symbol)
+            .await?
+            .unwrap_or(now - FUNDING_BACKFILL_LOOKBACK);
+
+        let mut total = 0;
+        for _ in 0..FUNDING_BACKFILL_STEPS_PER_RUN {
+            if start >= now {
+                break;
+            }
+            let end = (start + FUNDING_BACKFILL_STEP).min(now);
+
+            let rates = self
+                .api_client
+                .fetch_funding_rate_history(symbol, start, end)
+                .await?;
+            self.funding_rate_repo.save(exchange, &rates).await?;
+            self.flag_funding_anomalies(exchange, symbol, &rates)
+                .await?;
+            self.sync_cursor_repo
+                .save(exchange, FUNDING_HISTORY_CURSOR, symbol, end)
+                .await?;
+
+            total += rates.len();
+            start = end;
+        }
+
+        Ok(total)
+    }
+
+    async fn flag_funding_anomalies(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        rates: &[FundingRate],
+    ) -> Result<()> {
+        if rates.is_empty() {
+            return Ok(());
+        }
+
+        let (threshold, samples) = self
+            .funding_rate_repo
+            .find_abs_percentile(exchange, symbol, self.funding_rate_alert_percentile)
+            .await?;
+        let Some(threshold) = threshold else {
+            return Ok(());
+        };
+        if samples < FUNDING_ANOMALY_MIN_SAMPLES {
+            return Ok(());
+        }
+
+        let events: Vec<Event> = rates
+            .iter()
+            .filter(|rate| rate.funding_rate.abs() > threshold)
+            .map(|rate| {
+                Event::new(
+                    FUNDING_ANOMALY_EVENT.to_string(),
+                    rate.symbol.clone(),
+                    format!(
+                        "Funding rate {} at {} exceeds p{} of history ({})",
+                        rate.funding_rate,
+                        rate.time_point,
+                        self.funding_rate_alert_percentile * 100.0,
+                        threshold
+                    ),
+                    serde_json::json!({
+                        "time_point": rate.time_point,
+                        "funding_rate": rate.funding_rate,
+                        "percentile": self.funding_rate_alert_percentile,
+                        "threshold": threshold,
+                        "samples": samples,
+                    }),
+                )
+            })
+            .collect();
+
+        if !events.is_empty() {
+            warn!(
+                "Flagged {} anomalous funding rates for {}",
+                events.len(),
+                symbol
+            );
+            self.event_repo.save(exchange, &events).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl FuturesService for FuturesServiceImpl {
+    async fn fetch_and_save_contracts(&self, exchange: &str) -> Result<()> {
+        info!("Fetching futures contracts for exchange: {}", exchange);
+        let contracts = self.api_client.fetch_contracts().await?;
+        self.contract_repo.save(exchange, &contracts).await?;
+        info!("Saved {} futures contracts", contracts.len());
+        Ok(())
+    }
+
+    async fn fetch_and_save_funding_rates(&self, exchange: &str) -> Result<()> {
+        info!("Fetching funding rates for exchange: {}", exchange);
+        let symbols = self.contract_repo.find_open_symbols(exchange).await?;
+
+        let mut backfilled = 0;
+        let mut rates = Vec::new();
+        for symbol in &symbols {
+            match self.backfill_funding_history(exchange, symbol).await {
+                Ok(count) => backfilled += count,
+                Err(e) => warn!("Failed to backfill funding history for {}: {}", symbol, e),
+            }
+
+            match self.api_client.fetch_current_funding_rate(symbol).await {
+                Ok(rate) => rates.push(rate),
+                Err(e) => warn!("Failed to fetch current funding rate for {}: {}", symbol, e),
+            }
+        }
+
+        self.funding_rate_repo.save(exchange, &rates).await?;
+        info!(
+            "Saved {} current and {} historical funding rates",
+            rates.len(),
+            backfilled
+        );
+        Ok(())
+    }
+
+    async fn fetch_and_save_mark_prices(&self, exchange: &str) -> Result<()> {
+        info!("Fetching mark prices for exchange: {}", exchange);
+        let symbols = self.contract_repo.find_open_symbols(exchange).await?;
+
+        let mut prices = Vec::new();
+        for symbol in &symbols {
+            match self.api_client.fetch_mark_price(symbol).await {
+                Ok(price) => prices.push(price),
+                Err(e) => warn!("Failed to fetch mark price for {}: {}", symbol, e),
+            }
+        }
+
+        self.mark_price_repo.save(exchange, &prices).await?;
+        info!("Saved {} mark prices", prices.len());
+        Ok(())
+    }
+
+    async fn fetch_and_save_open_interest(&self, exchange: &str) -> Result<()> {
+        info!("Fetching open interest for exchange: {}", exchange);
+        let open_interests = self.api_client.fetch_open_interest().await?;
+        self.open_interest_repo
+            .save(exchange, &open_interests)
+            .await?;
+        info!("Saved {} open interests", open_interests.len());
+        Ok(())
+    }
+}
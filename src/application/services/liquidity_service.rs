@@ -0,0 +1,151 @@
+use crate::application::services::cross_rate_service::CrossRateService;
+use crate::domain::entities::liquidity::{LIQUID_UNIVERSE, LiquidityInputs};
+use crate::domain::repositories::liquidity_repository::LiquidityRepository;
+use crate::domain::repositories::symbol_repository::SymbolRepository;
+use crate::domain::repositories::ticker_repository::TickerRepository;
+use crate::domain::repositories::trade_repository::TradeRepository;
+use crate::domain::repositories::watchlist_repository::WatchlistRepository;
+use crate::domain::services::cross_rate::PathPreference;
+use crate::domain::services::liquidity::{depth_within, score_liquidity, to_f64, trades_per_hour};
+use crate::infrastructure::api::api_client::ApiClient;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+const VALUATION_CURRENCY: &str = "USDT";
+
+#[async_trait]
+pub trait LiquidityService: Send + Sync {
+    async fn score_symbols(&self, exchange: &str) -> Result<()>;
+}
+
+pub struct LiquidityServiceImpl {
+    api_client: Arc<dyn ApiClient>,
+    cross_rate_service: Arc<dyn CrossRateService>,
+    symbol_repo: Arc<dyn SymbolRepository>,
+    ticker_repo: Arc<dyn TickerRepository>,
+    trade_repo: Arc<dyn TradeRepository>,
+    liquidity_repo: Arc<dyn LiquidityRepository>,
+    watchlist_repo: Arc<dyn WatchlistRepository>,
+    candidates: usize,
+    universe_size: usize,
+}
+
+impl LiquidityServiceImpl {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        api_client: Arc<dyn ApiClient>,
+        cross_rate_service: Arc<dyn CrossRateService>,
+        symbol_repo: Arc<dyn SymbolRepository>,
+        ticker_repo: Arc<dyn TickerRepository>,
+        trade_repo: Arc<dyn TradeRepository>,
+        liquidity_repo: Arc<dyn LiquidityRepository>,
+        watchlist_repo: Arc<dyn WatchlistRepository>,
+        candidates: usize,
+        universe_size: usize,
+    ) -> Self {
+        Self {
+            api_client,
+            cross_rate_service,
+            symbol_repo,
+            ticker_repo,
+            trade_repo,
+            liquidity_repo,
+            watchlist_repo,
+            candidates,
+            universe_size,
+        }
+    }
+}
+
+#[async_trait]
+impl LiquidityService for LiquidityServiceImpl {
+    async fn score_symbols(&self, exchange: &str) -> Result<()> {
+        info!("Scoring symbol liquidity for exchange: {}", exchange);
+        let now = Utc::now();
+        let symbols = self.symbol_repo.find_all(exchange).await?;
+        let tickers: HashMap<String, _> = self
+            .ticker_repo
+            .find_all(exchange)
+            .await?
+            .into_iter()
+            .map(|t| (t.symbol.clone(), t))
+            .collect();
+        let graph = self.cross_rate_service.graph(exchange).await?;
+
+        // Rank by 24h turnover valued in USDT first so only the most active
+        // symbols cost an order book and trade history request each.
+        let mut ranked: Vec<(&str, Decimal, Decimal)> = symbols
+            .iter()
+            .filter(|s| s.enable_trading)
+            .filter_map(|s| {
+                let vol_value = tickers.get(&s.symbol)?.vol_value?;
+                let rate = graph
+                    .find_path(
+                        &s.quote_currency,
+                        VALUATION_CURRENCY,
+                        PathPreference::FewestHops,
+                        now,
+                    )?
+                    .price;
+                Some((s.symbol.as_str(), vol_value * rate, rate))
+            })
+            .collect();
+        ranked.sort_by_key(|r| std::cmp::Reverse(r.1));
+        ranked.truncate(self.candidates);
+
+        let mut inputs = Vec::new();
+        for (symbol, volume_usdt, rate) in ranked {
+            let book = match self.api_client.fetch_partial_order_book(symbol).await {
+                Ok(book) => book,
+                Err(e) => {
+                    warn!("Failed to fetch order book for {}: {}", symbol, e);
+                    continue;
+                }
+            };
+            let Some((depth, spread)) = depth_within(&book, Decimal::new(2, 2)) else {
+                continue;
+            };
+
+            let trades = match self.api_client.fetch_trades(symbol).await {
+                Ok(trades) => trades,
+                Err(e) => {
+                    warn!("Failed to fetch trades for {}: {}", symbol, e);
+                    continue;
+                }
+            };
+            self.trade_repo.save(exchange, &trades).await?;
+
+            inputs.push(LiquidityInputs::new(
+                symbol.to_string(),
+                to_f64(volume_usdt),
+                to_f64(spread),
+                to_f64(depth * rate),
+                trades_per_hour(&trades),
+            ));
+        }
+
+        let scores = score_liquidity(inputs, now);
+        self.liquidity_repo.save(exchange, &scores).await?;
+
+        let universe: Vec<String> = scores
+            .iter()
+            .take(self.universe_size)
+            .map(|s| s.inputs.symbol.clone())
+            .collect();
+        self.watchlist_repo
+            .replace(exchange, LIQUID_UNIVERSE, &universe)
+            .await?;
+
+        info!(
+            "Scored {} symbols, {} in the liquid universe",
+            scores.len(),
+            universe.len()
+        );
+        Ok(())
+    }
+}
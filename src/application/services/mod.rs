@@ -1 +1,13 @@
+pub mod account_service;
+pub mod analytics_service;
+pub mod announcement_service;
+pub mod candle_service;
+pub mod cross_rate_service;
+pub mod fee_service;
+pub mod futures_service;
+pub mod liquidity_service;
 pub mod monitoring_service;
+pub mod order_book_service;
+pub mod portfolio_service;
+pub mod private_feed_service;
+pub mod status_service;
@@ -1,40 +1,120 @@
+use crate::domain::entities::data_quality::DataQualityIssue;
+use crate::domain::entities::event::Event;
+use crate::domain::entities::liquidity::LIQUID_UNIVERSE;
 use crate::domain::repositories::currency_repository::CurrencyRepository;
+use crate::domain::repositories::event_repository::EventRepository;
+use crate::domain::repositories::fiat_price_repository::FiatPriceRepository;
+use crate::domain::repositories::lending_repository::LendingRepository;
+use crate::domain::repositories::margin_repository::MarginRepository;
+use crate::domain::repositories::market_repository::MarketRepository;
 use crate::domain::repositories::symbol_repository::SymbolRepository;
 use crate::domain::repositories::ticker_repository::TickerRepository;
+use crate::domain::repositories::watchlist_repository::WatchlistRepository;
 use crate::infrastructure::api::api_client::ApiClient;
 use anyhow::Result;
 use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
-use tracing::info;
+use tracing::{info, warn};
+
+const MARKET_INTRODUCED_EVENT: &str = "market_introduced";
+const MARKET_RETIRED_EVENT: &str = "market_retired";
+const MARKET_UNKNOWN_EVENT: &str = "market_unknown";
+const DATA_QUALITY_EVENT: &str = "data_quality_error";
+
+/// Stores one data-quality event per rejected row so the report covers every
+/// feed, not just the log.
+pub(crate) async fn record_data_quality(
+    event_repo: &dyn EventRepository,
+    exchange: &str,
+    issues: &[DataQualityIssue],
+) -> Result<()> {
+    if issues.is_empty() {
+        return Ok(());
+    }
+
+    let events: Vec<Event> = issues
+        .iter()
+        .map(|issue| {
+            warn!("Skipping row: {}", issue);
+            Event::new(
+                DATA_QUALITY_EVENT.to_string(),
+                issue.symbol.clone(),
+                issue.to_string(),
+                serde_json::json!({
+                    "symbol": issue.symbol,
+                    "field": issue.field,
+                    "value": issue.value,
+                    "reason": issue.reason,
+                }),
+            )
+        })
+        .collect();
+    event_repo.save(exchange, &events).await
+}
 
 #[async_trait]
 pub trait MonitoringService: Send + Sync {
     async fn fetch_and_save_currencies(&self, exchange: &str) -> Result<()>;
     async fn fetch_and_save_symbols(&self, exchange: &str) -> Result<()>;
+    async fn fetch_and_save_markets(&self, exchange: &str) -> Result<()>;
     async fn fetch_and_save_tickers(&self, exchange: &str) -> Result<()>;
+    async fn fetch_and_save_watchlist_stats(&self, exchange: &str) -> Result<()>;
+    async fn fetch_and_save_margin_metadata(&self, exchange: &str) -> Result<()>;
+    async fn fetch_and_save_lending_rates(&self, exchange: &str) -> Result<()>;
+    async fn fetch_and_save_fiat_prices(&self, exchange: &str) -> Result<()>;
 }
 
 pub struct MonitoringServiceImpl {
     api_client: Arc<dyn ApiClient>,
     currency_repo: Arc<dyn CurrencyRepository>,
     symbol_repo: Arc<dyn SymbolRepository>,
+    market_repo: Arc<dyn MarketRepository>,
+    event_repo: Arc<dyn EventRepository>,
     ticker_repo: Arc<dyn TickerRepository>,
+    margin_repo: Arc<dyn MarginRepository>,
+    lending_repo: Arc<dyn LendingRepository>,
+    fiat_price_repo: Arc<dyn FiatPriceRepository>,
+    watchlist_repo: Arc<dyn WatchlistRepository>,
+    fiat_bases: Vec<String>,
+    watchlist_symbols: Vec<String>,
 }
 
 impl MonitoringServiceImpl {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         api_client: Arc<dyn ApiClient>,
         currency_repo: Arc<dyn CurrencyRepository>,
         symbol_repo: Arc<dyn SymbolRepository>,
+        market_repo: Arc<dyn MarketRepository>,
+        event_repo: Arc<dyn EventRepository>,
         ticker_repo: Arc<dyn TickerRepository>,
+        margin_repo: Arc<dyn MarginRepository>,
+        lending_repo: Arc<dyn LendingRepository>,
+        fiat_price_repo: Arc<dyn FiatPriceRepository>,
+        watchlist_repo: Arc<dyn WatchlistRepository>,
+        fiat_bases: Vec<String>,
+        watchlist_symbols: Vec<String>,
     ) -> Self {
         Self {
             api_client,
             currency_repo,
             symbol_repo,
+            market_repo,
+            event_repo,
             ticker_repo,
+            margin_repo,
+            lending_repo,
+            fiat_price_repo,
+            watchlist_repo,
+            fiat_bases,
+            watchlist_symbols,
         }
     }
+
+    async fn record_data_quality(&self, exchange: &str, issues: &[DataQualityIssue]) -> Result<()> {
+        record_data_quality(self.event_repo.as_ref(), exchange, issues).await
+    }
 }
 
 #[async_trait]
@@ -49,17 +129,226 @@ impl MonitoringService for MonitoringServiceImpl {
 
     async fn fetch_and_save_symbols(&self, exchange: &str) -> Result<()> {
         info!("Fetching symbols for exchange: {}", exchange);
-        let symbols = self.api_client.fetch_symbols().await?;
+        let (symbols, issues) = self.api_client.fetch_symbols().await?;
+        self.record_data_quality(exchange, &issues).await?;
+
+        let markets: HashSet<String> = self
+            .market_repo
+            .find_active(exchange)
+            .await?
+            .into_iter()
+            .collect();
+        if !markets.is_empty() {
+            // Only symbols that are new or moved market are reported, so an
+            // unknown market raises one event rather than one per run.
+            let stored: HashMap<String, String> = self
+                .symbol_repo
+                .find_all(exchange)
+                .await?
+                .into_iter()
+                .map(|s| (s.symbol, s.market))
+                .collect();
+            let mut unknown: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+            for symbol in symbols.iter().filter(|s| {
+                !markets.contains(&s.market) && stored.get(&s.symbol) != Some(&s.market)
+            }) {
+                unknown
+                    .entry(symbol.market.as_str())
+                    .or_default()
+                    .push(symbol.symbol.as_str());
+            }
+
+            let events: Vec<Event> = unknown
+                .into_iter()
+                .map(|(market, symbols)| {
+                    warn!(
+                        "{} symbols reference unknown market '{}'",
+                        symbols.len(),
+                        market
+                    );
+                    Event::new(
+                        MARKET_UNKNOWN_EVENT.to_string(),
+                        market.to_string(),
+                        format!(
+                            "{} symbols reference unknown market '{}'",
+                            symbols.len(),
+                            market
+                        ),
+                        serde_json::json!({ "market": market, "symbols": symbols }),
+                    )
+                })
+                .collect();
+            if !events.is_empty() {
+                self.event_repo.save(exchange, &events).await?;
+            }
+        }
+
         self.symbol_repo.save(exchange, &symbols).await?;
         info!("Saved {} symbols", symbols.len());
         Ok(())
     }
 
+    async fn fetch_and_save_markets(&self, exchange: &str) -> Result<()> {
+        info!("Fetching markets for exchange: {}", exchange);
+        let markets = self.api_client.fetch_markets().await?;
+        let known: HashSet<String> = self
+            .market_repo
+            .find_active(exchange)
+            .await?
+            .into_iter()
+            .collect();
+        let current: HashSet<String> = markets.iter().cloned().collect();
+
+        let mut events = Vec::new();
+        if !known.is_empty() {
+            for market in current.difference(&known) {
+                warn!("Market '{}' introduced on {}", market, exchange);
+                events.push(Event::new(
+                    MARKET_INTRODUCED_EVENT.to_string(),
+                    market.clone(),
+                    format!("Market '{}' introduced", market),
+                    serde_json::json!({ "market": market }),
+                ));
+            }
+        }
+        for market in known.difference(&current) {
+            let symbols: Vec<String> = self
+                .symbol_repo
+                .find_by_market(exchange, market)
+                .await?
+                .into_iter()
+                .map(|s| s.symbol)
+                .collect();
+            warn!(
+                "Market '{}' retired on {} with {} symbols still assigned",
+                market,
+                exchange,
+                symbols.len()
+            );
+            events.push(Event::new(
+                MARKET_RETIRED_EVENT.to_string(),
+                market.clone(),
+                format!("Market '{}' retired", market),
+                serde_json::json!({ "market": market, "symbols": symbols }),
+            ));
+        }
+
+        self.market_repo.save(exchange, &markets).await?;
+        self.event_repo.save(exchange, &events).await?;
+        info!("Saved {} markets", markets.len());
+        Ok(())
+    }
+
     async fn fetch_and_save_tickers(&self, exchange: &str) -> Result<()> {
         info!("Fetching tickers for exchange: {}", exchange);
-        let tickers = self.api_client.fetch_tickers().await?;
+        let (tickers, issues) = self.api_client.fetch_tickers().await?;
+        self.record_data_quality(exchange, &issues).await?;
         self.ticker_repo.save(exchange, &tickers).await?;
         info!("Saved {} tickers", tickers.len());
         Ok(())
     }
+
+    async fn fetch_and_save_watchlist_stats(&self, exchange: &str) -> Result<()> {
+        // Configured symbols first, then the liquid universe picked by the
+        // liquidity scoring job.
+        let mut symbols = self.watchlist_symbols.clone();
+        for symbol in self
+            .watchlist_repo
+            .find_symbols(exchange, LIQUID_UNIVERSE)
+            .await?
+        {
+            if !symbols.contains(&symbol) {
+                symbols.push(symbol);
+            }
+        }
+        if symbols.is_empty() {
+            return Ok(());
+        }
+
+        // allTickers carries the same 24h stats for every symbol in one
+        // request, display names included.
+        let watched: HashSet<&str> = symbols.iter().map(String::as_str).collect();
+        let (mut tickers, mut issues) = self.api_client.fetch_tickers().await?;
+        tickers.retain(|t| watched.contains(t.symbol.as_str()));
+        issues.retain(|i| watched.contains(i.symbol.as_str()));
+        self.record_data_quality(exchange, &issues).await?;
+        self.ticker_repo.save(exchange, &tickers).await?;
+        info!("Saved stats for {} watchlist symbols", tickers.len());
+        Ok(())
+    }
+
+    async fn fetch_and_save_margin_metadata(&self, exchange: &str) -> Result<()> {
+        info!("Fetching margin metadata for exchange: {}", exchange);
+
+        let cross_symbols = self.api_client.fetch_cross_margin_symbols().await?;
+        self.margin_repo
+            .save_cross_symbols(exchange, &cross_symbols)
+            .await?;
+
+        let isolated_symbols = self.api_client.fetch_isolated_margin_symbols().await?;
+        self.margin_repo
+            .save_isolated_symbols(exchange, &isolated_symbols)
+            .await?;
+
+        let config = self.api_client.fetch_margin_config().await?;
+        self.margin_repo.save_config(exchange, &config).await?;
+
+        info!(
+            "Saved {} cross and {} isolated margin symbols",
+            cross_symbols.len(),
+            isolated_symbols.len()
+        );
+        Ok(())
+    }
+
+    async fn fetch_and_save_lending_rates(&self, exchange: &str) -> Result<()> {
+        info!("Fetching lending markets for exchange: {}", exchange);
+        let markets = self.api_client.fetch_lending_markets().await?;
+        self.lending_repo.save_markets(exchange, &markets).await?;
+
+        let mut rates = Vec::new();
+        for market in &markets {
+            match self
+                .api_client
+                .fetch_lending_interest_rates(&market.currency)
+                .await
+            {
+                Ok(currency_rates) => rates.extend(currency_rates),
+                Err(e) => warn!(
+                    "Failed to fetch interest rates for {}: {}",
+                    market.currency, e
+                ),
+            }
+        }
+        self.lending_repo
+            .save_interest_rates(exchange, &rates)
+            .await?;
+
+        info!(
+            "Saved {} lending markets and {} interest rates",
+            markets.len(),
+            rates.len()
+        );
+        Ok(())
+    }
+
+    async fn fetch_and_save_fiat_prices(&self, exchange: &str) -> Result<()> {
+        info!("Fetching fiat prices for exchange: {}", exchange);
+
+        let mut prices = Vec::new();
+        for base in &self.fiat_bases {
+            match self.api_client.fetch_fiat_prices(base).await {
+                Ok(base_prices) => prices.extend(base_prices),
+                Err(e) => warn!("Failed to fetch fiat prices in {}: {}", base, e),
+            }
+        }
+        self.fiat_price_repo.save(exchange, &prices).await?;
+
+        info!(
+            "Saved {} fiat prices across {} bases",
+            prices.len(),
+            self.fiat_bases.len()
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,186 @@
+use crate::domain::entities::order_book::{
+    ApplyOutcome, OrderBook, OrderBookDepth, OrderBookSnapshot, OrderBookUpdate,
+};
+use crate::domain::repositories::order_book_repository::OrderBookRepository;
+use crate::infrastructure::api::api_client::ApiClient;
+use crate::infrastructure::ws::market_stream_client::{Level2Stream, MarketStreamClient};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use futures::StreamExt;
+use futures::stream::FuturesUnordered;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+const ORDER_BOOK_DEPTH: usize = 20;
+const SEED_CONCURRENCY: usize = 5;
+const RECONNECT_DELAY_MIN: Duration = Duration::from_secs(1);
+const RECONNECT_DELAY_MAX: Duration = Duration::from_secs(60);
+
+#[async_trait]
+pub trait OrderBookService: Send + Sync {
+    async fn run(&self, exchange: &str);
+    fn top_of_book(&self, symbol: &str, levels: usize) -> Option<OrderBookDepth>;
+    async fn save_snapshots(&self, exchange: &str) -> Result<()>;
+}
+
+pub struct OrderBookServiceImpl {
+    api_client: Arc<dyn ApiClient>,
+    stream_client: Arc<dyn MarketStreamClient>,
+    order_book_repo: Arc<dyn OrderBookRepository>,
+    watchlist_symbols: Vec<String>,
+    books: RwLock<HashMap<String, OrderBook>>,
+}
+
+impl OrderBookServiceImpl {
+    pub fn new(
+        api_client: Arc<dyn ApiClient>,
+        stream_client: Arc<dyn MarketStreamClient>,
+        order_book_repo: Arc<dyn OrderBookRepository>,
+        watchlist_symbols: Vec<String>,
+    ) -> Self {
+        Self {
+            api_client,
+            stream_client,
+            order_book_repo,
+            watchlist_symbols,
+            books: RwLock::new(HashMap::new()),
+        }
+    }
+
+    async fn fetch_snapshot(&self, symbol: String) -> (String, Result<OrderBookSnapshot>) {
+        let snapshot = self.api_client.fetch_order_book(&symbol).await;
+        (symbol, snapshot)
+    }
+
+    // Books are seeded over REST while the stream keeps being read, so the
+    // connection is still pinged. Updates for a book that is being seeded are
+    // buffered and replayed on top of its snapshot.
+    async fn consume(&self, stream: &mut Box<dyn Level2Stream>) -> Result<()> {
+        let mut queue: VecDeque<String> = self.watchlist_symbols.iter().cloned().collect();
+        let mut buffered: HashMap<String, Vec<OrderBookUpdate>> =
+            queue.iter().map(|s| (s.clone(), Vec::new())).collect();
+        let mut seeding = FuturesUnordered::new();
+
+        loop {
+            while seeding.len() < SEED_CONCURRENCY
+                && let Some(symbol) = queue.pop_front()
+            {
+                seeding.push(self.fetch_snapshot(symbol));
+            }
+
+            tokio::select! {
+                Some((symbol, snapshot)) = seeding.next(), if !seeding.is_empty() => {
+                    let snapshot = snapshot
+                        .with_context(|| format!("Failed to seed {} order book", symbol))?;
+                    info!("Seeded {} order book at sequence {}", symbol, snapshot.sequence);
+
+                    let mut book = OrderBook::from_snapshot(snapshot);
+                    let updates = buffered.remove(&symbol).unwrap_or_default();
+                    if updates.iter().any(|u| needs_resync(&mut book, u)) {
+                        buffered.insert(symbol.clone(), Vec::new());
+                        queue.push_back(symbol);
+                    } else {
+                        self.books
+                            .write()
+                            .expect("order book lock poisoned")
+                            .insert(symbol, book);
+                    }
+                }
+                update = stream.next_update() => {
+                    let update = update?;
+                    if let Some(pending) = buffered.get_mut(&update.symbol) {
+                        pending.push(update);
+                        continue;
+                    }
+
+                    let mut books = self.books.write().expect("order book lock poisoned");
+                    let Some(book) = books.get_mut(&update.symbol) else {
+                        continue;
+                    };
+                    if needs_resync(book, &update) {
+                        books.remove(&update.symbol);
+                        buffered.insert(update.symbol.clone(), Vec::new());
+                        queue.push_back(update.symbol);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn needs_resync(book: &mut OrderBook, update: &OrderBookUpdate) -> bool {
+    match book.apply(update) {
+        ApplyOutcome::Stale => false,
+        ApplyOutcome::Applied => match book.check() {
+            Ok(()) => false,
+            Err(reason) => {
+                warn!("Inconsistent order book, resyncing: {}", reason);
+                true
+            }
+        },
+        ApplyOutcome::Gap => {
+            warn!(
+                "Sequence gap on {} at {}, resyncing",
+                update.symbol, update.sequence_start
+            );
+            true
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookService for OrderBookServiceImpl {
+    async fn run(&self, exchange: &str) {
+        if self.watchlist_symbols.is_empty() {
+            return;
+        }
+        let mut delay = RECONNECT_DELAY_MIN;
+
+        loop {
+            match self
+                .stream_client
+                .connect_level2(&self.watchlist_symbols)
+                .await
+            {
+                Ok(mut stream) => {
+                    info!("Level2 feed connected for exchange: {}", exchange);
+                    delay = RECONNECT_DELAY_MIN;
+
+                    if let Err(e) = self.consume(&mut stream).await {
+                        warn!("Level2 feed disconnected: {:#}", e);
+                    }
+                }
+                Err(e) => error!("Level2 feed connection failed: {}", e),
+            }
+
+            self.books
+                .write()
+                .expect("order book lock poisoned")
+                .clear();
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(RECONNECT_DELAY_MAX);
+        }
+    }
+
+    fn top_of_book(&self, symbol: &str, levels: usize) -> Option<OrderBookDepth> {
+        self.books
+            .read()
+            .expect("order book lock poisoned")
+            .get(symbol)
+            .map(|book| book.depth(levels))
+    }
+
+    async fn save_snapshots(&self, exchange: &str) -> Result<()> {
+        let depths: Vec<OrderBookDepth> = self
+            .watchlist_symbols
+            .iter()
+            .filter_map(|symbol| self.top_of_book(symbol, ORDER_BOOK_DEPTH))
+            .collect();
+
+        self.order_book_repo.save(exchange, &depths).await?;
+        info!("Saved {} order book snapshots", depths.len());
+        Ok(())
+    }
+}
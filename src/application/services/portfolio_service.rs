@@ -0,0 +1,202 @@
+use crate::application::services::cross_rate_service::CrossRateService;
+use crate::domain::entities::portfolio::PortfolioBalance;
+use crate::domain::repositories::balance_snapshot_repository::BalanceSnapshotRepository;
+use crate::domain::repositories::fill_repository::FillRepository;
+use crate::domain::repositories::portfolio_repository::PortfolioRepository;
+use crate::domain::repositories::sub_account_repository::SubAccountRepository;
+use crate::domain::repositories::symbol_repository::SymbolRepository;
+use crate::domain::services::portfolio_valuation::{pnl_from_fills, value_balances};
+use crate::infrastructure::api::api_client::ApiClient;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::info;
+
+// Snapshots valued per run, so a long backlog is worked off over several runs.
+const VALUATION_BATCH: i64 = 50;
+
+#[async_trait]
+pub trait PortfolioService: Send + Sync {
+    async fn sync_sub_accounts(&self, exchange: &str) -> Result<()>;
+    async fn snapshot_portfolio(&self, exchange: &str) -> Result<()>;
+    async fn value_portfolio(&self, exchange: &str) -> Result<()>;
+}
+
+pub struct PortfolioServiceImpl {
+    api_client: Arc<dyn ApiClient>,
+    sub_account_repo: Arc<dyn SubAccountRepository>,
+    portfolio_repo: Arc<dyn PortfolioRepository>,
+    balance_snapshot_repo: Arc<dyn BalanceSnapshotRepository>,
+    fill_repo: Arc<dyn FillRepository>,
+    symbol_repo: Arc<dyn SymbolRepository>,
+    cross_rate_service: Arc<dyn CrossRateService>,
+}
+
+impl PortfolioServiceImpl {
+    pub fn new(
+        api_client: Arc<dyn ApiClient>,
+        sub_account_repo: Arc<dyn SubAccountRepository>,
+        portfolio_repo: Arc<dyn PortfolioRepository>,
+        balance_snapshot_repo: Arc<dyn BalanceSnapshotRepository>,
+        fill_repo: Arc<dyn FillRepository>,
+        symbol_repo: Arc<dyn SymbolRepository>,
+        cross_rate_service: Arc<dyn CrossRateService>,
+    ) -> Self {
+        Self {
+            api_client,
+            sub_account_repo,
+            portfolio_repo,
+            balance_snapshot_repo,
+            fill_repo,
+            symbol_repo,
+            cross_rate_service,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Totals {
+    balance: Decimal,
+    available: Decimal,
+    holds: Decimal,
+    accounts: i32,
+}
+
+impl Totals {
+    fn add(&mut self, balance: &str, available: &str, holds: &str) -> Result<()> {
+        self.balance += parse_amount(balance)?;
+        self.available += parse_amount(available)?;
+        self.holds += parse_amount(holds)?;
+        self.accounts += 1;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PortfolioService for PortfolioServiceImpl {
+    async fn sync_sub_accounts(&self, exchange: &str) -> Result<()> {
+        info!("Syncing sub-accounts for exchange: {}", exchange);
+        let accounts = self.api_client.fetch_sub_accounts().await?;
+        self.sub_account_repo
+            .save_accounts(exchange, &accounts)
+            .await?;
+        info!("Saved {} sub-accounts", accounts.len());
+        Ok(())
+    }
+
+    async fn snapshot_portfolio(&self, exchange: &str) -> Result<()> {
+        info!("Snapshotting portfolio for exchange: {}", exchange);
+        let snapshot_at = Utc::now();
+
+        let master_balances = self.api_client.fetch_account_balances().await?;
+        let sub_balances = self.api_client.fetch_sub_account_balances().await?;
+        self.sub_account_repo
+            .save_balances(exchange, snapshot_at, &sub_balances)
+            .await?;
+
+        let mut totals: BTreeMap<String, Totals> = BTreeMap::new();
+        for b in &master_balances {
+            totals.entry(b.currency.clone()).or_default().add(
+                &b.balance,
+                &b.available,
+                &b.holds,
+            )?;
+        }
+        for b in &sub_balances {
+            totals.entry(b.currency.clone()).or_default().add(
+                &b.balance,
+                &b.available,
+                &b.holds,
+            )?;
+        }
+
+        let portfolio: Vec<PortfolioBalance> = totals
+            .into_iter()
+            .filter(|(_, t)| !t.balance.is_zero())
+            .map(|(currency, t)| {
+                PortfolioBalance::new(
+                    currency,
+                    t.balance.normalize().to_string(),
+                    t.available.normalize().to_string(),
+                    t.holds.normalize().to_string(),
+                    t.accounts,
+                )
+            })
+            .collect();
+        self.portfolio_repo
+            .save(exchange, snapshot_at, &portfolio)
+            .await?;
+
+        info!(
+            "Saved portfolio of {} currencies across master and {} sub-account balances",
+            portfolio.len(),
+            sub_balances.len()
+        );
+        Ok(())
+    }
+
+    async fn value_portfolio(&self, exchange: &str) -> Result<()> {
+        // Every snapshot after the last valued one is valued at the rates
+        // stored as of its own time.
+        let after = self
+            .portfolio_repo
+            .find_latest_valuation_at(exchange)
+            .await?
+            .unwrap_or(DateTime::UNIX_EPOCH);
+        let snapshots = self
+            .balance_snapshot_repo
+            .find_after(exchange, after, VALUATION_BATCH)
+            .await?;
+        if snapshots.is_empty() {
+            info!("No balance snapshot to value for exchange: {}", exchange);
+            return Ok(());
+        }
+        let symbols = self.symbol_repo.find_all(exchange).await?;
+
+        for (snapshot_at, balances) in snapshots {
+            let graph = self
+                .cross_rate_service
+                .graph_at(exchange, snapshot_at)
+                .await?;
+
+            // PnL carries on from the previous snapshot with only the fills since.
+            let (since, previous) = self
+                .portfolio_repo
+                .find_pnl_before(exchange, snapshot_at)
+                .await?
+                .unwrap_or((DateTime::UNIX_EPOCH, Vec::new()));
+            let fills = self
+                .fill_repo
+                .find_with_symbols(exchange, since, snapshot_at)
+                .await?;
+            let pnl = pnl_from_fills(&previous, &fills, &symbols, &graph, snapshot_at)?;
+            self.portfolio_repo
+                .save_pnl(exchange, snapshot_at, &pnl)
+                .await?;
+
+            // Saved last: the valuation marks the snapshot as done.
+            let valuations = value_balances(&balances, &graph, snapshot_at)?;
+            self.portfolio_repo
+                .save_valuations(exchange, &valuations)
+                .await?;
+
+            let total: Decimal = valuations.iter().map(|v| v.value_usdt).sum();
+            info!(
+                "Valued {} accounts at {} USDT for snapshot {}",
+                valuations.len(),
+                total.normalize(),
+                snapshot_at
+            );
+        }
+        Ok(())
+    }
+}
+
+fn parse_amount(value: &str) -> Result<Decimal> {
+    value
+        .parse()
+        .with_context(|| format!("Invalid balance amount: {}", value))
+}
@@ -0,0 +1,122 @@
+use crate::application::services::account_service::AccountService;
+use crate::domain::repositories::balance_change_repository::BalanceChangeRepository;
+use crate::domain::repositories::order_event_repository::OrderEventRepository;
+use crate::domain::services::feed_continuity::FeedContinuity;
+use crate::infrastructure::ws::private_stream_client::{
+    PrivateEvent, PrivateStream, PrivateStreamClient,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+const RECONNECT_DELAY_MIN: Duration = Duration::from_secs(1);
+const RECONNECT_DELAY_MAX: Duration = Duration::from_secs(60);
+
+#[async_trait]
+pub trait PrivateFeedService: Send + Sync {
+    async fn run(&self, exchange: &str);
+}
+
+pub struct PrivateFeedServiceImpl {
+    stream_client: Arc<dyn PrivateStreamClient>,
+    account_service: Arc<dyn AccountService>,
+    balance_change_repo: Arc<dyn BalanceChangeRepository>,
+    order_event_repo: Arc<dyn OrderEventRepository>,
+}
+
+impl PrivateFeedServiceImpl {
+    pub fn new(
+        stream_client: Arc<dyn PrivateStreamClient>,
+        account_service: Arc<dyn AccountService>,
+        balance_change_repo: Arc<dyn BalanceChangeRepository>,
+        order_event_repo: Arc<dyn OrderEventRepository>,
+    ) -> Self {
+        Self {
+            stream_client,
+            account_service,
+            balance_change_repo,
+            order_event_repo,
+        }
+    }
+
+    async fn resync(&self, exchange: &str) {
+        if let Err(e) = self.account_service.snapshot_balances(exchange).await {
+            warn!("Balance resync after reconnect failed: {}", e);
+        }
+        if let Err(e) = self.account_service.sync_orders(exchange).await {
+            warn!("Order resync after reconnect failed: {}", e);
+        }
+    }
+
+    // REST resyncs after a gap run in the background so the stream keeps
+    // being read, and pinged, meanwhile.
+    fn resync_in_background(&self, exchange: &str, balances: bool) {
+        let account_service = self.account_service.clone();
+        let exchange = exchange.to_string();
+        tokio::spawn(async move {
+            let result = if balances {
+                account_service.snapshot_balances(&exchange).await
+            } else {
+                account_service.sync_orders(&exchange).await
+            };
+            if let Err(e) = result {
+                warn!("Resync after private feed gap failed: {}", e);
+            }
+        });
+    }
+
+    async fn consume(&self, exchange: &str, stream: &mut Box<dyn PrivateStream>) -> Result<()> {
+        let mut continuity = FeedContinuity::default();
+        loop {
+            match stream.next_event().await? {
+                PrivateEvent::Balance(change) => {
+                    if !continuity.check_balance(&change) {
+                        warn!(
+                            "Missed balance update for {} ({}), resyncing",
+                            change.currency, change.account_id
+                        );
+                        self.resync_in_background(exchange, true);
+                    }
+                    self.balance_change_repo.save(exchange, &[change]).await?
+                }
+                PrivateEvent::Order(event) => {
+                    if !continuity.check_order(&event) {
+                        warn!(
+                            "Missed order update for {} ({}), resyncing",
+                            event.order_id, event.symbol
+                        );
+                        self.resync_in_background(exchange, false);
+                    }
+                    self.order_event_repo.save(exchange, &[event]).await?
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl PrivateFeedService for PrivateFeedServiceImpl {
+    async fn run(&self, exchange: &str) {
+        let mut delay = RECONNECT_DELAY_MIN;
+
+        loop {
+            match self.stream_client.connect().await {
+                Ok(mut stream) => {
+                    info!("Private feed connected for exchange: {}", exchange);
+                    delay = RECONNECT_DELAY_MIN;
+                    self.resync(exchange).await;
+
+                    if let Err(e) = self.consume(exchange, &mut stream).await {
+                        warn!("Private feed disconnected: {}", e);
+                    }
+                }
+                Err(e) => error!("Private feed connection failed: {}", e),
+            }
+
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(RECONNECT_DELAY_MAX);
+        }
+    }
+}
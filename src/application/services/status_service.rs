@@ -0,0 +1,105 @@
+use crate::domain::entities::job_run::JobRun;
+use crate::domain::repositories::exchange_status_repository::ExchangeStatusRepository;
+use crate::domain::repositories::job_run_repository::JobRunRepository;
+use crate::infrastructure::api::api_client::ApiClient;
+use crate::infrastructure::api::futures_api_client::FuturesApiClient;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tracing::{error, info, warn};
+
+#[async_trait]
+pub trait StatusService: Send + Sync {
+    async fn fetch_and_save_status(&self, exchange: &str) -> Result<()>;
+    async fn fetch_and_save_futures_status(&self, exchange: &str) -> Result<()>;
+    async fn record_job_run(
+        &self,
+        job_name: &str,
+        exchange: &str,
+        started_at: DateTime<Utc>,
+        result: &Result<()>,
+    );
+}
+
+pub struct StatusServiceImpl {
+    api_client: Arc<dyn ApiClient>,
+    futures_api_client: Arc<dyn FuturesApiClient>,
+    exchange_status_repo: Arc<dyn ExchangeStatusRepository>,
+    job_run_repo: Arc<dyn JobRunRepository>,
+}
+
+impl StatusServiceImpl {
+    pub fn new(
+        api_client: Arc<dyn ApiClient>,
+        futures_api_client: Arc<dyn FuturesApiClient>,
+        exchange_status_repo: Arc<dyn ExchangeStatusRepository>,
+        job_run_repo: Arc<dyn JobRunRepository>,
+    ) -> Self {
+        Self {
+            api_client,
+            futures_api_client,
+            exchange_status_repo,
+            job_run_repo,
+        }
+    }
+}
+
+#[async_trait]
+impl StatusService for StatusServiceImpl {
+    async fn fetch_and_save_status(&self, exchange: &str) -> Result<()> {
+        info!("Fetching service status for exchange: {}", exchange);
+        let status = self.api_client.fetch_service_status().await?;
+        self.exchange_status_repo.save(exchange, &status).await?;
+        info!("Exchange '{}' reports status '{}'", exchange, status.status);
+        Ok(())
+    }
+
+    async fn fetch_and_save_futures_status(&self, exchange: &str) -> Result<()> {
+        info!("Fetching service status for exchange: {}", exchange);
+        let status = self.futures_api_client.fetch_service_status().await?;
+        self.exchange_status_repo.save(exchange, &status).await?;
+        info!("Exchange '{}' reports status '{}'", exchange, status.status);
+        Ok(())
+    }
+
+    async fn record_job_run(
+        &self,
+        job_name: &str,
+        exchange: &str,
+        started_at: DateTime<Utc>,
+        result: &Result<()>,
+    ) {
+        let status = match self.exchange_status_repo.find_current(exchange).await {
+            Ok(status) => status,
+            Err(e) => {
+                warn!("Failed to read status of exchange '{}': {}", exchange, e);
+                None
+            }
+        };
+        let during_maintenance = status.as_ref().is_some_and(|s| s.is_maintenance());
+
+        if let Err(e) = result {
+            if during_maintenance {
+                warn!(
+                    "{} failed while exchange '{}' is in maintenance: {}",
+                    job_name, exchange, e
+                );
+            } else {
+                error!("{} failed: {}", job_name, e);
+            }
+        }
+
+        let run = JobRun::new(
+            job_name.to_string(),
+            started_at,
+            Utc::now(),
+            result.as_ref().err().map(|e| format!("{:#}", e)),
+            status.map(|s| s.status),
+            during_maintenance,
+        );
+        if let Err(e) = self.job_run_repo.save(exchange, &run).await {
+            warn!("Failed to record run of '{}': {}", job_name, e);
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountBalance {
+    pub account_id: String,
+    pub currency: String,
+    pub account_type: String,
+    pub balance: String,
+    pub available: String,
+    pub holds: String,
+}
+
+impl AccountBalance {
+    pub fn new(
+        account_id: String,
+        currency: String,
+        account_type: String,
+        balance: String,
+        available: String,
+        holds: String,
+    ) -> Self {
+        Self {
+            account_id,
+            currency,
+            account_type,
+            balance,
+            available,
+            holds,
+        }
+    }
+}
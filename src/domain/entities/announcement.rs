@@ -0,0 +1,55 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+pub const LINK_SYMBOL: &str = "symbol";
+pub const LINK_CURRENCY: &str = "currency";
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Announcement {
+    pub id: i64,
+    pub title: String,
+    pub types: Vec<String>,
+    pub description: String,
+    pub url: String,
+    pub language: String,
+    pub published_at: DateTime<Utc>,
+}
+
+impl Announcement {
+    pub fn new(
+        id: i64,
+        title: String,
+        types: Vec<String>,
+        description: String,
+        url: String,
+        language: String,
+        published_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            title,
+            types,
+            description,
+            url,
+            language,
+            published_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AnnouncementLink {
+    pub announcement_id: i64,
+    pub link_type: String,
+    pub target: String,
+}
+
+impl AnnouncementLink {
+    pub fn new(announcement_id: i64, link_type: String, target: String) -> Self {
+        Self {
+            announcement_id,
+            link_type,
+            target,
+        }
+    }
+}
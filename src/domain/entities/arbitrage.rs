@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+pub const SIDE_BUY: &str = "buy";
+pub const SIDE_SELL: &str = "sell";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArbitrageLeg {
+    pub symbol: String,
+    pub side: String,
+    pub from: String,
+    pub to: String,
+    pub price: Decimal,
+    pub fee_rate: Decimal,
+    pub time_point: DateTime<Utc>,
+}
+
+impl ArbitrageLeg {
+    pub fn new(
+        symbol: String,
+        side: String,
+        from: String,
+        to: String,
+        price: Decimal,
+        fee_rate: Decimal,
+        time_point: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            symbol,
+            side,
+            from,
+            to,
+            price,
+            fee_rate,
+            time_point,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArbitrageOpportunity {
+    pub cycle: String,
+    pub legs: Vec<ArbitrageLeg>,
+    pub gross_edge: Decimal,
+    pub net_edge: Decimal,
+    pub observed_at: DateTime<Utc>,
+}
+
+impl ArbitrageOpportunity {
+    pub fn new(
+        cycle: String,
+        legs: Vec<ArbitrageLeg>,
+        gross_edge: Decimal,
+        net_edge: Decimal,
+        observed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            cycle,
+            legs,
+            gross_edge,
+            net_edge,
+            observed_at,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BalanceChange {
+    pub account_id: String,
+    pub currency: String,
+    pub total: String,
+    pub available: String,
+    pub hold: String,
+    pub available_change: String,
+    pub hold_change: String,
+    pub relation_event: String,
+    pub relation_event_id: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl BalanceChange {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        account_id: String,
+        currency: String,
+        total: String,
+        available: String,
+        hold: String,
+        available_change: String,
+        hold_change: String,
+        relation_event: String,
+        relation_event_id: String,
+        changed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            account_id,
+            currency,
+            total,
+            available,
+            hold,
+            available_change,
+            hold_change,
+            relation_event,
+            relation_event_id,
+            changed_at,
+        }
+    }
+}
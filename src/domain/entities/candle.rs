@@ -0,0 +1,92 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+pub const SOURCE_TRADES: &str = "trades";
+pub const SOURCE_TICKERS: &str = "tickers";
+
+pub const CANDLE_INTERVALS: [(&str, i64); 13] = [
+    ("1min", 60),
+    ("3min", 180),
+    ("5min", 300),
+    ("15min", 900),
+    ("30min", 1800),
+    ("1hour", 3600),
+    ("2hour", 7200),
+    ("4hour", 14400),
+    ("6hour", 21600),
+    ("8hour", 28800),
+    ("12hour", 43200),
+    ("1day", 86400),
+    ("1week", 604800),
+];
+
+pub fn interval_duration(interval: &str) -> Option<TimeDelta> {
+    CANDLE_INTERVALS
+        .iter()
+        .find(|(name, _)| *name == interval)
+        .map(|(_, secs)| TimeDelta::seconds(*secs))
+}
+
+/// The KuCoin name of an interval given as a duration, e.g. 3 minutes is "3min".
+pub fn interval_name(duration: TimeDelta) -> Option<&'static str> {
+    CANDLE_INTERVALS
+        .iter()
+        .find(|(_, secs)| *secs == duration.num_seconds() && duration.subsec_nanos() == 0)
+        .map(|(name, _)| *name)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Candle {
+    pub symbol: String,
+    pub interval: String,
+    pub open_time: DateTime<Utc>,
+    pub open: Decimal,
+    pub close: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub volume: Decimal,
+    pub turnover: Decimal,
+}
+
+impl Candle {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        symbol: String,
+        interval: String,
+        open_time: DateTime<Utc>,
+        open: Decimal,
+        close: Decimal,
+        high: Decimal,
+        low: Decimal,
+        volume: Decimal,
+        turnover: Decimal,
+    ) -> Self {
+        Self {
+            symbol,
+            interval,
+            open_time,
+            open,
+            close,
+            high,
+            low,
+            volume,
+            turnover,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CandleConsistencyCheck {
+    pub symbol: String,
+    pub interval: String,
+    pub source: String,
+    pub window_start: DateTime<Utc>,
+    pub window_end: DateTime<Utc>,
+    pub compared: i32,
+    pub missing: i32,
+    pub mismatched: i32,
+    pub max_price_deviation: Decimal,
+    pub max_volume_deviation: Decimal,
+    pub checked_at: DateTime<Utc>,
+}
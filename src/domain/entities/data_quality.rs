@@ -0,0 +1,32 @@
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct DataQualityIssue {
+    pub symbol: String,
+    pub field: String,
+    pub value: String,
+    pub reason: String,
+}
+
+impl DataQualityIssue {
+    pub fn new(symbol: String, field: String, value: String, reason: String) -> Self {
+        Self {
+            symbol,
+            field,
+            value,
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for DataQualityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Data quality error: {} field '{}' has unparseable value '{}': {}",
+            self.symbol, self.field, self.value, self.reason
+        )
+    }
+}
+
+impl std::error::Error for DataQualityIssue {}
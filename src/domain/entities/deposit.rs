@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Deposit {
+    pub currency: String,
+    pub chain: String,
+    pub status: String,
+    pub address: String,
+    pub memo: String,
+    pub is_inner: bool,
+    pub amount: String,
+    pub fee: String,
+    pub wallet_tx_id: Option<String>,
+    pub remark: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Deposit {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        currency: String,
+        chain: String,
+        status: String,
+        address: String,
+        memo: String,
+        is_inner: bool,
+        amount: String,
+        fee: String,
+        wallet_tx_id: Option<String>,
+        remark: String,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            currency,
+            chain,
+            status,
+            address,
+            memo,
+            is_inner,
+            amount,
+            fee,
+            wallet_tx_id,
+            remark,
+            created_at,
+            updated_at,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Event {
+    pub kind: String,
+    pub subject: String,
+    pub message: String,
+    pub payload: serde_json::Value,
+}
+
+impl Event {
+    pub fn new(kind: String, subject: String, message: String, payload: serde_json::Value) -> Self {
+        Self {
+            kind,
+            subject,
+            message,
+            payload,
+        }
+    }
+}
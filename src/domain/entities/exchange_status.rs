@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+pub const STATUS_OPEN: &str = "open";
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExchangeStatus {
+    pub status: String,
+    pub message: String,
+}
+
+impl ExchangeStatus {
+    pub fn new(status: String, message: String) -> Self {
+        Self { status, message }
+    }
+
+    pub fn is_maintenance(&self) -> bool {
+        self.status != STATUS_OPEN
+    }
+}
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FiatPrice {
+    pub currency: String,
+    pub base: String,
+    pub price: String,
+    pub time_point: DateTime<Utc>,
+}
+
+impl FiatPrice {
+    pub fn new(currency: String, base: String, price: String, time_point: DateTime<Utc>) -> Self {
+        Self {
+            currency,
+            base,
+            price,
+            time_point,
+        }
+    }
+}
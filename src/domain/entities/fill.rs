@@ -0,0 +1,70 @@
+use crate::domain::entities::symbol::Symbol;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Fill {
+    pub trade_id: String,
+    pub symbol: String,
+    pub order_id: String,
+    pub counter_order_id: String,
+    pub side: String,
+    pub liquidity: String,
+    pub force_taker: bool,
+    pub price: String,
+    pub size: String,
+    pub funds: String,
+    pub fee: String,
+    pub fee_rate: String,
+    pub fee_currency: String,
+    pub trade_type: String,
+    pub order_type: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Fill {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        trade_id: String,
+        symbol: String,
+        order_id: String,
+        counter_order_id: String,
+        side: String,
+        liquidity: String,
+        force_taker: bool,
+        price: String,
+        size: String,
+        funds: String,
+        fee: String,
+        fee_rate: String,
+        fee_currency: String,
+        trade_type: String,
+        order_type: String,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            trade_id,
+            symbol,
+            order_id,
+            counter_order_id,
+            side,
+            liquidity,
+            force_taker,
+            price,
+            size,
+            funds,
+            fee,
+            fee_rate,
+            fee_currency,
+            trade_type,
+            order_type,
+            created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FillWithSymbol {
+    pub fill: Fill,
+    pub symbol: Option<Symbol>,
+}
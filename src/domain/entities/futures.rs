@@ -0,0 +1,146 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FuturesContract {
+    pub symbol: String,
+    pub root_symbol: String,
+    pub contract_type: String,
+    pub base_currency: String,
+    pub quote_currency: String,
+    pub settle_currency: String,
+    pub status: String,
+    pub multiplier: f64,
+    pub lot_size: f64,
+    pub tick_size: f64,
+    pub max_order_qty: f64,
+    pub max_price: f64,
+    pub max_leverage: f64,
+    pub initial_margin: f64,
+    pub maintain_margin: f64,
+    pub maker_fee_rate: f64,
+    pub taker_fee_rate: f64,
+    pub is_inverse: bool,
+    pub is_quanto: bool,
+    pub funding_rate_granularity: Option<i64>,
+    pub first_open_date: Option<DateTime<Utc>>,
+    pub expire_date: Option<DateTime<Utc>>,
+}
+
+impl FuturesContract {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        symbol: String,
+        root_symbol: String,
+        contract_type: String,
+        base_currency: String,
+        quote_currency: String,
+        settle_currency: String,
+        status: String,
+        multiplier: f64,
+        lot_size: f64,
+        tick_size: f64,
+        max_order_qty: f64,
+        max_price: f64,
+        max_leverage: f64,
+        initial_margin: f64,
+        maintain_margin: f64,
+        maker_fee_rate: f64,
+        taker_fee_rate: f64,
+        is_inverse: bool,
+        is_quanto: bool,
+        funding_rate_granularity: Option<i64>,
+        first_open_date: Option<DateTime<Utc>>,
+        expire_date: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            symbol,
+            root_symbol,
+            contract_type,
+            base_currency,
+            quote_currency,
+            settle_currency,
+            status,
+            multiplier,
+            lot_size,
+            tick_size,
+            max_order_qty,
+            max_price,
+            max_leverage,
+            initial_margin,
+            maintain_margin,
+            maker_fee_rate,
+            taker_fee_rate,
+            is_inverse,
+            is_quanto,
+            funding_rate_granularity,
+            first_open_date,
+            expire_date,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FundingRate {
+    pub symbol: String,
+    pub time_point: DateTime<Utc>,
+    pub funding_rate: f64,
+    pub predicted_funding_rate: Option<f64>,
+}
+
+impl FundingRate {
+    pub fn new(
+        symbol: String,
+        time_point: DateTime<Utc>,
+        funding_rate: f64,
+        predicted_funding_rate: Option<f64>,
+    ) -> Self {
+        Self {
+            symbol,
+            time_point,
+            funding_rate,
+            predicted_funding_rate,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MarkPrice {
+    pub symbol: String,
+    pub time_point: DateTime<Utc>,
+    pub mark_price: f64,
+    pub index_price: f64,
+}
+
+impl MarkPrice {
+    pub fn new(
+        symbol: String,
+        time_point: DateTime<Utc>,
+        mark_price: f64,
+        index_price: f64,
+    ) -> Self {
+        Self {
+            symbol,
+            time_point,
+            mark_price,
+            index_price,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OpenInterest {
+    pub symbol: String,
+    pub open_interest: String,
+    pub time_point: DateTime<Utc>,
+}
+
+impl OpenInterest {
+    pub fn new(symbol: String, open_interest: String, time_point: DateTime<Utc>) -> Self {
+        Self {
+            symbol,
+            open_interest,
+            time_point,
+        }
+    }
+}
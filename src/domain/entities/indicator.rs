@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndicatorValue {
+    pub symbol: String,
+    pub interval: String,
+    pub indicator: String,
+    pub open_time: DateTime<Utc>,
+    pub value: f64,
+}
+
+impl IndicatorValue {
+    pub fn new(
+        symbol: String,
+        interval: String,
+        indicator: String,
+        open_time: DateTime<Utc>,
+        value: f64,
+    ) -> Self {
+        Self {
+            symbol,
+            interval,
+            indicator,
+            open_time,
+            value,
+        }
+    }
+}
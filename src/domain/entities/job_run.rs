@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct JobRun {
+    pub job_name: String,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub success: bool,
+    pub error: Option<String>,
+    pub exchange_status: Option<String>,
+    pub during_maintenance: bool,
+}
+
+impl JobRun {
+    pub fn new(
+        job_name: String,
+        started_at: DateTime<Utc>,
+        finished_at: DateTime<Utc>,
+        error: Option<String>,
+        exchange_status: Option<String>,
+        during_maintenance: bool,
+    ) -> Self {
+        Self {
+            job_name,
+            started_at,
+            finished_at,
+            success: error.is_none(),
+            error,
+            exchange_status,
+            during_maintenance,
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LedgerEntry {
+    pub id: String,
+    pub currency: String,
+    pub amount: Decimal,
+    pub fee: Decimal,
+    pub balance: Decimal,
+    pub account_type: String,
+    pub biz_type: String,
+    pub direction: String,
+    pub context: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LedgerEntry {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        currency: String,
+        amount: Decimal,
+        fee: Decimal,
+        balance: Decimal,
+        account_type: String,
+        biz_type: String,
+        direction: String,
+        context: String,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            currency,
+            amount,
+            fee,
+            balance,
+            account_type,
+            biz_type,
+            direction,
+            context,
+            created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RebuiltBalance {
+    pub currency: String,
+    pub account_type: String,
+    pub balance: Decimal,
+}
+
+impl RebuiltBalance {
+    pub fn new(currency: String, account_type: String, balance: Decimal) -> Self {
+        Self {
+            currency,
+            account_type,
+            balance,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LendingMarket {
+    pub currency: String,
+    pub purchase_enable: bool,
+    pub redeem_enable: bool,
+    pub auto_purchase_enable: bool,
+    pub increment: String,
+    pub min_purchase_size: String,
+    pub max_purchase_size: String,
+    pub min_interest_rate: String,
+    pub max_interest_rate: String,
+    pub interest_increment: String,
+    pub market_interest_rate: String,
+}
+
+impl LendingMarket {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        currency: String,
+        purchase_enable: bool,
+        redeem_enable: bool,
+        auto_purchase_enable: bool,
+        increment: String,
+        min_purchase_size: String,
+        max_purchase_size: String,
+        min_interest_rate: String,
+        max_interest_rate: String,
+        interest_increment: String,
+        market_interest_rate: String,
+    ) -> Self {
+        Self {
+            currency,
+            purchase_enable,
+            redeem_enable,
+            auto_purchase_enable,
+            increment,
+            min_purchase_size,
+            max_purchase_size,
+            min_interest_rate,
+            max_interest_rate,
+            interest_increment,
+            market_interest_rate,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LendingInterestRate {
+    pub currency: String,
+    pub time: DateTime<Utc>,
+    pub market_interest_rate: String,
+}
+
+impl LendingInterestRate {
+    pub fn new(currency: String, time: DateTime<Utc>, market_interest_rate: String) -> Self {
+        Self {
+            currency,
+            time,
+            market_interest_rate,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+pub const LIQUID_UNIVERSE: &str = "liquid-universe";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiquidityInputs {
+    pub symbol: String,
+    pub volume_usdt: f64,
+    pub spread: f64,
+    pub depth_usdt: f64,
+    pub trades_per_hour: f64,
+}
+
+impl LiquidityInputs {
+    pub fn new(
+        symbol: String,
+        volume_usdt: f64,
+        spread: f64,
+        depth_usdt: f64,
+        trades_per_hour: f64,
+    ) -> Self {
+        Self {
+            symbol,
+            volume_usdt,
+            spread,
+            depth_usdt,
+            trades_per_hour,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiquidityScore {
+    pub inputs: LiquidityInputs,
+    pub score: f64,
+    pub rank: i32,
+    pub computed_at: DateTime<Utc>,
+}
+
+impl LiquidityScore {
+    pub fn new(inputs: LiquidityInputs, score: f64, rank: i32, computed_at: DateTime<Utc>) -> Self {
+        Self {
+            inputs,
+            score,
+            rank,
+            computed_at,
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CrossMarginSymbol {
+    pub symbol: String,
+    pub name: String,
+    pub base_currency: String,
+    pub quote_currency: String,
+    pub market: String,
+    pub enable_trading: bool,
+}
+
+impl CrossMarginSymbol {
+    pub fn new(
+        symbol: String,
+        name: String,
+        base_currency: String,
+        quote_currency: String,
+        market: String,
+        enable_trading: bool,
+    ) -> Self {
+        Self {
+            symbol,
+            name,
+            base_currency,
+            quote_currency,
+            market,
+            enable_trading,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IsolatedMarginSymbol {
+    pub symbol: String,
+    pub symbol_name: String,
+    pub base_currency: String,
+    pub quote_currency: String,
+    pub max_leverage: i16,
+    pub liquidation_debt_ratio: String,
+    pub auto_renew_max_debt_ratio: String,
+    pub trade_enable: bool,
+    pub base_borrow_enable: bool,
+    pub quote_borrow_enable: bool,
+    pub base_transfer_in_enable: bool,
+    pub quote_transfer_in_enable: bool,
+    pub base_borrow_coefficient: String,
+    pub quote_borrow_coefficient: String,
+}
+
+impl IsolatedMarginSymbol {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        symbol: String,
+        symbol_name: String,
+        base_currency: String,
+        quote_currency: String,
+        max_leverage: i16,
+        liquidation_debt_ratio: String,
+        auto_renew_max_debt_ratio: String,
+        trade_enable: bool,
+        base_borrow_enable: bool,
+        quote_borrow_enable: bool,
+        base_transfer_in_enable: bool,
+        quote_transfer_in_enable: bool,
+        base_borrow_coefficient: String,
+        quote_borrow_coefficient: String,
+    ) -> Self {
+        Self {
+            symbol,
+            symbol_name,
+            base_currency,
+            quote_currency,
+            max_leverage,
+            liquidation_debt_ratio,
+            auto_renew_max_debt_ratio,
+            trade_enable,
+            base_borrow_enable,
+            quote_borrow_enable,
+            base_transfer_in_enable,
+            quote_transfer_in_enable,
+            base_borrow_coefficient,
+            quote_borrow_coefficient,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MarginConfig {
+    pub currencies: Vec<String>,
+    pub max_leverage: i16,
+    pub warning_debt_ratio: String,
+    pub liquidation_debt_ratio: String,
+}
+
+impl MarginConfig {
+    pub fn new(
+        currencies: Vec<String>,
+        max_leverage: i16,
+        warning_debt_ratio: String,
+        liquidation_debt_ratio: String,
+    ) -> Self {
+        Self {
+            currencies,
+            max_leverage,
+            warning_debt_ratio,
+            liquidation_debt_ratio,
+        }
+    }
+}
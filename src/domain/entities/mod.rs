@@ -1,3 +1,29 @@
+pub mod account_balance;
+pub mod announcement;
+pub mod arbitrage;
+pub mod balance_change;
+pub mod candle;
 pub mod currency;
+pub mod data_quality;
+pub mod deposit;
+pub mod event;
+pub mod exchange_status;
+pub mod fiat_price;
+pub mod fill;
+pub mod futures;
+pub mod indicator;
+pub mod job_run;
+pub mod ledger_entry;
+pub mod lending;
+pub mod liquidity;
+pub mod margin;
+pub mod order;
+pub mod order_book;
+pub mod order_event;
+pub mod portfolio;
+pub mod risk_metric;
+pub mod sub_account;
 pub mod symbol;
 pub mod ticker;
+pub mod trade;
+pub mod withdrawal;
@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Order {
+    pub id: String,
+    pub client_oid: String,
+    pub symbol: String,
+    pub op_type: String,
+    pub order_type: String,
+    pub side: String,
+    pub price: String,
+    pub size: String,
+    pub funds: String,
+    pub deal_size: String,
+    pub deal_funds: String,
+    pub fee: String,
+    pub fee_currency: String,
+    pub time_in_force: String,
+    pub post_only: bool,
+    pub hidden: bool,
+    pub iceberg: bool,
+    pub cancel_exist: bool,
+    pub active: bool,
+    pub in_order_book: bool,
+    pub trade_type: String,
+    pub remark: String,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: DateTime<Utc>,
+}
+
+impl Order {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        client_oid: String,
+        symbol: String,
+        op_type: String,
+        order_type: String,
+        side: String,
+        price: String,
+        size: String,
+        funds: String,
+        deal_size: String,
+        deal_funds: String,
+        fee: String,
+        fee_currency: String,
+        time_in_force: String,
+        post_only: bool,
+        hidden: bool,
+        iceberg: bool,
+        cancel_exist: bool,
+        active: bool,
+        in_order_book: bool,
+        trade_type: String,
+        remark: String,
+        created_at: DateTime<Utc>,
+        last_updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            client_oid,
+            symbol,
+            op_type,
+            order_type,
+            side,
+            price,
+            size,
+            funds,
+            deal_size,
+            deal_funds,
+            fee,
+            fee_currency,
+            time_in_force,
+            post_only,
+            hidden,
+            iceberg,
+            cancel_exist,
+            active,
+            in_order_book,
+            trade_type,
+            remark,
+            created_at,
+            last_updated_at,
+        }
+    }
+}
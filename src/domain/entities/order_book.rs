@@ -0,0 +1,293 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriceLevel {
+    pub price: Decimal,
+    pub size: Decimal,
+}
+
+impl PriceLevel {
+    pub fn new(price: Decimal, size: Decimal) -> Self {
+        Self { price, size }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderBookSnapshot {
+    pub symbol: String,
+    pub sequence: i64,
+    pub bids: Vec<PriceLevel>,
+    pub asks: Vec<PriceLevel>,
+    pub time_point: DateTime<Utc>,
+}
+
+impl OrderBookSnapshot {
+    pub fn new(
+        symbol: String,
+        sequence: i64,
+        bids: Vec<PriceLevel>,
+        asks: Vec<PriceLevel>,
+        time_point: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            symbol,
+            sequence,
+            bids,
+            asks,
+            time_point,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LevelChange {
+    pub price: Decimal,
+    pub size: Decimal,
+    pub sequence: i64,
+}
+
+impl LevelChange {
+    pub fn new(price: Decimal, size: Decimal, sequence: i64) -> Self {
+        Self {
+            price,
+            size,
+            sequence,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderBookUpdate {
+    pub symbol: String,
+    pub sequence_start: i64,
+    pub sequence_end: i64,
+    pub bids: Vec<LevelChange>,
+    pub asks: Vec<LevelChange>,
+    pub time_point: DateTime<Utc>,
+}
+
+impl OrderBookUpdate {
+    pub fn new(
+        symbol: String,
+        sequence_start: i64,
+        sequence_end: i64,
+        bids: Vec<LevelChange>,
+        asks: Vec<LevelChange>,
+        time_point: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            symbol,
+            sequence_start,
+            sequence_end,
+            bids,
+            asks,
+            time_point,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplyOutcome {
+    Applied,
+    Stale,
+    Gap,
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderBookDepth {
+    pub symbol: String,
+    pub sequence: i64,
+    pub time_point: DateTime<Utc>,
+    pub bids: Vec<PriceLevel>,
+    pub asks: Vec<PriceLevel>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderBook {
+    symbol: String,
+    sequence: i64,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+    time_point: DateTime<Utc>,
+}
+
+impl OrderBook {
+    pub fn from_snapshot(snapshot: OrderBookSnapshot) -> Self {
+        let levels = |levels: Vec<PriceLevel>| {
+            levels
+                .into_iter()
+                .filter(|l| !l.size.is_zero())
+                .map(|l| (l.price, l.size))
+                .collect()
+        };
+
+        Self {
+            symbol: snapshot.symbol,
+            sequence: snapshot.sequence,
+            bids: levels(snapshot.bids),
+            asks: levels(snapshot.asks),
+            time_point: snapshot.time_point,
+        }
+    }
+
+    pub fn apply(&mut self, update: &OrderBookUpdate) -> ApplyOutcome {
+        if update.sequence_end <= self.sequence {
+            return ApplyOutcome::Stale;
+        }
+        if update.sequence_start > self.sequence + 1 {
+            return ApplyOutcome::Gap;
+        }
+
+        apply_changes(&mut self.bids, &update.bids, self.sequence);
+        apply_changes(&mut self.asks, &update.asks, self.sequence);
+        self.sequence = update.sequence_end;
+        self.time_point = update.time_point;
+        ApplyOutcome::Applied
+    }
+
+    pub fn best_bid(&self) -> Option<PriceLevel> {
+        self.bids
+            .iter()
+            .next_back()
+            .map(|(price, size)| PriceLevel::new(*price, *size))
+    }
+
+    pub fn best_ask(&self) -> Option<PriceLevel> {
+        self.asks
+            .iter()
+            .next()
+            .map(|(price, size)| PriceLevel::new(*price, *size))
+    }
+
+    pub fn depth(&self, levels: usize) -> OrderBookDepth {
+        let bids: Vec<PriceLevel> = self
+            .bids
+            .iter()
+            .rev()
+            .take(levels)
+            .map(|(price, size)| PriceLevel::new(*price, *size))
+            .collect();
+        let asks: Vec<PriceLevel> = self
+            .asks
+            .iter()
+            .take(levels)
+            .map(|(price, size)| PriceLevel::new(*price, *size))
+            .collect();
+
+        OrderBookDepth {
+            symbol: self.symbol.clone(),
+            sequence: self.sequence,
+            time_point: self.time_point,
+            bids,
+            asks,
+        }
+    }
+
+    pub fn check(&self) -> Result<(), String> {
+        if let (Some(bid), Some(ask)) = (self.best_bid(), self.best_ask())
+            && bid.price >= ask.price
+        {
+            return Err(format!(
+                "{} book is crossed: bid {} >= ask {} at sequence {}",
+                self.symbol, bid.price, ask.price, self.sequence
+            ));
+        }
+        if self
+            .bids
+            .iter()
+            .chain(self.asks.iter())
+            .any(|(price, size)| price.is_sign_negative() || size.is_sign_negative())
+        {
+            return Err(format!(
+                "{} book has negative levels at sequence {}",
+                self.symbol, self.sequence
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn apply_changes(side: &mut BTreeMap<Decimal, Decimal>, changes: &[LevelChange], sequence: i64) {
+    for change in changes {
+        if change.sequence <= sequence || change.price.is_zero() {
+            continue;
+        }
+        if change.size.is_zero() {
+            side.remove(&change.price);
+        } else {
+            side.insert(change.price, change.size);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book() -> OrderBook {
+        OrderBook::from_snapshot(OrderBookSnapshot::new(
+            "BTC-USDT".to_string(),
+            10,
+            vec![PriceLevel::new(Decimal::from(100), Decimal::from(1))],
+            vec![PriceLevel::new(Decimal::from(101), Decimal::from(2))],
+            Utc::now(),
+        ))
+    }
+
+    fn update(start: i64, end: i64, bids: Vec<LevelChange>) -> OrderBookUpdate {
+        OrderBookUpdate::new("BTC-USDT".to_string(), start, end, bids, vec![], Utc::now())
+    }
+
+    #[test]
+    fn apply_ignores_stale_updates() {
+        let mut book = book();
+        let stale = update(
+            9,
+            10,
+            vec![LevelChange::new(Decimal::from(99), Decimal::from(5), 10)],
+        );
+
+        assert_eq!(book.apply(&stale), ApplyOutcome::Stale);
+        assert_eq!(book.depth(5).bids.len(), 1);
+        assert_eq!(book.depth(5).sequence, 10);
+    }
+
+    #[test]
+    fn apply_reports_gaps_without_changing_the_book() {
+        let mut book = book();
+        let gapped = update(
+            12,
+            13,
+            vec![LevelChange::new(Decimal::from(99), Decimal::from(5), 12)],
+        );
+
+        assert_eq!(book.apply(&gapped), ApplyOutcome::Gap);
+        assert_eq!(book.depth(5).bids.len(), 1);
+        assert_eq!(book.depth(5).sequence, 10);
+    }
+
+    #[test]
+    fn apply_updates_levels_and_skips_already_seen_changes() {
+        let mut book = book();
+        let next = update(
+            10,
+            12,
+            vec![
+                LevelChange::new(Decimal::from(98), Decimal::from(7), 10),
+                LevelChange::new(Decimal::from(99), Decimal::from(5), 11),
+                LevelChange::new(Decimal::from(100), Decimal::ZERO, 12),
+            ],
+        );
+
+        assert_eq!(book.apply(&next), ApplyOutcome::Applied);
+        let depth = book.depth(5);
+        assert_eq!(depth.sequence, 12);
+        assert_eq!(
+            depth.bids,
+            vec![PriceLevel::new(Decimal::from(99), Decimal::from(5))]
+        );
+        assert!(book.check().is_ok());
+    }
+}
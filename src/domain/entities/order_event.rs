@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrderEvent {
+    pub order_id: String,
+    pub client_oid: Option<String>,
+    pub symbol: String,
+    pub event_type: String,
+    pub status: String,
+    pub side: String,
+    pub order_type: String,
+    pub price: Option<String>,
+    pub size: Option<String>,
+    pub origin_size: Option<String>,
+    pub filled_size: Option<String>,
+    pub remain_size: Option<String>,
+    pub canceled_size: Option<String>,
+    pub match_price: Option<String>,
+    pub match_size: Option<String>,
+    pub trade_id: Option<String>,
+    pub liquidity: Option<String>,
+    pub event_time: DateTime<Utc>,
+}
+
+impl OrderEvent {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        order_id: String,
+        client_oid: Option<String>,
+        symbol: String,
+        event_type: String,
+        status: String,
+        side: String,
+        order_type: String,
+        price: Option<String>,
+        size: Option<String>,
+        origin_size: Option<String>,
+        filled_size: Option<String>,
+        remain_size: Option<String>,
+        canceled_size: Option<String>,
+        match_price: Option<String>,
+        match_size: Option<String>,
+        trade_id: Option<String>,
+        liquidity: Option<String>,
+        event_time: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            order_id,
+            client_oid,
+            symbol,
+            event_type,
+            status,
+            side,
+            order_type,
+            price,
+            size,
+            origin_size,
+            filled_size,
+            remain_size,
+            canceled_size,
+            match_price,
+            match_size,
+            trade_id,
+            liquidity,
+            event_time,
+        }
+    }
+}
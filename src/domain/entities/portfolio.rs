@@ -0,0 +1,106 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioBalance {
+    pub currency: String,
+    pub balance: String,
+    pub available: String,
+    pub holds: String,
+    pub account_count: i32,
+}
+
+impl PortfolioBalance {
+    pub fn new(
+        currency: String,
+        balance: String,
+        available: String,
+        holds: String,
+        account_count: i32,
+    ) -> Self {
+        Self {
+            currency,
+            balance,
+            available,
+            holds,
+            account_count,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetValuation {
+    pub account_id: String,
+    pub account_type: String,
+    pub currency: String,
+    pub balance: Decimal,
+    pub price_usdt: Option<Decimal>,
+    pub value_usdt: Option<Decimal>,
+    pub value_usd: Option<Decimal>,
+}
+
+impl AssetValuation {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        account_id: String,
+        account_type: String,
+        currency: String,
+        balance: Decimal,
+        price_usdt: Option<Decimal>,
+        value_usdt: Option<Decimal>,
+        value_usd: Option<Decimal>,
+    ) -> Self {
+        Self {
+            account_id,
+            account_type,
+            currency,
+            balance,
+            price_usdt,
+            value_usdt,
+            value_usd,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioValuation {
+    pub account_id: String,
+    pub account_type: String,
+    pub snapshot_at: DateTime<Utc>,
+    pub value_usdt: Decimal,
+    pub value_usd: Decimal,
+    pub assets: Vec<AssetValuation>,
+}
+
+impl PortfolioValuation {
+    pub fn new(
+        account_id: String,
+        account_type: String,
+        snapshot_at: DateTime<Utc>,
+        value_usdt: Decimal,
+        value_usd: Decimal,
+        assets: Vec<AssetValuation>,
+    ) -> Self {
+        Self {
+            account_id,
+            account_type,
+            snapshot_at,
+            value_usdt,
+            value_usd,
+            assets,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymbolPnl {
+    pub symbol: String,
+    pub quote_currency: String,
+    pub position: Decimal,
+    pub average_cost: Decimal,
+    pub realized_pnl: Decimal,
+    pub unrealized_pnl: Option<Decimal>,
+    pub realized_pnl_usdt: Option<Decimal>,
+    pub unrealized_pnl_usdt: Option<Decimal>,
+}
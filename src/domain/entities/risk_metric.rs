@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolatilityMetric {
+    pub symbol: String,
+    pub interval: String,
+    pub window_days: i32,
+    pub sample_count: i32,
+    pub realized_volatility: f64,
+    pub computed_at: DateTime<Utc>,
+}
+
+impl VolatilityMetric {
+    pub fn new(
+        symbol: String,
+        interval: String,
+        window_days: i32,
+        sample_count: i32,
+        realized_volatility: f64,
+        computed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            symbol,
+            interval,
+            window_days,
+            sample_count,
+            realized_volatility,
+            computed_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorrelationMetric {
+    pub symbol_a: String,
+    pub symbol_b: String,
+    pub interval: String,
+    pub window_days: i32,
+    pub sample_count: i32,
+    pub correlation: f64,
+    pub computed_at: DateTime<Utc>,
+}
+
+impl CorrelationMetric {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        symbol_a: String,
+        symbol_b: String,
+        interval: String,
+        window_days: i32,
+        sample_count: i32,
+        correlation: f64,
+        computed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            symbol_a,
+            symbol_b,
+            interval,
+            window_days,
+            sample_count,
+            correlation,
+            computed_at,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubAccount {
+    pub user_id: String,
+    pub uid: i64,
+    pub sub_name: String,
+    pub status: i32,
+    pub sub_type: i32,
+    pub access: String,
+    pub remarks: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl SubAccount {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_id: String,
+        uid: i64,
+        sub_name: String,
+        status: i32,
+        sub_type: i32,
+        access: String,
+        remarks: Option<String>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            user_id,
+            uid,
+            sub_name,
+            status,
+            sub_type,
+            access,
+            remarks,
+            created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubAccountBalance {
+    pub sub_user_id: String,
+    pub sub_name: String,
+    pub currency: String,
+    pub account_type: String,
+    pub balance: String,
+    pub available: String,
+    pub holds: String,
+}
+
+impl SubAccountBalance {
+    pub fn new(
+        sub_user_id: String,
+        sub_name: String,
+        currency: String,
+        account_type: String,
+        balance: String,
+        available: String,
+        holds: String,
+    ) -> Self {
+        Self {
+            sub_user_id,
+            sub_name,
+            currency,
+            account_type,
+            balance,
+            available,
+            holds,
+        }
+    }
+}
@@ -1,6 +1,7 @@
-use serde::Deserialize;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Symbol {
     pub symbol: String,
     pub name: String,
@@ -8,20 +9,20 @@ pub struct Symbol {
     pub quote_currency: String,
     pub fee_currency: String,
     pub market: String,
-    pub base_min_size: String,
-    pub quote_min_size: String,
-    pub base_max_size: String,
-    pub quote_max_size: String,
-    pub base_increment: String,
-    pub quote_increment: String,
-    pub price_increment: String,
-    pub price_limit_rate: String,
-    pub min_funds: Option<String>,
+    pub base_min_size: Decimal,
+    pub quote_min_size: Decimal,
+    pub base_max_size: Decimal,
+    pub quote_max_size: Decimal,
+    pub base_increment: Decimal,
+    pub quote_increment: Decimal,
+    pub price_increment: Decimal,
+    pub price_limit_rate: Decimal,
+    pub min_funds: Option<Decimal>,
     pub is_margin_enabled: bool,
     pub enable_trading: bool,
     pub fee_category: i16,
-    pub maker_fee_coefficient: String,
-    pub taker_fee_coefficient: String,
+    pub maker_fee_coefficient: Decimal,
+    pub taker_fee_coefficient: Decimal,
     pub st: bool,
 }
 
@@ -34,20 +35,20 @@ impl Symbol {
         quote_currency: String,
         fee_currency: String,
         market: String,
-        base_min_size: String,
-        quote_min_size: String,
-        base_max_size: String,
-        quote_max_size: String,
-        base_increment: String,
-        quote_increment: String,
-        price_increment: String,
-        price_limit_rate: String,
-        min_funds: Option<String>,
+        base_min_size: Decimal,
+        quote_min_size: Decimal,
+        base_max_size: Decimal,
+        quote_max_size: Decimal,
+        base_increment: Decimal,
+        quote_increment: Decimal,
+        price_increment: Decimal,
+        price_limit_rate: Decimal,
+        min_funds: Option<Decimal>,
         is_margin_enabled: bool,
         enable_trading: bool,
         fee_category: i16,
-        maker_fee_coefficient: String,
-        taker_fee_coefficient: String,
+        maker_fee_coefficient: Decimal,
+        taker_fee_coefficient: Decimal,
         st: bool,
     ) -> Self {
         Self {
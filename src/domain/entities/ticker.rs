@@ -1,23 +1,48 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::Deserialize;
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct Ticker {
     pub symbol: String,
     pub symbol_name: String,
-    pub taker_fee_rate: String,
-    pub maker_fee_rate: String,
-    pub taker_coefficient: String,
-    pub maker_coefficient: String,
+    pub taker_fee_rate: Decimal,
+    pub maker_fee_rate: Decimal,
+    pub taker_coefficient: Decimal,
+    pub maker_coefficient: Decimal,
+    pub buy: Option<Decimal>,
+    pub sell: Option<Decimal>,
+    pub change_rate: Option<Decimal>,
+    pub change_price: Option<Decimal>,
+    pub high: Option<Decimal>,
+    pub low: Option<Decimal>,
+    pub vol: Option<Decimal>,
+    pub vol_value: Option<Decimal>,
+    pub last: Option<Decimal>,
+    pub average_price: Option<Decimal>,
+    pub time_point: DateTime<Utc>,
 }
 
 impl Ticker {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         symbol: String,
         symbol_name: String,
-        taker_fee_rate: String,
-        maker_fee_rate: String,
-        taker_coefficient: String,
-        maker_coefficient: String,
+        taker_fee_rate: Decimal,
+        maker_fee_rate: Decimal,
+        taker_coefficient: Decimal,
+        maker_coefficient: Decimal,
+        buy: Option<Decimal>,
+        sell: Option<Decimal>,
+        change_rate: Option<Decimal>,
+        change_price: Option<Decimal>,
+        high: Option<Decimal>,
+        low: Option<Decimal>,
+        vol: Option<Decimal>,
+        vol_value: Option<Decimal>,
+        last: Option<Decimal>,
+        average_price: Option<Decimal>,
+        time_point: DateTime<Utc>,
     ) -> Self {
         Self {
             symbol,
@@ -26,6 +51,34 @@ impl Ticker {
             maker_fee_rate,
             taker_coefficient,
             maker_coefficient,
+            buy,
+            sell,
+            change_rate,
+            change_price,
+            high,
+            low,
+            vol,
+            vol_value,
+            last,
+            average_price,
+            time_point,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TickerSnapshot {
+    pub symbol: String,
+    pub time_point: DateTime<Utc>,
+    pub last: Decimal,
+}
+
+impl TickerSnapshot {
+    pub fn new(symbol: String, time_point: DateTime<Utc>, last: Decimal) -> Self {
+        Self {
+            symbol,
+            time_point,
+            last,
         }
     }
 }
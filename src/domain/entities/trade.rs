@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Trade {
+    pub symbol: String,
+    pub sequence: i64,
+    pub price: Decimal,
+    pub size: Decimal,
+    pub side: String,
+    pub time_point: DateTime<Utc>,
+}
+
+impl Trade {
+    pub fn new(
+        symbol: String,
+        sequence: i64,
+        price: Decimal,
+        size: Decimal,
+        side: String,
+        time_point: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            symbol,
+            sequence,
+            price,
+            size,
+            side,
+            time_point,
+        }
+    }
+}
+
+/// Stretch between two stored trades where the exchange history no longer
+/// reaches back far enough, so trades in between may be missing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeGap {
+    pub symbol: String,
+    pub last_sequence: i64,
+    pub next_sequence: i64,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub detected_at: DateTime<Utc>,
+}
+
+impl TradeGap {
+    pub fn new(last: &Trade, next: &Trade, detected_at: DateTime<Utc>) -> Self {
+        Self {
+            symbol: next.symbol.clone(),
+            last_sequence: last.sequence,
+            next_sequence: next.sequence,
+            start: last.time_point,
+            end: next.time_point,
+            detected_at,
+        }
+    }
+}
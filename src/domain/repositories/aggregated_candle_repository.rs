@@ -0,0 +1,43 @@
+use crate::domain::entities::candle::{Candle, CandleConsistencyCheck};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait AggregatedCandleReadRepository: Send + Sync {
+    async fn find_latest_open_time(
+        &self,
+        exchange: &str,
+        source: &str,
+        symbol: &str,
+        interval: &str,
+    ) -> Result<Option<DateTime<Utc>>>;
+
+    async fn find_range(
+        &self,
+        exchange: &str,
+        source: &str,
+        symbol: &str,
+        interval: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Candle>>;
+}
+
+#[async_trait]
+pub trait AggregatedCandleWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, source: &str, candles: &[Candle]) -> Result<()>;
+
+    async fn save_checks(&self, exchange: &str, checks: &[CandleConsistencyCheck]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait AggregatedCandleRepository:
+    AggregatedCandleReadRepository + AggregatedCandleWriteRepository
+{
+}
+
+impl<T> AggregatedCandleRepository for T where
+    T: AggregatedCandleReadRepository + AggregatedCandleWriteRepository
+{
+}
@@ -0,0 +1,21 @@
+use crate::domain::entities::announcement::{Announcement, AnnouncementLink};
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait AnnouncementReadRepository: Send + Sync {}
+
+#[async_trait]
+pub trait AnnouncementWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, announcements: &[Announcement]) -> Result<()>;
+
+    async fn save_links(&self, exchange: &str, links: &[AnnouncementLink]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait AnnouncementRepository: AnnouncementReadRepository + AnnouncementWriteRepository {}
+
+impl<T> AnnouncementRepository for T where
+    T: AnnouncementReadRepository + AnnouncementWriteRepository
+{
+}
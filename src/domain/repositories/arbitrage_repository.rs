@@ -0,0 +1,16 @@
+use crate::domain::entities::arbitrage::ArbitrageOpportunity;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ArbitrageReadRepository: Send + Sync {}
+
+#[async_trait]
+pub trait ArbitrageWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, opportunities: &[ArbitrageOpportunity]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait ArbitrageRepository: ArbitrageReadRepository + ArbitrageWriteRepository {}
+
+impl<T> ArbitrageRepository for T where T: ArbitrageReadRepository + ArbitrageWriteRepository {}
@@ -0,0 +1,22 @@
+use crate::domain::entities::balance_change::BalanceChange;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait BalanceChangeReadRepository: Send + Sync {}
+
+#[async_trait]
+pub trait BalanceChangeWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, changes: &[BalanceChange]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait BalanceChangeRepository:
+    BalanceChangeReadRepository + BalanceChangeWriteRepository
+{
+}
+
+impl<T> BalanceChangeRepository for T where
+    T: BalanceChangeReadRepository + BalanceChangeWriteRepository
+{
+}
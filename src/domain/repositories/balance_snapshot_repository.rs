@@ -0,0 +1,46 @@
+use crate::domain::entities::account_balance::AccountBalance;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait BalanceSnapshotReadRepository: Send + Sync {
+    async fn find_latest(
+        &self,
+        exchange: &str,
+    ) -> Result<Option<(DateTime<Utc>, Vec<AccountBalance>)>>;
+
+    async fn find_latest_before(
+        &self,
+        exchange: &str,
+        before: DateTime<Utc>,
+    ) -> Result<Option<(DateTime<Utc>, Vec<AccountBalance>)>>;
+
+    async fn find_after(
+        &self,
+        exchange: &str,
+        after: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<(DateTime<Utc>, Vec<AccountBalance>)>>;
+}
+
+#[async_trait]
+pub trait BalanceSnapshotWriteRepository: Send + Sync {
+    async fn save(
+        &self,
+        exchange: &str,
+        snapshot_at: DateTime<Utc>,
+        balances: &[AccountBalance],
+    ) -> Result<()>;
+}
+
+#[async_trait]
+pub trait BalanceSnapshotRepository:
+    BalanceSnapshotReadRepository + BalanceSnapshotWriteRepository
+{
+}
+
+impl<T> BalanceSnapshotRepository for T where
+    T: BalanceSnapshotReadRepository + BalanceSnapshotWriteRepository
+{
+}
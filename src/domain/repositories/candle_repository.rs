@@ -0,0 +1,33 @@
+use crate::domain::entities::candle::Candle;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait CandleReadRepository: Send + Sync {
+    async fn find_latest_open_time(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        interval: &str,
+    ) -> Result<Option<DateTime<Utc>>>;
+
+    async fn find_range(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        interval: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Candle>>;
+}
+
+#[async_trait]
+pub trait CandleWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, candles: &[Candle]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait CandleRepository: CandleReadRepository + CandleWriteRepository {}
+
+impl<T> CandleRepository for T where T: CandleReadRepository + CandleWriteRepository {}
@@ -2,7 +2,9 @@ use crate::domain::entities::currency::Currency;
 use anyhow::Result;
 use async_trait::async_trait;
 #[async_trait]
-pub trait CurrencyReadRepository: Send + Sync {}
+pub trait CurrencyReadRepository: Send + Sync {
+    async fn find_codes(&self, exchange: &str) -> Result<Vec<String>>;
+}
 
 #[async_trait]
 pub trait CurrencyWriteRepository: Send + Sync {
@@ -0,0 +1,21 @@
+use crate::domain::entities::deposit::Deposit;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait DepositReadRepository: Send + Sync {
+    /// Earliest creation time that still has to be re-queried: the oldest
+    /// deposit in `PROCESSING`, or the newest known deposit otherwise.
+    async fn find_sync_start(&self, exchange: &str) -> Result<Option<DateTime<Utc>>>;
+}
+
+#[async_trait]
+pub trait DepositWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, deposits: &[Deposit]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait DepositRepository: DepositReadRepository + DepositWriteRepository {}
+
+impl<T> DepositRepository for T where T: DepositReadRepository + DepositWriteRepository {}
@@ -0,0 +1,16 @@
+use crate::domain::entities::event::Event;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait EventReadRepository: Send + Sync {}
+
+#[async_trait]
+pub trait EventWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, events: &[Event]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait EventRepository: EventReadRepository + EventWriteRepository {}
+
+impl<T> EventRepository for T where T: EventReadRepository + EventWriteRepository {}
@@ -0,0 +1,24 @@
+use crate::domain::entities::exchange_status::ExchangeStatus;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ExchangeStatusReadRepository: Send + Sync {
+    async fn find_current(&self, exchange: &str) -> Result<Option<ExchangeStatus>>;
+}
+
+#[async_trait]
+pub trait ExchangeStatusWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, status: &ExchangeStatus) -> Result<()>;
+}
+
+#[async_trait]
+pub trait ExchangeStatusRepository:
+    ExchangeStatusReadRepository + ExchangeStatusWriteRepository
+{
+}
+
+impl<T> ExchangeStatusRepository for T where
+    T: ExchangeStatusReadRepository + ExchangeStatusWriteRepository
+{
+}
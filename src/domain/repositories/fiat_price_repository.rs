@@ -0,0 +1,21 @@
+use crate::domain::entities::fiat_price::FiatPrice;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait FiatPriceReadRepository: Send + Sync {
+    async fn find_latest(&self, exchange: &str) -> Result<Vec<FiatPrice>>;
+
+    async fn find_latest_at(&self, exchange: &str, at: DateTime<Utc>) -> Result<Vec<FiatPrice>>;
+}
+
+#[async_trait]
+pub trait FiatPriceWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, prices: &[FiatPrice]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait FiatPriceRepository: FiatPriceReadRepository + FiatPriceWriteRepository {}
+
+impl<T> FiatPriceRepository for T where T: FiatPriceReadRepository + FiatPriceWriteRepository {}
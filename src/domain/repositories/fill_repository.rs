@@ -0,0 +1,24 @@
+use crate::domain::entities::fill::{Fill, FillWithSymbol};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait FillReadRepository: Send + Sync {
+    async fn find_with_symbols(
+        &self,
+        exchange: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<FillWithSymbol>>;
+}
+
+#[async_trait]
+pub trait FillWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, fills: &[Fill]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait FillRepository: FillReadRepository + FillWriteRepository {}
+
+impl<T> FillRepository for T where T: FillReadRepository + FillWriteRepository {}
@@ -0,0 +1,25 @@
+use crate::domain::entities::futures::FundingRate;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FundingRateReadRepository: Send + Sync {
+    /// Percentile of absolute funding rates in the stored history of `symbol`,
+    /// together with the number of samples it was computed from.
+    async fn find_abs_percentile(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        percentile: f64,
+    ) -> Result<(Option<f64>, i64)>;
+}
+
+#[async_trait]
+pub trait FundingRateWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, rates: &[FundingRate]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait FundingRateRepository: FundingRateReadRepository + FundingRateWriteRepository {}
+
+impl<T> FundingRateRepository for T where T: FundingRateReadRepository + FundingRateWriteRepository {}
@@ -0,0 +1,24 @@
+use crate::domain::entities::futures::FuturesContract;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FuturesContractReadRepository: Send + Sync {
+    async fn find_open_symbols(&self, exchange: &str) -> Result<Vec<String>>;
+}
+
+#[async_trait]
+pub trait FuturesContractWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, contracts: &[FuturesContract]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait FuturesContractRepository:
+    FuturesContractReadRepository + FuturesContractWriteRepository
+{
+}
+
+impl<T> FuturesContractRepository for T where
+    T: FuturesContractReadRepository + FuturesContractWriteRepository
+{
+}
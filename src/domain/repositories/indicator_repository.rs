@@ -0,0 +1,32 @@
+use crate::domain::entities::indicator::IndicatorValue;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait IndicatorReadRepository: Send + Sync {
+    async fn find_recent(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        interval: &str,
+        indicator: &str,
+        limit: i64,
+    ) -> Result<Vec<IndicatorValue>>;
+    async fn find_latest_open_time(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        interval: &str,
+    ) -> Result<Option<DateTime<Utc>>>;
+}
+
+#[async_trait]
+pub trait IndicatorWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, values: &[IndicatorValue]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait IndicatorRepository: IndicatorReadRepository + IndicatorWriteRepository {}
+
+impl<T> IndicatorRepository for T where T: IndicatorReadRepository + IndicatorWriteRepository {}
@@ -0,0 +1,16 @@
+use crate::domain::entities::job_run::JobRun;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait JobRunReadRepository: Send + Sync {}
+
+#[async_trait]
+pub trait JobRunWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, run: &JobRun) -> Result<()>;
+}
+
+#[async_trait]
+pub trait JobRunRepository: JobRunReadRepository + JobRunWriteRepository {}
+
+impl<T> JobRunRepository for T where T: JobRunReadRepository + JobRunWriteRepository {}
@@ -0,0 +1,25 @@
+use crate::domain::entities::ledger_entry::{LedgerEntry, RebuiltBalance};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait LedgerReadRepository: Send + Sync {
+    /// Balances replayed from the stored ledger up to `as_of`, one per
+    /// currency and account type that has ledger history.
+    async fn find_rebuilt_balances(
+        &self,
+        exchange: &str,
+        as_of: DateTime<Utc>,
+    ) -> Result<Vec<RebuiltBalance>>;
+}
+
+#[async_trait]
+pub trait LedgerWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, entries: &[LedgerEntry]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait LedgerRepository: LedgerReadRepository + LedgerWriteRepository {}
+
+impl<T> LedgerRepository for T where T: LedgerReadRepository + LedgerWriteRepository {}
@@ -0,0 +1,22 @@
+use crate::domain::entities::lending::{LendingInterestRate, LendingMarket};
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait LendingReadRepository: Send + Sync {}
+
+#[async_trait]
+pub trait LendingWriteRepository: Send + Sync {
+    async fn save_markets(&self, exchange: &str, markets: &[LendingMarket]) -> Result<()>;
+
+    async fn save_interest_rates(
+        &self,
+        exchange: &str,
+        rates: &[LendingInterestRate],
+    ) -> Result<()>;
+}
+
+#[async_trait]
+pub trait LendingRepository: LendingReadRepository + LendingWriteRepository {}
+
+impl<T> LendingRepository for T where T: LendingReadRepository + LendingWriteRepository {}
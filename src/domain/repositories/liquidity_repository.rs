@@ -0,0 +1,18 @@
+use crate::domain::entities::liquidity::LiquidityScore;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait LiquidityReadRepository: Send + Sync {
+    async fn find_latest(&self, exchange: &str) -> Result<Vec<LiquidityScore>>;
+}
+
+#[async_trait]
+pub trait LiquidityWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, scores: &[LiquidityScore]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait LiquidityRepository: LiquidityReadRepository + LiquidityWriteRepository {}
+
+impl<T> LiquidityRepository for T where T: LiquidityReadRepository + LiquidityWriteRepository {}
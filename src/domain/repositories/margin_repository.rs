@@ -0,0 +1,25 @@
+use crate::domain::entities::margin::{CrossMarginSymbol, IsolatedMarginSymbol, MarginConfig};
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait MarginReadRepository: Send + Sync {}
+
+#[async_trait]
+pub trait MarginWriteRepository: Send + Sync {
+    async fn save_cross_symbols(&self, exchange: &str, symbols: &[CrossMarginSymbol])
+    -> Result<()>;
+
+    async fn save_isolated_symbols(
+        &self,
+        exchange: &str,
+        symbols: &[IsolatedMarginSymbol],
+    ) -> Result<()>;
+
+    async fn save_config(&self, exchange: &str, config: &MarginConfig) -> Result<()>;
+}
+
+#[async_trait]
+pub trait MarginRepository: MarginReadRepository + MarginWriteRepository {}
+
+impl<T> MarginRepository for T where T: MarginReadRepository + MarginWriteRepository {}
@@ -0,0 +1,16 @@
+use crate::domain::entities::futures::MarkPrice;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait MarkPriceReadRepository: Send + Sync {}
+
+#[async_trait]
+pub trait MarkPriceWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, prices: &[MarkPrice]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait MarkPriceRepository: MarkPriceReadRepository + MarkPriceWriteRepository {}
+
+impl<T> MarkPriceRepository for T where T: MarkPriceReadRepository + MarkPriceWriteRepository {}
@@ -0,0 +1,17 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait MarketReadRepository: Send + Sync {
+    async fn find_active(&self, exchange: &str) -> Result<Vec<String>>;
+}
+
+#[async_trait]
+pub trait MarketWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, markets: &[String]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait MarketRepository: MarketReadRepository + MarketWriteRepository {}
+
+impl<T> MarketRepository for T where T: MarketReadRepository + MarketWriteRepository {}
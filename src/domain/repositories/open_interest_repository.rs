@@ -0,0 +1,19 @@
+use crate::domain::entities::futures::OpenInterest;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait OpenInterestReadRepository: Send + Sync {}
+
+#[async_trait]
+pub trait OpenInterestWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, open_interests: &[OpenInterest]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait OpenInterestRepository: OpenInterestReadRepository + OpenInterestWriteRepository {}
+
+impl<T> OpenInterestRepository for T where
+    T: OpenInterestReadRepository + OpenInterestWriteRepository
+{
+}
@@ -0,0 +1,16 @@
+use crate::domain::entities::order_book::OrderBookDepth;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait OrderBookReadRepository: Send + Sync {}
+
+#[async_trait]
+pub trait OrderBookWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, depths: &[OrderBookDepth]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait OrderBookRepository: OrderBookReadRepository + OrderBookWriteRepository {}
+
+impl<T> OrderBookRepository for T where T: OrderBookReadRepository + OrderBookWriteRepository {}
@@ -0,0 +1,16 @@
+use crate::domain::entities::order_event::OrderEvent;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait OrderEventReadRepository: Send + Sync {}
+
+#[async_trait]
+pub trait OrderEventWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, events: &[OrderEvent]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait OrderEventRepository: OrderEventReadRepository + OrderEventWriteRepository {}
+
+impl<T> OrderEventRepository for T where T: OrderEventReadRepository + OrderEventWriteRepository {}
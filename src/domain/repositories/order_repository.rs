@@ -0,0 +1,16 @@
+use crate::domain::entities::order::Order;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait OrderReadRepository: Send + Sync {}
+
+#[async_trait]
+pub trait OrderWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, orders: &[Order]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait OrderRepository: OrderReadRepository + OrderWriteRepository {}
+
+impl<T> OrderRepository for T where T: OrderReadRepository + OrderWriteRepository {}
@@ -0,0 +1,49 @@
+use crate::domain::entities::portfolio::{PortfolioBalance, PortfolioValuation, SymbolPnl};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait PortfolioReadRepository: Send + Sync {
+    async fn find_valuations(
+        &self,
+        exchange: &str,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<PortfolioValuation>>;
+
+    async fn find_latest_valuation_at(&self, exchange: &str) -> Result<Option<DateTime<Utc>>>;
+
+    async fn find_pnl_before(
+        &self,
+        exchange: &str,
+        before: DateTime<Utc>,
+    ) -> Result<Option<(DateTime<Utc>, Vec<SymbolPnl>)>>;
+}
+
+#[async_trait]
+pub trait PortfolioWriteRepository: Send + Sync {
+    async fn save(
+        &self,
+        exchange: &str,
+        snapshot_at: DateTime<Utc>,
+        balances: &[PortfolioBalance],
+    ) -> Result<()>;
+
+    async fn save_valuations(
+        &self,
+        exchange: &str,
+        valuations: &[PortfolioValuation],
+    ) -> Result<()>;
+
+    async fn save_pnl(
+        &self,
+        exchange: &str,
+        snapshot_at: DateTime<Utc>,
+        pnl: &[SymbolPnl],
+    ) -> Result<()>;
+}
+
+#[async_trait]
+pub trait PortfolioRepository: PortfolioReadRepository + PortfolioWriteRepository {}
+
+impl<T> PortfolioRepository for T where T: PortfolioReadRepository + PortfolioWriteRepository {}
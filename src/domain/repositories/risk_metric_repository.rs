@@ -0,0 +1,30 @@
+use crate::domain::entities::risk_metric::{CorrelationMetric, VolatilityMetric};
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait RiskMetricReadRepository: Send + Sync {
+    async fn find_latest_volatility(
+        &self,
+        exchange: &str,
+        window_days: i32,
+    ) -> Result<Vec<VolatilityMetric>>;
+
+    async fn find_latest_correlations(
+        &self,
+        exchange: &str,
+        window_days: i32,
+    ) -> Result<Vec<CorrelationMetric>>;
+}
+
+#[async_trait]
+pub trait RiskMetricWriteRepository: Send + Sync {
+    async fn save_volatility(&self, exchange: &str, metrics: &[VolatilityMetric]) -> Result<()>;
+
+    async fn save_correlations(&self, exchange: &str, metrics: &[CorrelationMetric]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait RiskMetricRepository: RiskMetricReadRepository + RiskMetricWriteRepository {}
+
+impl<T> RiskMetricRepository for T where T: RiskMetricReadRepository + RiskMetricWriteRepository {}
@@ -0,0 +1,24 @@
+use crate::domain::entities::sub_account::{SubAccount, SubAccountBalance};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait SubAccountReadRepository: Send + Sync {}
+
+#[async_trait]
+pub trait SubAccountWriteRepository: Send + Sync {
+    async fn save_accounts(&self, exchange: &str, accounts: &[SubAccount]) -> Result<()>;
+
+    async fn save_balances(
+        &self,
+        exchange: &str,
+        snapshot_at: DateTime<Utc>,
+        balances: &[SubAccountBalance],
+    ) -> Result<()>;
+}
+
+#[async_trait]
+pub trait SubAccountRepository: SubAccountReadRepository + SubAccountWriteRepository {}
+
+impl<T> SubAccountRepository for T where T: SubAccountReadRepository + SubAccountWriteRepository {}
@@ -2,7 +2,13 @@ use crate::domain::entities::symbol::Symbol;
 use anyhow::Result;
 use async_trait::async_trait;
 #[async_trait]
-pub trait SymbolReadRepository: Send + Sync {}
+pub trait SymbolReadRepository: Send + Sync {
+    async fn find_all(&self, exchange: &str) -> Result<Vec<Symbol>>;
+
+    async fn find_by_market(&self, exchange: &str, market: &str) -> Result<Vec<Symbol>>;
+
+    async fn find_by_symbol(&self, exchange: &str, symbol: &str) -> Result<Option<Symbol>>;
+}
 
 #[async_trait]
 pub trait SymbolWriteRepository: Send + Sync {
@@ -0,0 +1,25 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait SyncCursorReadRepository: Send + Sync {
+    async fn find(&self, exchange: &str, dataset: &str, key: &str)
+    -> Result<Option<DateTime<Utc>>>;
+}
+
+#[async_trait]
+pub trait SyncCursorWriteRepository: Send + Sync {
+    async fn save(
+        &self,
+        exchange: &str,
+        dataset: &str,
+        key: &str,
+        cursor: DateTime<Utc>,
+    ) -> Result<()>;
+}
+
+#[async_trait]
+pub trait SyncCursorRepository: SyncCursorReadRepository + SyncCursorWriteRepository {}
+
+impl<T> SyncCursorRepository for T where T: SyncCursorReadRepository + SyncCursorWriteRepository {}
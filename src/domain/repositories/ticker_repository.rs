@@ -1,9 +1,24 @@
-use crate::domain::entities::ticker::Ticker;
+use crate::domain::entities::ticker::{Ticker, TickerSnapshot};
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 #[async_trait]
-pub trait TickerReadRepository: Send + Sync {}
+pub trait TickerReadRepository: Send + Sync {
+    async fn find_all(&self, exchange: &str) -> Result<Vec<Ticker>>;
+
+    async fn find_all_at(&self, exchange: &str, at: DateTime<Utc>) -> Result<Vec<Ticker>>;
+
+    async fn find_latest(&self, exchange: &str, symbol: &str) -> Result<Option<Ticker>>;
+
+    async fn find_history(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<TickerSnapshot>>;
+}
 
 #[async_trait]
 pub trait TickerWriteRepository: Send + Sync {
@@ -0,0 +1,34 @@
+use crate::domain::entities::trade::{Trade, TradeGap};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait TradeReadRepository: Send + Sync {
+    async fn find_range(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Trade>>;
+    async fn find_latest(&self, exchange: &str, symbol: &str) -> Result<Option<Trade>>;
+    async fn find_gaps(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<TradeGap>>;
+}
+
+#[async_trait]
+pub trait TradeWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, trades: &[Trade]) -> Result<()>;
+    async fn save_gaps(&self, exchange: &str, gaps: &[TradeGap]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait TradeRepository: TradeReadRepository + TradeWriteRepository {}
+
+impl<T> TradeRepository for T where T: TradeReadRepository + TradeWriteRepository {}
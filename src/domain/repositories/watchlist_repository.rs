@@ -0,0 +1,17 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait WatchlistReadRepository: Send + Sync {
+    async fn find_symbols(&self, exchange: &str, name: &str) -> Result<Vec<String>>;
+}
+
+#[async_trait]
+pub trait WatchlistWriteRepository: Send + Sync {
+    async fn replace(&self, exchange: &str, name: &str, symbols: &[String]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait WatchlistRepository: WatchlistReadRepository + WatchlistWriteRepository {}
+
+impl<T> WatchlistRepository for T where T: WatchlistReadRepository + WatchlistWriteRepository {}
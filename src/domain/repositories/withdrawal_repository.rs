@@ -0,0 +1,22 @@
+use crate::domain::entities::withdrawal::Withdrawal;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait WithdrawalReadRepository: Send + Sync {
+    /// Earliest creation time that still has to be re-queried: the oldest
+    /// withdrawal that has not reached a final status, or the newest known
+    /// withdrawal otherwise.
+    async fn find_sync_start(&self, exchange: &str) -> Result<Option<DateTime<Utc>>>;
+}
+
+#[async_trait]
+pub trait WithdrawalWriteRepository: Send + Sync {
+    async fn save(&self, exchange: &str, withdrawals: &[Withdrawal]) -> Result<()>;
+}
+
+#[async_trait]
+pub trait WithdrawalRepository: WithdrawalReadRepository + WithdrawalWriteRepository {}
+
+impl<T> WithdrawalRepository for T where T: WithdrawalReadRepository + WithdrawalWriteRepository {}
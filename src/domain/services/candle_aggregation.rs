@@ -0,0 +1,375 @@
+use crate::domain::entities::candle::{Candle, CandleConsistencyCheck, interval_duration};
+use crate::domain::entities::ticker::TickerSnapshot;
+use crate::domain::entities::trade::{Trade, TradeGap};
+use chrono::{DateTime, TimeDelta, Utc};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+pub const CONSISTENCY_TOLERANCE: Decimal = Decimal::from_parts(1, 0, 0, false, 3);
+
+/// Accepts KuCoin interval names ("1min", "4hour") as well as free-form
+/// durations such as "15s", "3m", "2h" or "1d".
+pub fn parse_interval(interval: &str) -> Option<TimeDelta> {
+    if let Some(duration) = interval_duration(interval) {
+        return Some(duration);
+    }
+    let split = interval.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, unit) = interval.split_at(split);
+    let amount: i64 = amount.parse().ok().filter(|a| *a > 0)?;
+    match unit {
+        "s" => Some(TimeDelta::seconds(amount)),
+        "m" => Some(TimeDelta::minutes(amount)),
+        "h" => Some(TimeDelta::hours(amount)),
+        "d" => Some(TimeDelta::days(amount)),
+        _ => None,
+    }
+}
+
+pub fn bucket_start(time: DateTime<Utc>, duration: TimeDelta) -> DateTime<Utc> {
+    let step = duration.num_milliseconds();
+    let millis = time.timestamp_millis();
+    DateTime::from_timestamp_millis(millis - millis.rem_euclid(step)).unwrap_or(time)
+}
+
+fn push_price(
+    buckets: &mut BTreeMap<DateTime<Utc>, Candle>,
+    symbol: &str,
+    interval: &str,
+    open_time: DateTime<Utc>,
+    price: Decimal,
+    size: Decimal,
+) {
+    let candle = buckets.entry(open_time).or_insert_with(|| {
+        Candle::new(
+            symbol.to_string(),
+            interval.to_string(),
+            open_time,
+            price,
+            price,
+            price,
+            price,
+            Decimal::ZERO,
+            Decimal::ZERO,
+        )
+    });
+    candle.close = price;
+    candle.high = candle.high.max(price);
+    candle.low = candle.low.min(price);
+    candle.volume += size;
+    candle.turnover += price * size;
+}
+
+/// Trades must be ordered by time and sequence, as returned by the trade
+/// repository. Buckets without trades produce no candle, matching KuCoin.
+pub fn candles_from_trades(
+    symbol: &str,
+    interval: &str,
+    duration: TimeDelta,
+    trades: &[Trade],
+) -> Vec<Candle> {
+    let mut buckets = BTreeMap::new();
+    for trade in trades {
+        let open_time = bucket_start(trade.time_point, duration);
+        push_price(
+            &mut buckets,
+            symbol,
+            interval,
+            open_time,
+            trade.price,
+            trade.size,
+        );
+    }
+    buckets.into_values().collect()
+}
+
+/// A bucket is incomplete when trades may be missing anywhere inside it,
+/// including the buckets holding the trades on either side of a gap.
+pub fn overlaps_gap(open_time: DateTime<Utc>, duration: TimeDelta, gaps: &[TradeGap]) -> bool {
+    gaps.iter()
+        .any(|gap| open_time <= gap.end && open_time + duration > gap.start)
+}
+
+/// Ticker snapshots only carry the last traded price, so the resulting
+/// candles are sampled prices with zero volume and turnover.
+pub fn candles_from_snapshots(
+    symbol: &str,
+    interval: &str,
+    duration: TimeDelta,
+    snapshots: &[TickerSnapshot],
+) -> Vec<Candle> {
+    let mut buckets = BTreeMap::new();
+    for snapshot in snapshots {
+        let open_time = bucket_start(snapshot.time_point, duration);
+        push_price(
+            &mut buckets,
+            symbol,
+            interval,
+            open_time,
+            snapshot.last,
+            Decimal::ZERO,
+        );
+    }
+    buckets.into_values().collect()
+}
+
+/// Rolls finer candles (ordered by open time) up into `duration` buckets,
+/// which must be a multiple of the source interval.
+pub fn roll_up(candles: &[Candle], interval: &str, duration: TimeDelta) -> Vec<Candle> {
+    let mut buckets: BTreeMap<DateTime<Utc>, Candle> = BTreeMap::new();
+    for candle in candles {
+        let open_time = bucket_start(candle.open_time, duration);
+        let rolled = buckets.entry(open_time).or_insert_with(|| {
+            Candle::new(
+                candle.symbol.clone(),
+                interval.to_string(),
+                open_time,
+                candle.open,
+                candle.close,
+                candle.high,
+                candle.low,
+                Decimal::ZERO,
+                Decimal::ZERO,
+            )
+        });
+        rolled.close = candle.close;
+        rolled.high = rolled.high.max(candle.high);
+        rolled.low = rolled.low.min(candle.low);
+        rolled.volume += candle.volume;
+        rolled.turnover += candle.turnover;
+    }
+    buckets.into_values().collect()
+}
+
+fn deviation(value: Decimal, reference: Decimal) -> Decimal {
+    let diff = (value - reference).abs();
+    if reference.is_zero() {
+        diff
+    } else {
+        diff / reference.abs()
+    }
+}
+
+/// Compares aggregated candles with the exchange's own candles over the same
+/// window. Reference candles without an aggregated counterpart count as
+/// missing; the others are mismatched when any price or the volume deviates by
+/// more than `CONSISTENCY_TOLERANCE`.
+#[allow(clippy::too_many_arguments)]
+pub fn compare_candles(
+    symbol: &str,
+    interval: &str,
+    source: &str,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    aggregated: &[Candle],
+    reference: &[Candle],
+    checked_at: DateTime<Utc>,
+) -> CandleConsistencyCheck {
+    let aggregated: BTreeMap<DateTime<Utc>, &Candle> =
+        aggregated.iter().map(|c| (c.open_time, c)).collect();
+    let mut check = CandleConsistencyCheck {
+        symbol: symbol.to_string(),
+        interval: interval.to_string(),
+        source: source.to_string(),
+        window_start,
+        window_end,
+        compared: 0,
+        missing: 0,
+        mismatched: 0,
+        max_price_deviation: Decimal::ZERO,
+        max_volume_deviation: Decimal::ZERO,
+        checked_at,
+    };
+
+    for expected in reference {
+        if expected.open_time < window_start || expected.open_time >= window_end {
+            continue;
+        }
+        let Some(actual) = aggregated.get(&expected.open_time) else {
+            check.missing += 1;
+            continue;
+        };
+        let price_deviation = [
+            deviation(actual.open, expected.open),
+            deviation(actual.close, expected.close),
+            deviation(actual.high, expected.high),
+            deviation(actual.low, expected.low),
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or_default();
+        let volume_deviation = deviation(actual.volume, expected.volume);
+
+        check.compared += 1;
+        if price_deviation > CONSISTENCY_TOLERANCE || volume_deviation > CONSISTENCY_TOLERANCE {
+            check.mismatched += 1;
+        }
+        check.max_price_deviation = check.max_price_deviation.max(price_deviation);
+        check.max_volume_deviation = check.max_volume_deviation.max(volume_deviation);
+    }
+
+    check.max_price_deviation = check.max_price_deviation.normalize();
+    check.max_volume_deviation = check.max_volume_deviation.normalize();
+    check
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::candle::interval_name;
+
+    fn d(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1_699_999_200 + secs, 0).unwrap()
+    }
+
+    fn trade(sequence: i64, secs: i64, price: &str, size: &str) -> Trade {
+        Trade::new(
+            "BTC-USDT".to_string(),
+            sequence,
+            d(price),
+            d(size),
+            "buy".to_string(),
+            at(secs),
+        )
+    }
+
+    fn candle(secs: i64, open: &str, close: &str, high: &str, low: &str, volume: &str) -> Candle {
+        Candle::new(
+            "BTC-USDT".to_string(),
+            "1min".to_string(),
+            at(secs),
+            d(open),
+            d(close),
+            d(high),
+            d(low),
+            d(volume),
+            Decimal::ZERO,
+        )
+    }
+
+    #[test]
+    fn parses_kucoin_names_and_short_forms() {
+        assert_eq!(parse_interval("1min"), Some(TimeDelta::minutes(1)));
+        assert_eq!(parse_interval("4hour"), Some(TimeDelta::hours(4)));
+        assert_eq!(parse_interval("15s"), Some(TimeDelta::seconds(15)));
+        assert_eq!(parse_interval("3m"), Some(TimeDelta::minutes(3)));
+        assert_eq!(parse_interval("2h"), Some(TimeDelta::hours(2)));
+        assert_eq!(parse_interval("1d"), Some(TimeDelta::days(1)));
+        for invalid in ["", "m", "0m", "3x", "3"] {
+            assert_eq!(parse_interval(invalid), None, "{}", invalid);
+        }
+
+        assert_eq!(interval_name(TimeDelta::minutes(3)), Some("3min"));
+        assert_eq!(interval_name(TimeDelta::seconds(15)), None);
+    }
+
+    #[test]
+    fn bucket_start_keeps_boundaries_and_floors_the_rest() {
+        let minute = TimeDelta::minutes(1);
+        assert_eq!(bucket_start(at(60), minute), at(60));
+        assert_eq!(
+            bucket_start(at(60) - TimeDelta::milliseconds(1), minute),
+            at(0)
+        );
+        assert_eq!(bucket_start(at(119), minute), at(60));
+    }
+
+    #[test]
+    fn trades_fill_buckets_and_skip_empty_ones() {
+        let trades = [
+            trade(1, 0, "10", "1"),
+            trade(2, 30, "12", "2"),
+            trade(3, 59, "9", "1"),
+            trade(4, 60, "11", "1"),
+            trade(5, 200, "13", "1"),
+        ];
+        let candles = candles_from_trades("BTC-USDT", "1min", TimeDelta::minutes(1), &trades);
+
+        let open_times: Vec<_> = candles.iter().map(|c| c.open_time).collect();
+        assert_eq!(open_times, [at(0), at(60), at(180)]);
+        let first = &candles[0];
+        assert_eq!(
+            (first.open, first.close, first.high, first.low),
+            (d("10"), d("9"), d("12"), d("9"))
+        );
+        assert_eq!(first.volume, d("4"));
+        assert_eq!(first.turnover, d("43"));
+        assert_eq!(candles[1].open, d("11"));
+    }
+
+    #[test]
+    fn gaps_touching_a_bucket_edge_mark_it_incomplete() {
+        let minute = TimeDelta::minutes(1);
+        let gap = |start: i64, end: i64| {
+            TradeGap::new(
+                &trade(1, start, "1", "1"),
+                &trade(3, end, "1", "1"),
+                at(300),
+            )
+        };
+
+        // The trade after the gap opens the bucket.
+        assert!(overlaps_gap(at(60), minute, &[gap(30, 60)]));
+        // The trade before the gap closes the previous bucket.
+        assert!(overlaps_gap(at(0), minute, &[gap(59, 90)]));
+        // A gap starting exactly at the next bucket leaves this one whole.
+        assert!(!overlaps_gap(at(0), minute, &[gap(60, 90)]));
+        assert!(!overlaps_gap(at(120), minute, &[gap(30, 60)]));
+        assert!(!overlaps_gap(at(0), minute, &[]));
+    }
+
+    #[test]
+    fn roll_up_merges_finer_candles_and_skips_empty_buckets() {
+        let candles = [
+            candle(0, "10", "11", "12", "9", "1"),
+            candle(60, "11", "13", "14", "10", "2"),
+            candle(120, "13", "12", "13", "8", "3"),
+            candle(360, "20", "21", "22", "19", "4"),
+        ];
+        let rolled = roll_up(&candles, "3min", TimeDelta::minutes(3));
+
+        assert_eq!(rolled.len(), 2);
+        let first = &rolled[0];
+        assert_eq!(first.open_time, at(0));
+        assert_eq!(first.interval, "3min");
+        assert_eq!(
+            (first.open, first.close, first.high, first.low),
+            (d("10"), d("12"), d("14"), d("8"))
+        );
+        assert_eq!(first.volume, d("6"));
+        assert_eq!(rolled[1].open_time, at(360));
+    }
+
+    #[test]
+    fn compare_counts_missing_and_mismatched_candles_in_the_window() {
+        let aggregated = [
+            candle(0, "10", "11", "12", "9", "1"),
+            candle(60, "11", "13", "14", "10", "2"),
+        ];
+        let reference = [
+            candle(0, "10", "11", "12", "9", "1"),
+            candle(60, "11", "13", "14", "10", "2.5"),
+            candle(120, "13", "12", "13", "8", "3"),
+            candle(180, "13", "12", "13", "8", "3"),
+        ];
+        let check = compare_candles(
+            "BTC-USDT",
+            "1min",
+            "trades",
+            at(0),
+            at(180),
+            &aggregated,
+            &reference,
+            at(300),
+        );
+
+        assert_eq!(check.compared, 2);
+        assert_eq!(check.missing, 1);
+        assert_eq!(check.mismatched, 1);
+        assert_eq!(check.max_price_deviation, Decimal::ZERO);
+        assert_eq!(check.max_volume_deviation, d("0.2"));
+    }
+}
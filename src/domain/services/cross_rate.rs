@@ -0,0 +1,354 @@
+use crate::domain::entities::{fiat_price::FiatPrice, symbol::Symbol, ticker::Ticker};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub const MAX_HOPS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PathPreference {
+    FewestHops,
+    MostLiquid,
+}
+
+#[derive(Debug, Clone)]
+struct RateEdge {
+    to: String,
+    symbol: String,
+    rate: Decimal,
+    inverted: bool,
+    spread: Decimal,
+    time_point: DateTime<Utc>,
+}
+
+type PartialPath<'a> = (Decimal, Vec<(&'a str, &'a RateEdge)>);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RateLeg {
+    pub symbol: String,
+    pub from: String,
+    pub to: String,
+    pub rate: Decimal,
+    pub inverted: bool,
+    pub spread: Decimal,
+    pub time_point: DateTime<Utc>,
+    pub staleness_secs: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConversionPath {
+    pub from: String,
+    pub to: String,
+    pub price: Decimal,
+    pub legs: Vec<RateLeg>,
+}
+
+/// Conversion graph over spot symbols: every tradable symbol with a usable
+/// price adds a base->quote edge at the mid price and the inverse quote->base
+/// edge. Fiat reference prices only add currency->fiat edges, so fiat
+/// currencies can be a target but are never used as an intermediate hop.
+#[derive(Debug, Clone, Default)]
+pub struct RateGraph {
+    edges: HashMap<String, Vec<RateEdge>>,
+}
+
+impl RateGraph {
+    pub fn new(symbols: &[Symbol], tickers: &[Ticker], fiat_prices: &[FiatPrice]) -> Self {
+        let tickers: HashMap<&str, &Ticker> =
+            tickers.iter().map(|t| (t.symbol.as_str(), t)).collect();
+        let mut graph = Self::default();
+
+        for symbol in symbols.iter().filter(|s| s.enable_trading) {
+            let Some(ticker) = tickers.get(symbol.symbol.as_str()) else {
+                continue;
+            };
+            let Some((price, spread)) = mid_price(ticker) else {
+                continue;
+            };
+            graph.add_edge(
+                &symbol.base_currency,
+                RateEdge {
+                    to: symbol.quote_currency.clone(),
+                    symbol: symbol.symbol.clone(),
+                    rate: price,
+                    inverted: false,
+                    spread,
+                    time_point: ticker.time_point,
+                },
+            );
+            graph.add_edge(
+                &symbol.quote_currency,
+                RateEdge {
+                    to: symbol.base_currency.clone(),
+                    symbol: symbol.symbol.clone(),
+                    rate: Decimal::ONE / price,
+                    inverted: true,
+                    spread,
+                    time_point: ticker.time_point,
+                },
+            );
+        }
+
+        for fiat in fiat_prices {
+            let Some(price) = positive_price(fiat.price.parse().ok()) else {
+                continue;
+            };
+            graph.add_edge(
+                &fiat.currency,
+                RateEdge {
+                    to: fiat.base.clone(),
+                    symbol: format!("{}-{}", fiat.currency, fiat.base),
+                    rate: price,
+                    inverted: false,
+                    spread: Decimal::ZERO,
+                    time_point: fiat.time_point,
+                },
+            );
+        }
+
+        graph
+    }
+
+    fn add_edge(&mut self, from: &str, edge: RateEdge) {
+        self.edges.entry(from.to_string()).or_default().push(edge);
+    }
+
+    /// Layered search over at most `MAX_HOPS` legs. `FewestHops` takes the
+    /// shortest reachable layer and `MostLiquid` the path with the lowest
+    /// summed relative spread; ties go to the tighter or shorter path.
+    pub fn find_path(
+        &self,
+        from: &str,
+        to: &str,
+        preference: PathPreference,
+        now: DateTime<Utc>,
+    ) -> Option<ConversionPath> {
+        if from == to {
+            return Some(ConversionPath {
+                from: from.to_string(),
+                to: to.to_string(),
+                price: Decimal::ONE,
+                legs: Vec::new(),
+            });
+        }
+
+        let mut layer: HashMap<&str, PartialPath<'_>> = HashMap::new();
+        layer.insert(from, (Decimal::ZERO, Vec::new()));
+        let mut best: Option<PartialPath> = None;
+
+        for _ in 0..MAX_HOPS {
+            let mut next: HashMap<&str, PartialPath<'_>> = HashMap::new();
+            for (node, (cost, path)) in &layer {
+                for edge in self.edges.get(*node).into_iter().flatten() {
+                    let target = edge.to.as_str();
+                    if target == from || path.iter().any(|(n, _)| *n == target) {
+                        continue;
+                    }
+                    let cost = *cost + edge.spread;
+                    if next.get(target).is_some_and(|(c, _)| *c <= cost) {
+                        continue;
+                    }
+                    let mut path = path.clone();
+                    path.push((*node, edge));
+                    next.insert(target, (cost, path));
+                }
+            }
+
+            if let Some((cost, path)) = next.remove(to) {
+                if best.as_ref().is_none_or(|(c, _)| cost < *c) {
+                    best = Some((cost, path));
+                }
+                if preference == PathPreference::FewestHops {
+                    break;
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            layer = next;
+        }
+
+        let (_, path) = best?;
+        let legs: Vec<RateLeg> = path
+            .into_iter()
+            .map(|(node, edge)| RateLeg {
+                symbol: edge.symbol.clone(),
+                from: node.to_string(),
+                to: edge.to.clone(),
+                rate: edge.rate,
+                inverted: edge.inverted,
+                spread: edge.spread,
+                time_point: edge.time_point,
+                staleness_secs: (now - edge.time_point).num_seconds().max(0),
+            })
+            .collect();
+        let price = legs
+            .iter()
+            .try_fold(Decimal::ONE, |acc, leg| acc.checked_mul(leg.rate))?;
+
+        Some(ConversionPath {
+            from: from.to_string(),
+            to: to.to_string(),
+            price: price.normalize(),
+            legs,
+        })
+    }
+}
+
+fn positive_price(value: Option<Decimal>) -> Option<Decimal> {
+    value.filter(|p| *p > Decimal::ZERO)
+}
+
+fn mid_price(ticker: &Ticker) -> Option<(Decimal, Decimal)> {
+    match (positive_price(ticker.buy), positive_price(ticker.sell)) {
+        (Some(buy), Some(sell)) if sell >= buy => {
+            let mid = (buy + sell) / Decimal::TWO;
+            Some((mid, (sell - buy) / mid))
+        }
+        // Without a quote the spread is unknown, so treat the leg as maximally illiquid.
+        _ => positive_price(ticker.last).map(|last| (last, Decimal::ONE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn symbol(base: &str, quote: &str) -> Symbol {
+        Symbol::new(
+            format!("{}-{}", base, quote),
+            format!("{}-{}", base, quote),
+            base.to_string(),
+            quote.to_string(),
+            quote.to_string(),
+            "USDS".to_string(),
+            d("0.001"),
+            d("0.1"),
+            d("100"),
+            d("1000000"),
+            d("0.0001"),
+            d("0.01"),
+            d("0.1"),
+            d("0.1"),
+            None,
+            false,
+            true,
+            1,
+            d("1"),
+            d("1"),
+            false,
+        )
+    }
+
+    fn ticker(symbol: &str, buy: &str, sell: &str) -> Ticker {
+        Ticker::new(
+            symbol.to_string(),
+            symbol.to_string(),
+            d("0.001"),
+            d("0.001"),
+            d("1"),
+            d("1"),
+            Some(d(buy)),
+            Some(d(sell)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Utc::now(),
+        )
+    }
+
+    fn graph() -> RateGraph {
+        let symbols = [
+            symbol("ETH", "BTC"),
+            symbol("BTC", "USDT"),
+            symbol("ETH", "USDT"),
+            symbol("XRP", "BTC"),
+        ];
+        let tickers = [
+            ticker("ETH-BTC", "0.05", "0.05"),
+            ticker("BTC-USDT", "40000", "40000"),
+            // Wide direct book, so the two-hop route through BTC is tighter.
+            ticker("ETH-USDT", "1800", "2200"),
+            ticker("XRP-BTC", "0.00001", "0.00001"),
+        ];
+        RateGraph::new(&symbols, &tickers, &[])
+    }
+
+    #[test]
+    fn direct_and_inverted_legs() {
+        let graph = graph();
+        let path = graph
+            .find_path("BTC", "USDT", PathPreference::FewestHops, Utc::now())
+            .unwrap();
+        assert_eq!(path.price, d("40000"));
+        assert_eq!(path.legs.len(), 1);
+
+        let path = graph
+            .find_path("USDT", "BTC", PathPreference::FewestHops, Utc::now())
+            .unwrap();
+        assert_eq!(path.price, d("0.000025"));
+        assert!(path.legs[0].inverted);
+    }
+
+    #[test]
+    fn multi_hop_path_multiplies_rates() {
+        let path = graph()
+            .find_path("XRP", "USDT", PathPreference::FewestHops, Utc::now())
+            .unwrap();
+        assert_eq!(path.price, d("0.4"));
+        let route: Vec<&str> = path.legs.iter().map(|l| l.symbol.as_str()).collect();
+        assert_eq!(route, ["XRP-BTC", "BTC-USDT"]);
+    }
+
+    #[test]
+    fn missing_currency_has_no_path() {
+        assert!(
+            graph()
+                .find_path("ETH", "DOGE", PathPreference::MostLiquid, Utc::now())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn most_liquid_prefers_tighter_longer_path() {
+        let graph = graph();
+        let fewest = graph
+            .find_path("ETH", "USDT", PathPreference::FewestHops, Utc::now())
+            .unwrap();
+        assert_eq!(fewest.legs.len(), 1);
+        assert_eq!(fewest.price, d("2000"));
+
+        let liquid = graph
+            .find_path("ETH", "USDT", PathPreference::MostLiquid, Utc::now())
+            .unwrap();
+        let route: Vec<&str> = liquid.legs.iter().map(|l| l.symbol.as_str()).collect();
+        assert_eq!(route, ["ETH-BTC", "BTC-USDT"]);
+        assert_eq!(liquid.price, d("2000"));
+    }
+
+    #[test]
+    fn overflowing_path_is_dropped() {
+        let symbols = [symbol("A", "B"), symbol("B", "C")];
+        let tickers = [
+            ticker("A-B", "10000000000000000000", "10000000000000000000"),
+            ticker("B-C", "10000000000000000000", "10000000000000000000"),
+        ];
+        let graph = RateGraph::new(&symbols, &tickers, &[]);
+        assert!(
+            graph
+                .find_path("A", "C", PathPreference::FewestHops, Utc::now())
+                .is_none()
+        );
+    }
+}
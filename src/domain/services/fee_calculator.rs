@@ -0,0 +1,170 @@
+use crate::domain::entities::{symbol::Symbol, ticker::Ticker};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct VipFeeRates {
+    pub maker_fee_rate: Decimal,
+    pub taker_fee_rate: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FeeEstimate {
+    pub symbol: String,
+    pub fee_category: i16,
+    pub fee_currency: String,
+    pub maker_fee_rate: Decimal,
+    pub taker_fee_rate: Decimal,
+    pub maker_fee: Decimal,
+    pub taker_fee: Decimal,
+}
+
+/// The requested price and size are too large to estimate fees for.
+#[derive(Debug, Clone)]
+pub struct FeeOverflow {
+    pub symbol: String,
+}
+
+impl fmt::Display for FeeOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fee estimate for {} overflows", self.symbol)
+    }
+}
+
+impl std::error::Error for FeeOverflow {}
+
+/// KuCoin charges `base rate * coefficient`, where the base rate depends on the
+/// symbol's fee category and the user's VIP level. The ticker carries the
+/// default-tier base rates; `vip` replaces them with the caller's own tier
+/// rates for the same category. The fee is charged on size when the fee
+/// currency is the base currency and on funds otherwise.
+pub fn estimate_fees(
+    symbol: &Symbol,
+    ticker: &Ticker,
+    price: Decimal,
+    size: Decimal,
+    vip: Option<VipFeeRates>,
+) -> Result<FeeEstimate, FeeOverflow> {
+    let overflow = || FeeOverflow {
+        symbol: symbol.symbol.clone(),
+    };
+    let (maker_base, taker_base) = match vip {
+        Some(rates) => (rates.maker_fee_rate, rates.taker_fee_rate),
+        None => (ticker.maker_fee_rate, ticker.taker_fee_rate),
+    };
+    let maker_fee_rate = maker_base
+        .checked_mul(symbol.maker_fee_coefficient)
+        .ok_or_else(overflow)?;
+    let taker_fee_rate = taker_base
+        .checked_mul(symbol.taker_fee_coefficient)
+        .ok_or_else(overflow)?;
+
+    let charged_on = if symbol.fee_currency == symbol.base_currency {
+        size
+    } else {
+        price.checked_mul(size).ok_or_else(overflow)?
+    };
+    let maker_fee = charged_on
+        .checked_mul(maker_fee_rate)
+        .ok_or_else(overflow)?;
+    let taker_fee = charged_on
+        .checked_mul(taker_fee_rate)
+        .ok_or_else(overflow)?;
+
+    Ok(FeeEstimate {
+        symbol: symbol.symbol.clone(),
+        fee_category: symbol.fee_category,
+        fee_currency: symbol.fee_currency.clone(),
+        maker_fee_rate: maker_fee_rate.normalize(),
+        taker_fee_rate: taker_fee_rate.normalize(),
+        maker_fee: maker_fee.normalize(),
+        taker_fee: taker_fee.normalize(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn d(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn symbol(fee_currency: &str) -> Symbol {
+        Symbol::new(
+            "BTC-USDT".to_string(),
+            "BTC-USDT".to_string(),
+            "BTC".to_string(),
+            "USDT".to_string(),
+            fee_currency.to_string(),
+            "USDS".to_string(),
+            d("0.001"),
+            d("0.1"),
+            d("100"),
+            d("1000000"),
+            d("0.0001"),
+            d("0.01"),
+            d("0.1"),
+            d("0.1"),
+            Some(d("1")),
+            false,
+            true,
+            1,
+            d("0.5"),
+            d("2"),
+            false,
+        )
+    }
+
+    fn ticker() -> Ticker {
+        Ticker::new(
+            "BTC-USDT".to_string(),
+            "BTC-USDT".to_string(),
+            d("0.001"),
+            d("0.002"),
+            d("1"),
+            d("1"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn quote_fee_is_charged_on_funds() {
+        let estimate = estimate_fees(&symbol("USDT"), &ticker(), d("100"), d("2"), None).unwrap();
+        assert_eq!(estimate.maker_fee_rate, d("0.001"));
+        assert_eq!(estimate.taker_fee_rate, d("0.002"));
+        assert_eq!(estimate.maker_fee, d("0.2"));
+        assert_eq!(estimate.taker_fee, d("0.4"));
+    }
+
+    #[test]
+    fn base_fee_is_charged_on_size_with_vip_rates() {
+        let vip = VipFeeRates {
+            maker_fee_rate: d("0.0005"),
+            taker_fee_rate: d("0.0005"),
+        };
+        let estimate =
+            estimate_fees(&symbol("BTC"), &ticker(), d("100"), d("2"), Some(vip)).unwrap();
+        assert_eq!(estimate.fee_currency, "BTC");
+        assert_eq!(estimate.maker_fee, d("0.0005"));
+        assert_eq!(estimate.taker_fee, d("0.002"));
+    }
+
+    #[test]
+    fn overflowing_funds_are_rejected() {
+        let estimate = estimate_fees(&symbol("USDT"), &ticker(), Decimal::MAX, d("2"), None);
+        assert!(estimate.is_err());
+    }
+}
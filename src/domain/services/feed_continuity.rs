@@ -0,0 +1,137 @@
+use crate::domain::entities::balance_change::BalanceChange;
+use crate::domain::entities::order_event::OrderEvent;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+
+/// KuCoin's private channels carry no sequence numbers, so continuity is
+/// checked from the payloads: a balance change must start from the balance the
+/// previous change left, and a match must add its size to the order's previous
+/// filled size. Anything else means a message was missed.
+#[derive(Default)]
+pub struct FeedContinuity {
+    balances: HashMap<(String, String), (Decimal, Decimal)>,
+    filled: HashMap<String, Decimal>,
+}
+
+fn parse(value: &str) -> Option<Decimal> {
+    value.trim().parse().ok()
+}
+
+impl FeedContinuity {
+    pub fn check_balance(&mut self, change: &BalanceChange) -> bool {
+        let key = (change.account_id.clone(), change.currency.clone());
+        let parsed = parse(&change.available)
+            .zip(parse(&change.hold))
+            .zip(parse(&change.available_change).zip(parse(&change.hold_change)));
+        let Some(((available, hold), (available_change, hold_change))) = parsed else {
+            self.balances.remove(&key);
+            return true;
+        };
+
+        let continuous = match self.balances.get(&key) {
+            Some(&(previous_available, previous_hold)) => {
+                previous_available == available - available_change
+                    && previous_hold == hold - hold_change
+            }
+            None => true,
+        };
+        self.balances.insert(key, (available, hold));
+        continuous
+    }
+
+    pub fn check_order(&mut self, event: &OrderEvent) -> bool {
+        let filled = event.filled_size.as_deref().and_then(parse);
+        let matched = event.match_size.as_deref().and_then(parse);
+
+        let continuous = match (
+            event.event_type.as_str(),
+            self.filled.get(&event.order_id),
+            filled,
+            matched,
+        ) {
+            ("match", Some(&previous), Some(filled), Some(matched)) => previous + matched == filled,
+            _ => true,
+        };
+
+        match (event.event_type.as_str(), filled) {
+            ("filled" | "canceled", _) | (_, None) => {
+                self.filled.remove(&event.order_id);
+            }
+            (_, Some(filled)) => {
+                self.filled.insert(event.order_id.clone(), filled);
+            }
+        }
+        continuous
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn balance(available: &str, available_change: &str) -> BalanceChange {
+        BalanceChange::new(
+            "main".to_string(),
+            "USDT".to_string(),
+            available.to_string(),
+            available.to_string(),
+            "0".to_string(),
+            available_change.to_string(),
+            "0".to_string(),
+            "trade.setted".to_string(),
+            "1".to_string(),
+            Utc::now(),
+        )
+    }
+
+    fn order(event_type: &str, filled: &str, matched: Option<&str>) -> OrderEvent {
+        OrderEvent::new(
+            "order-1".to_string(),
+            None,
+            "BTC-USDT".to_string(),
+            event_type.to_string(),
+            "open".to_string(),
+            "buy".to_string(),
+            "limit".to_string(),
+            Some("100".to_string()),
+            Some("1".to_string()),
+            Some("1".to_string()),
+            Some(filled.to_string()),
+            None,
+            None,
+            None,
+            matched.map(str::to_string),
+            None,
+            None,
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn balance_changes_must_chain() {
+        let mut continuity = FeedContinuity::default();
+        assert!(continuity.check_balance(&balance("10", "10")));
+        assert!(continuity.check_balance(&balance("7.5", "-2.5")));
+        // A change from 5 to 6 means the step from 7.5 to 5 was missed.
+        assert!(!continuity.check_balance(&balance("6", "1")));
+        assert!(continuity.check_balance(&balance("8", "2")));
+    }
+
+    #[test]
+    fn matches_must_add_up_to_filled_size() {
+        let mut continuity = FeedContinuity::default();
+        assert!(continuity.check_order(&order("open", "0", None)));
+        assert!(continuity.check_order(&order("match", "0.4", Some("0.4"))));
+        assert!(!continuity.check_order(&order("match", "0.9", Some("0.2"))));
+        assert!(continuity.check_order(&order("match", "1", Some("0.1"))));
+    }
+
+    #[test]
+    fn orders_first_seen_mid_life_are_accepted() {
+        let mut continuity = FeedContinuity::default();
+        assert!(continuity.check_order(&order("match", "0.7", Some("0.2"))));
+        assert!(continuity.check_order(&order("filled", "1", None)));
+        assert!(continuity.check_order(&order("match", "0.3", Some("0.3"))));
+    }
+}
@@ -0,0 +1,524 @@
+use crate::domain::entities::candle::Candle;
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IndicatorSpec {
+    Sma(usize),
+    Ema(usize),
+    Rsi(usize),
+    Macd(usize, usize, usize),
+    Bollinger(usize, f64),
+    Atr(usize),
+    Vwap,
+}
+
+impl FromStr for IndicatorSpec {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let parts: Vec<&str> = value.split(':').map(str::trim).collect();
+        let period = |i: usize| -> Result<usize> {
+            let p: usize = parts
+                .get(i)
+                .with_context(|| format!("Missing parameter in indicator '{}'", value))?
+                .parse()
+                .with_context(|| format!("Invalid parameter in indicator '{}'", value))?;
+            anyhow::ensure!(p > 0, "Indicator period must be positive in '{}'", value);
+            Ok(p)
+        };
+
+        match parts[0].to_lowercase().as_str() {
+            "sma" => Ok(Self::Sma(period(1)?)),
+            "ema" => Ok(Self::Ema(period(1)?)),
+            "rsi" => Ok(Self::Rsi(period(1)?)),
+            "macd" => Ok(Self::Macd(period(1)?, period(2)?, period(3)?)),
+            "bb" => {
+                let width = parts
+                    .get(2)
+                    .map(|w| w.parse::<f64>())
+                    .unwrap_or(Ok(2.0))
+                    .with_context(|| format!("Invalid band width in indicator '{}'", value))?;
+                Ok(Self::Bollinger(period(1)?, width))
+            }
+            "atr" => Ok(Self::Atr(period(1)?)),
+            "vwap" => Ok(Self::Vwap),
+            other => anyhow::bail!("Unknown indicator '{}'", other),
+        }
+    }
+}
+
+impl IndicatorSpec {
+    pub fn name(&self) -> String {
+        match self {
+            Self::Sma(n) => format!("sma_{}", n),
+            Self::Ema(n) => format!("ema_{}", n),
+            Self::Rsi(n) => format!("rsi_{}", n),
+            Self::Macd(fast, slow, signal) => format!("macd_{}_{}_{}", fast, slow, signal),
+            Self::Bollinger(n, k) => format!("bb_{}_{}", n, k),
+            Self::Atr(n) => format!("atr_{}", n),
+            Self::Vwap => "vwap".to_string(),
+        }
+    }
+
+    pub fn build(&self) -> Box<dyn Indicator> {
+        match *self {
+            Self::Sma(n) => Box::new(Sma::new(n)),
+            Self::Ema(n) => Box::new(Ema::new(n)),
+            Self::Rsi(n) => Box::new(Rsi::new(n)),
+            Self::Macd(fast, slow, signal) => Box::new(Macd::new(fast, slow, signal)),
+            Self::Bollinger(n, k) => Box::new(Bollinger::new(n, k)),
+            Self::Atr(n) => Box::new(Atr::new(n)),
+            Self::Vwap => Box::new(Vwap::default()),
+        }
+    }
+}
+
+/// Streaming indicator fed one closed candle at a time, oldest first. Returns
+/// `(output, value)` pairs once warmed up; single-output indicators use the
+/// output name "value".
+pub trait Indicator: Send + Sync {
+    fn update(&mut self, candle: &Candle) -> Vec<(&'static str, f64)>;
+}
+
+fn to_f64(value: rust_decimal::Decimal) -> f64 {
+    value.to_f64().unwrap_or(f64::NAN)
+}
+
+pub struct Sma {
+    period: usize,
+    window: VecDeque<f64>,
+    sum: f64,
+}
+
+impl Sma {
+    pub fn new(period: usize) -> Self {
+        Self {
+            period,
+            window: VecDeque::with_capacity(period + 1),
+            sum: 0.0,
+        }
+    }
+
+    fn next(&mut self, value: f64) -> Option<f64> {
+        self.window.push_back(value);
+        self.sum += value;
+        if self.window.len() > self.period {
+            self.sum -= self.window.pop_front().unwrap_or_default();
+        }
+        (self.window.len() == self.period).then(|| self.sum / self.period as f64)
+    }
+}
+
+impl Indicator for Sma {
+    fn update(&mut self, candle: &Candle) -> Vec<(&'static str, f64)> {
+        self.next(to_f64(candle.close))
+            .map(|v| vec![("value", v)])
+            .unwrap_or_default()
+    }
+}
+
+pub struct Ema {
+    alpha: f64,
+    seed: Sma,
+    value: Option<f64>,
+}
+
+impl Ema {
+    pub fn new(period: usize) -> Self {
+        Self {
+            alpha: 2.0 / (period as f64 + 1.0),
+            seed: Sma::new(period),
+            value: None,
+        }
+    }
+
+    fn next(&mut self, value: f64) -> Option<f64> {
+        self.value = match self.value {
+            Some(prev) => Some(prev + self.alpha * (value - prev)),
+            None => self.seed.next(value),
+        };
+        self.value
+    }
+}
+
+impl Indicator for Ema {
+    fn update(&mut self, candle: &Candle) -> Vec<(&'static str, f64)> {
+        self.next(to_f64(candle.close))
+            .map(|v| vec![("value", v)])
+            .unwrap_or_default()
+    }
+}
+
+/// Wilder's smoothing: seeded with the simple mean of the first `period`
+/// samples, then `avg = (avg * (period - 1) + x) / period`.
+struct Wilder {
+    period: usize,
+    seed: Sma,
+    value: Option<f64>,
+}
+
+impl Wilder {
+    fn new(period: usize) -> Self {
+        Self {
+            period,
+            seed: Sma::new(period),
+            value: None,
+        }
+    }
+
+    fn next(&mut self, value: f64) -> Option<f64> {
+        let n = self.period as f64;
+        self.value = match self.value {
+            Some(prev) => Some((prev * (n - 1.0) + value) / n),
+            None => self.seed.next(value),
+        };
+        self.value
+    }
+}
+
+pub struct Rsi {
+    gains: Wilder,
+    losses: Wilder,
+    prev_close: Option<f64>,
+}
+
+impl Rsi {
+    pub fn new(period: usize) -> Self {
+        Self {
+            gains: Wilder::new(period),
+            losses: Wilder::new(period),
+            prev_close: None,
+        }
+    }
+}
+
+impl Indicator for Rsi {
+    fn update(&mut self, candle: &Candle) -> Vec<(&'static str, f64)> {
+        let close = to_f64(candle.close);
+        let Some(prev) = self.prev_close.replace(close) else {
+            return Vec::new();
+        };
+        let change = close - prev;
+        let gain = self.gains.next(change.max(0.0));
+        let loss = self.losses.next((-change).max(0.0));
+
+        match (gain, loss) {
+            // A flat window has neither gains nor losses.
+            (Some(0.0), Some(0.0)) => vec![("value", 50.0)],
+            (Some(_), Some(0.0)) => vec![("value", 100.0)],
+            (Some(gain), Some(loss)) => vec![("value", 100.0 - 100.0 / (1.0 + gain / loss))],
+            _ => Vec::new(),
+        }
+    }
+}
+
+pub struct Macd {
+    fast: Ema,
+    slow: Ema,
+    signal: Ema,
+}
+
+impl Macd {
+    pub fn new(fast: usize, slow: usize, signal: usize) -> Self {
+        Self {
+            fast: Ema::new(fast),
+            slow: Ema::new(slow),
+            signal: Ema::new(signal),
+        }
+    }
+}
+
+impl Indicator for Macd {
+    fn update(&mut self, candle: &Candle) -> Vec<(&'static str, f64)> {
+        let close = to_f64(candle.close);
+        let (Some(fast), Some(slow)) = (self.fast.next(close), self.slow.next(close)) else {
+            return Vec::new();
+        };
+        let macd = fast - slow;
+        match self.signal.next(macd) {
+            Some(signal) => vec![
+                ("macd", macd),
+                ("signal", signal),
+                ("histogram", macd - signal),
+            ],
+            None => Vec::new(),
+        }
+    }
+}
+
+pub struct Bollinger {
+    period: usize,
+    width: f64,
+    window: VecDeque<f64>,
+}
+
+impl Bollinger {
+    pub fn new(period: usize, width: f64) -> Self {
+        Self {
+            period,
+            width,
+            window: VecDeque::with_capacity(period + 1),
+        }
+    }
+}
+
+impl Indicator for Bollinger {
+    fn update(&mut self, candle: &Candle) -> Vec<(&'static str, f64)> {
+        self.window.push_back(to_f64(candle.close));
+        if self.window.len() > self.period {
+            self.window.pop_front();
+        }
+        if self.window.len() < self.period {
+            return Vec::new();
+        }
+
+        let n = self.period as f64;
+        let mean = self.window.iter().sum::<f64>() / n;
+        let variance = self.window.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+        let band = self.width * variance.sqrt();
+        vec![
+            ("middle", mean),
+            ("upper", mean + band),
+            ("lower", mean - band),
+        ]
+    }
+}
+
+pub struct Atr {
+    smoothing: Wilder,
+    prev_close: Option<f64>,
+}
+
+impl Atr {
+    pub fn new(period: usize) -> Self {
+        Self {
+            smoothing: Wilder::new(period),
+            prev_close: None,
+        }
+    }
+}
+
+impl Indicator for Atr {
+    fn update(&mut self, candle: &Candle) -> Vec<(&'static str, f64)> {
+        let (high, low) = (to_f64(candle.high), to_f64(candle.low));
+        let range = match self.prev_close {
+            Some(prev) => (high - low)
+                .max((high - prev).abs())
+                .max((low - prev).abs()),
+            None => high - low,
+        };
+        self.prev_close = Some(to_f64(candle.close));
+
+        self.smoothing
+            .next(range)
+            .map(|v| vec![("value", v)])
+            .unwrap_or_default()
+    }
+}
+
+/// Session VWAP over typical price, reset at each UTC day boundary.
+#[derive(Default)]
+pub struct Vwap {
+    session: Option<NaiveDate>,
+    price_volume: f64,
+    volume: f64,
+}
+
+impl Indicator for Vwap {
+    fn update(&mut self, candle: &Candle) -> Vec<(&'static str, f64)> {
+        let day = candle.open_time.date_naive();
+        if self.session != Some(day) {
+            self.session = Some(day);
+            self.price_volume = 0.0;
+            self.volume = 0.0;
+        }
+
+        let typical = (to_f64(candle.high) + to_f64(candle.low) + to_f64(candle.close)) / 3.0;
+        let volume = to_f64(candle.volume);
+        self.price_volume += typical * volume;
+        self.volume += volume;
+
+        if self.volume > 0.0 {
+            vec![("value", self.price_volume / self.volume)]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, TimeDelta};
+    use rust_decimal::Decimal;
+
+    fn d(value: f64) -> Decimal {
+        Decimal::try_from(value).unwrap()
+    }
+
+    fn candle(index: i64, high: f64, low: f64, close: f64, volume: f64) -> Candle {
+        Candle::new(
+            "BTC-USDT".to_string(),
+            "1hour".to_string(),
+            DateTime::from_timestamp(1_700_006_400, 0).unwrap() + TimeDelta::hours(index),
+            d(close),
+            d(close),
+            d(high),
+            d(low),
+            d(volume),
+            Decimal::ZERO,
+        )
+    }
+
+    fn closes(values: &[f64]) -> Vec<Candle> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| candle(i as i64, c, c, c, 1.0))
+            .collect()
+    }
+
+    fn run(indicator: &mut dyn Indicator, candles: &[Candle], output: &str) -> Vec<f64> {
+        candles
+            .iter()
+            .flat_map(|c| indicator.update(c))
+            .filter(|(name, _)| *name == output)
+            .map(|(_, v)| v)
+            .collect()
+    }
+
+    fn assert_close(actual: &[f64], expected: &[f64], tolerance: f64) {
+        assert_eq!(
+            actual.len(),
+            expected.len(),
+            "{:?} vs {:?}",
+            actual,
+            expected
+        );
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() <= tolerance, "{:?} vs {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn sma_averages_the_trailing_window() {
+        let values = run(
+            &mut Sma::new(3),
+            &closes(&[1.0, 2.0, 3.0, 4.0, 5.0]),
+            "value",
+        );
+        assert_close(&values, &[2.0, 3.0, 4.0], 1e-12);
+    }
+
+    #[test]
+    fn ema_is_seeded_with_the_sma() {
+        let values = run(
+            &mut Ema::new(3),
+            &closes(&[2.0, 4.0, 6.0, 8.0, 12.0]),
+            "value",
+        );
+        assert_close(&values, &[4.0, 6.0, 9.0], 1e-12);
+    }
+
+    #[test]
+    fn rsi_matches_wilders_worked_example() {
+        let series = [
+            44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42, 45.84, 46.08, 45.89, 46.03,
+            45.61, 46.28, 46.28, 46.00, 46.03, 46.41, 46.22, 45.64,
+        ];
+        let values = run(&mut Rsi::new(14), &closes(&series), "value");
+        assert_close(&values, &[70.46, 66.25, 66.48, 69.35, 66.29, 57.92], 0.01);
+    }
+
+    #[test]
+    fn rsi_is_100_without_losses() {
+        let values = run(
+            &mut Rsi::new(3),
+            &closes(&[1.0, 2.0, 3.0, 4.0, 5.0]),
+            "value",
+        );
+        assert_close(&values, &[100.0, 100.0], 0.0);
+    }
+
+    #[test]
+    fn rsi_is_50_for_a_flat_series() {
+        let values = run(
+            &mut Rsi::new(3),
+            &closes(&[5.0, 5.0, 5.0, 5.0, 5.0]),
+            "value",
+        );
+        assert_close(&values, &[50.0, 50.0], 0.0);
+    }
+
+    #[test]
+    fn macd_signal_and_histogram_follow_the_emas() {
+        let candles = closes(&[1.0, 4.0, 9.0, 16.0, 25.0]);
+        let mut macd = Macd::new(2, 3, 2);
+        let outputs: Vec<_> = candles.iter().flat_map(|c| macd.update(c)).collect();
+        let names: Vec<_> = outputs.iter().map(|(name, _)| *name).collect();
+        assert_eq!(
+            names,
+            ["macd", "signal", "histogram", "macd", "signal", "histogram"]
+        );
+        let values: Vec<_> = outputs.iter().map(|(_, v)| *v).collect();
+        assert_close(
+            &values,
+            &[2.611111, 2.388889, 0.222222, 3.314815, 3.006173, 0.308642],
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn bollinger_uses_population_standard_deviation() {
+        let candles = closes(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        let mut bands = Bollinger::new(8, 2.0);
+        let outputs: Vec<_> = candles.iter().flat_map(|c| bands.update(c)).collect();
+        assert_eq!(outputs, [("middle", 5.0), ("upper", 9.0), ("lower", 1.0)]);
+    }
+
+    #[test]
+    fn atr_smooths_true_range_with_wilder() {
+        let candles = [
+            candle(0, 10.0, 8.0, 9.0, 1.0),
+            candle(1, 11.0, 9.0, 10.0, 1.0),
+            candle(2, 12.0, 9.0, 11.0, 1.0),
+            candle(3, 13.0, 11.0, 12.0, 1.0),
+        ];
+        let values = run(&mut Atr::new(3), &candles, "value");
+        assert_close(&values, &[7.0 / 3.0, 20.0 / 9.0], 1e-12);
+    }
+
+    #[test]
+    fn vwap_weights_typical_price_and_resets_daily() {
+        let candles = [
+            candle(0, 11.0, 9.0, 10.0, 1.0),
+            candle(1, 21.0, 19.0, 20.0, 3.0),
+            candle(24, 31.0, 29.0, 30.0, 2.0),
+        ];
+        let values = run(&mut Vwap::default(), &candles, "value");
+        assert_close(&values, &[10.0, 17.5, 30.0], 1e-12);
+    }
+
+    #[test]
+    fn parses_indicator_specs() {
+        assert_eq!(
+            "macd:12:26:9".parse::<IndicatorSpec>().unwrap(),
+            IndicatorSpec::Macd(12, 26, 9)
+        );
+        assert_eq!(
+            "bb:20".parse::<IndicatorSpec>().unwrap(),
+            IndicatorSpec::Bollinger(20, 2.0)
+        );
+        assert_eq!(
+            "EMA: 21".parse::<IndicatorSpec>().unwrap(),
+            IndicatorSpec::Ema(21)
+        );
+        assert!("sma:0".parse::<IndicatorSpec>().is_err());
+        assert!("rsi".parse::<IndicatorSpec>().is_err());
+        assert!("kama:10".parse::<IndicatorSpec>().is_err());
+    }
+}
@@ -0,0 +1,172 @@
+use crate::domain::entities::liquidity::{LiquidityInputs, LiquidityScore};
+use crate::domain::entities::order_book::OrderBookSnapshot;
+use crate::domain::entities::trade::Trade;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+const VOLUME_WEIGHT: f64 = 0.4;
+const DEPTH_WEIGHT: f64 = 0.3;
+const TRADES_WEIGHT: f64 = 0.2;
+const SPREAD_WEIGHT: f64 = 0.1;
+
+/// Quote notional resting within `band` (relative) of the mid on both sides.
+/// Empty levels are ignored; a book with no resting size has no depth.
+pub fn depth_within(book: &OrderBookSnapshot, band: Decimal) -> Option<(Decimal, Decimal)> {
+    let best_bid = book.bids.iter().find(|l| l.size > Decimal::ZERO)?.price;
+    let best_ask = book.asks.iter().find(|l| l.size > Decimal::ZERO)?.price;
+    let mid = (best_bid + best_ask) / Decimal::TWO;
+    if mid <= Decimal::ZERO {
+        return None;
+    }
+
+    let lower = mid * (Decimal::ONE - band);
+    let upper = mid * (Decimal::ONE + band);
+    let bids: Decimal = book
+        .bids
+        .iter()
+        .filter(|l| l.price >= lower)
+        .map(|l| l.price * l.size)
+        .sum();
+    let asks: Decimal = book
+        .asks
+        .iter()
+        .filter(|l| l.price <= upper)
+        .map(|l| l.price * l.size)
+        .sum();
+    let depth = bids + asks;
+    if depth <= Decimal::ZERO {
+        return None;
+    }
+
+    Some((depth, (best_ask - best_bid) / mid))
+}
+
+/// Trade frequency extrapolated from the span covered by the recent trades.
+pub fn trades_per_hour(trades: &[Trade]) -> f64 {
+    let (Some(first), Some(last)) = (
+        trades.iter().map(|t| t.time_point).min(),
+        trades.iter().map(|t| t.time_point).max(),
+    ) else {
+        return 0.0;
+    };
+    let span_secs = (last - first).num_milliseconds() as f64 / 1000.0;
+    if span_secs <= 0.0 {
+        return trades.len() as f64;
+    }
+    trades.len() as f64 * 3600.0 / span_secs
+}
+
+/// Each component is turned into a percentile rank across the candidates
+/// (a tighter spread ranks higher), then weighted into a 0-100 score.
+pub fn score_liquidity(
+    inputs: Vec<LiquidityInputs>,
+    computed_at: DateTime<Utc>,
+) -> Vec<LiquidityScore> {
+    let volume = percentile_ranks(inputs.iter().map(|i| i.volume_usdt).collect());
+    let depth = percentile_ranks(inputs.iter().map(|i| i.depth_usdt).collect());
+    let trades = percentile_ranks(inputs.iter().map(|i| i.trades_per_hour).collect());
+    let spread = percentile_ranks(inputs.iter().map(|i| -i.spread).collect());
+
+    let mut scores: Vec<LiquidityScore> = inputs
+        .into_iter()
+        .enumerate()
+        .map(|(i, input)| {
+            let score = 100.0
+                * (VOLUME_WEIGHT * volume[i]
+                    + DEPTH_WEIGHT * depth[i]
+                    + TRADES_WEIGHT * trades[i]
+                    + SPREAD_WEIGHT * spread[i]);
+            LiquidityScore::new(input, score, 0, computed_at)
+        })
+        .collect();
+
+    scores.sort_by(|a, b| b.score.total_cmp(&a.score));
+    for (rank, score) in scores.iter_mut().enumerate() {
+        score.rank = rank as i32 + 1;
+    }
+    scores
+}
+
+fn percentile_ranks(values: Vec<f64>) -> Vec<f64> {
+    if values.len() < 2 {
+        return vec![1.0; values.len()];
+    }
+    let max_rank = (values.len() - 1) as f64;
+    values
+        .iter()
+        .map(|v| values.iter().filter(|other| *other < v).count() as f64 / max_rank)
+        .collect()
+}
+
+pub fn to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::order_book::PriceLevel;
+
+    fn d(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn snapshot(bids: &[(&str, &str)], asks: &[(&str, &str)]) -> OrderBookSnapshot {
+        let levels = |levels: &[(&str, &str)]| {
+            levels
+                .iter()
+                .map(|(price, size)| PriceLevel::new(d(price), d(size)))
+                .collect()
+        };
+        OrderBookSnapshot::new(
+            "BTC-USDT".to_string(),
+            1,
+            levels(bids),
+            levels(asks),
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn depth_counts_levels_inside_the_band() {
+        let book = snapshot(&[("99", "1"), ("90", "5")], &[("101", "2"), ("120", "5")]);
+        let (depth, spread) = depth_within(&book, d("0.02")).unwrap();
+        assert_eq!(depth, d("301"));
+        assert_eq!(spread, d("0.02"));
+    }
+
+    #[test]
+    fn zero_size_levels_are_not_liquidity() {
+        let book = snapshot(
+            &[("100", "0"), ("99", "1")],
+            &[("100.5", "0"), ("101", "2")],
+        );
+        let (depth, spread) = depth_within(&book, d("0.02")).unwrap();
+        assert_eq!(depth, d("301"));
+        assert_eq!(spread, d("0.02"));
+
+        let empty = snapshot(&[("99", "0")], &[("101", "0")]);
+        assert!(depth_within(&empty, d("0.02")).is_none());
+    }
+
+    #[test]
+    fn scores_rank_the_most_liquid_symbol_first() {
+        let inputs = vec![
+            LiquidityInputs::new("THIN-USDT".to_string(), 10.0, 0.05, 100.0, 1.0),
+            LiquidityInputs::new("DEEP-USDT".to_string(), 1000.0, 0.001, 10000.0, 100.0),
+            LiquidityInputs::new("MID-USDT".to_string(), 100.0, 0.01, 1000.0, 10.0),
+        ];
+        let scores = score_liquidity(inputs, Utc::now());
+        let ranked: Vec<(&str, i32)> = scores
+            .iter()
+            .map(|s| (s.inputs.symbol.as_str(), s.rank))
+            .collect();
+        assert_eq!(
+            ranked,
+            [("DEEP-USDT", 1), ("MID-USDT", 2), ("THIN-USDT", 3)]
+        );
+        assert!((scores[0].score - 100.0).abs() < 1e-9);
+        assert!(scores[2].score.abs() < 1e-9);
+    }
+}
@@ -0,0 +1,10 @@
+pub mod candle_aggregation;
+pub mod cross_rate;
+pub mod fee_calculator;
+pub mod feed_continuity;
+pub mod indicators;
+pub mod liquidity;
+pub mod order_validator;
+pub mod portfolio_valuation;
+pub mod risk_metrics;
+pub mod triangular_arbitrage;
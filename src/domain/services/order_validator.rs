@@ -0,0 +1,335 @@
+use crate::domain::entities::symbol::Symbol;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderViolation {
+    TradingDisabled,
+    NonPositivePrice,
+    NonPositiveSize,
+    BelowBaseMinSize { size: Decimal, min: Decimal },
+    AboveBaseMaxSize { size: Decimal, max: Decimal },
+    BelowQuoteMinSize { funds: Decimal, min: Decimal },
+    AboveQuoteMaxSize { funds: Decimal, max: Decimal },
+    BelowMinFunds { funds: Decimal, min: Decimal },
+    PriceAboveLimit { price: Decimal, limit: Decimal },
+    PriceBelowLimit { price: Decimal, limit: Decimal },
+    Overflow,
+}
+
+#[derive(Debug, Clone)]
+pub struct ValidatedOrder {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub price: Decimal,
+    pub size: Decimal,
+    pub funds: Decimal,
+    pub violations: Vec<OrderViolation>,
+}
+
+impl ValidatedOrder {
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Rounds price and size onto the symbol's increments and reports every
+/// trading rule the rounded order breaks. Buy prices round down and sell
+/// prices round up so rounding never worsens the limit; size always rounds
+/// down. The price limit rule is only checked when a reference (last) price
+/// is given. Inputs too large to round or multiply are reported as a single
+/// `Overflow` violation instead of panicking.
+pub fn validate_order(
+    symbol: &Symbol,
+    side: OrderSide,
+    price: Decimal,
+    size: Decimal,
+    reference_price: Option<Decimal>,
+) -> ValidatedOrder {
+    let rounded_price = match side {
+        OrderSide::Buy => round_down(price, symbol.price_increment),
+        OrderSide::Sell => round_up(price, symbol.price_increment),
+    };
+    let rounded = rounded_price.zip(round_down(size, symbol.base_increment));
+    let Some((price, size, funds)) = rounded.and_then(|(price, size)| {
+        let funds = round_down(price.checked_mul(size)?, symbol.quote_increment)?;
+        Some((price, size, funds))
+    }) else {
+        return ValidatedOrder {
+            symbol: symbol.symbol.clone(),
+            side,
+            price,
+            size,
+            funds: Decimal::ZERO,
+            violations: vec![OrderViolation::Overflow],
+        };
+    };
+
+    let mut violations = Vec::new();
+
+    if !symbol.enable_trading {
+        violations.push(OrderViolation::TradingDisabled);
+    }
+    if price <= Decimal::ZERO {
+        violations.push(OrderViolation::NonPositivePrice);
+    }
+    if size <= Decimal::ZERO {
+        violations.push(OrderViolation::NonPositiveSize);
+    }
+    if size < symbol.base_min_size {
+        violations.push(OrderViolation::BelowBaseMinSize {
+            size,
+            min: symbol.base_min_size,
+        });
+    }
+    if size > symbol.base_max_size {
+        violations.push(OrderViolation::AboveBaseMaxSize {
+            size,
+            max: symbol.base_max_size,
+        });
+    }
+    if funds < symbol.quote_min_size {
+        violations.push(OrderViolation::BelowQuoteMinSize {
+            funds,
+            min: symbol.quote_min_size,
+        });
+    }
+    if funds > symbol.quote_max_size {
+        violations.push(OrderViolation::AboveQuoteMaxSize {
+            funds,
+            max: symbol.quote_max_size,
+        });
+    }
+    if let Some(min_funds) = symbol.min_funds
+        && funds < min_funds
+    {
+        violations.push(OrderViolation::BelowMinFunds {
+            funds,
+            min: min_funds,
+        });
+    }
+    if let Some(reference) = reference_price {
+        let factor = match side {
+            OrderSide::Buy => Decimal::ONE + symbol.price_limit_rate,
+            OrderSide::Sell => Decimal::ONE - symbol.price_limit_rate,
+        };
+        match (side, reference.checked_mul(factor)) {
+            (OrderSide::Buy, Some(limit)) if price > limit => {
+                violations.push(OrderViolation::PriceAboveLimit { price, limit });
+            }
+            (OrderSide::Sell, Some(limit)) if price < limit => {
+                violations.push(OrderViolation::PriceBelowLimit { price, limit });
+            }
+            (_, None) => violations.push(OrderViolation::Overflow),
+            _ => {}
+        }
+    }
+
+    ValidatedOrder {
+        symbol: symbol.symbol.clone(),
+        side,
+        price,
+        size,
+        funds,
+        violations,
+    }
+}
+
+pub fn round_down(value: Decimal, increment: Decimal) -> Option<Decimal> {
+    round_to_increment(value, increment, RoundingStrategy::ToNegativeInfinity)
+}
+
+pub fn round_up(value: Decimal, increment: Decimal) -> Option<Decimal> {
+    round_to_increment(value, increment, RoundingStrategy::ToPositiveInfinity)
+}
+
+fn round_to_increment(
+    value: Decimal,
+    increment: Decimal,
+    strategy: RoundingStrategy,
+) -> Option<Decimal> {
+    if increment <= Decimal::ZERO {
+        return Some(value);
+    }
+    let steps = value
+        .checked_div(increment)?
+        .round_dp_with_strategy(0, strategy);
+    steps.checked_mul(increment).map(|v| v.normalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn symbol() -> Symbol {
+        Symbol::new(
+            "BTC-USDT".to_string(),
+            "BTC-USDT".to_string(),
+            "BTC".to_string(),
+            "USDT".to_string(),
+            "USDT".to_string(),
+            "USDS".to_string(),
+            d("0.001"),
+            d("0.1"),
+            d("100"),
+            d("1000000"),
+            d("0.0001"),
+            d("0.01"),
+            d("0.1"),
+            d("0.1"),
+            Some(d("1")),
+            false,
+            true,
+            1,
+            d("1"),
+            d("1"),
+            false,
+        )
+    }
+
+    #[test]
+    fn buy_rounds_price_down_and_sell_rounds_price_up() {
+        let buy = validate_order(&symbol(), OrderSide::Buy, d("100.19"), d("0.12345"), None);
+        assert_eq!(buy.price, d("100.1"));
+        assert_eq!(buy.size, d("0.1234"));
+        assert_eq!(buy.funds, d("12.35"));
+        assert!(buy.is_valid());
+
+        let sell = validate_order(&symbol(), OrderSide::Sell, d("100.11"), d("0.12345"), None);
+        assert_eq!(sell.price, d("100.2"));
+        assert_eq!(sell.size, d("0.1234"));
+        assert!(sell.is_valid());
+    }
+
+    #[test]
+    fn zero_increment_leaves_values_unrounded() {
+        assert_eq!(round_down(d("1.23456"), Decimal::ZERO), Some(d("1.23456")));
+        assert_eq!(round_up(d("1.23456"), Decimal::ZERO), Some(d("1.23456")));
+
+        let mut symbol = symbol();
+        symbol.price_increment = Decimal::ZERO;
+        let order = validate_order(&symbol, OrderSide::Buy, d("100.123"), d("0.1"), None);
+        assert_eq!(order.price, d("100.123"));
+    }
+
+    #[test]
+    fn reports_trading_disabled() {
+        let mut symbol = symbol();
+        symbol.enable_trading = false;
+        let order = validate_order(&symbol, OrderSide::Buy, d("100"), d("0.1"), None);
+        assert_eq!(order.violations, vec![OrderViolation::TradingDisabled]);
+    }
+
+    #[test]
+    fn reports_non_positive_price_and_size() {
+        let order = validate_order(&symbol(), OrderSide::Buy, d("0"), d("0.1"), None);
+        assert!(order.violations.contains(&OrderViolation::NonPositivePrice));
+
+        let order = validate_order(&symbol(), OrderSide::Buy, d("100"), d("0"), None);
+        assert!(order.violations.contains(&OrderViolation::NonPositiveSize));
+    }
+
+    #[test]
+    fn reports_base_size_bounds() {
+        let order = validate_order(&symbol(), OrderSide::Buy, d("1000"), d("0.0005"), None);
+        assert!(
+            order
+                .violations
+                .contains(&OrderViolation::BelowBaseMinSize {
+                    size: d("0.0005"),
+                    min: d("0.001"),
+                })
+        );
+
+        let order = validate_order(&symbol(), OrderSide::Buy, d("1"), d("150"), None);
+        assert!(
+            order
+                .violations
+                .contains(&OrderViolation::AboveBaseMaxSize {
+                    size: d("150"),
+                    max: d("100"),
+                })
+        );
+    }
+
+    #[test]
+    fn reports_quote_size_bounds_and_min_funds() {
+        let order = validate_order(&symbol(), OrderSide::Buy, d("10"), d("0.005"), None);
+        assert_eq!(
+            order.violations,
+            vec![
+                OrderViolation::BelowQuoteMinSize {
+                    funds: d("0.05"),
+                    min: d("0.1"),
+                },
+                OrderViolation::BelowMinFunds {
+                    funds: d("0.05"),
+                    min: d("1"),
+                },
+            ]
+        );
+
+        let order = validate_order(&symbol(), OrderSide::Buy, d("20000"), d("60"), None);
+        assert_eq!(
+            order.violations,
+            vec![OrderViolation::AboveQuoteMaxSize {
+                funds: d("1200000"),
+                max: d("1000000"),
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_price_limits_against_reference() {
+        let order = validate_order(
+            &symbol(),
+            OrderSide::Buy,
+            d("120"),
+            d("0.1"),
+            Some(d("100")),
+        );
+        assert_eq!(
+            order.violations,
+            vec![OrderViolation::PriceAboveLimit {
+                price: d("120"),
+                limit: d("110.0"),
+            }]
+        );
+
+        let order = validate_order(
+            &symbol(),
+            OrderSide::Sell,
+            d("80"),
+            d("0.1"),
+            Some(d("100")),
+        );
+        assert_eq!(
+            order.violations,
+            vec![OrderViolation::PriceBelowLimit {
+                price: d("80"),
+                limit: d("90.0"),
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_overflow_instead_of_panicking() {
+        let order = validate_order(&symbol(), OrderSide::Buy, Decimal::MAX, d("10"), None);
+        assert_eq!(order.violations, vec![OrderViolation::Overflow]);
+
+        let mut symbol = symbol();
+        symbol.price_increment = Decimal::ZERO;
+        symbol.base_increment = Decimal::ZERO;
+        let order = validate_order(&symbol, OrderSide::Buy, Decimal::MAX, Decimal::MAX, None);
+        assert_eq!(order.violations, vec![OrderViolation::Overflow]);
+    }
+}
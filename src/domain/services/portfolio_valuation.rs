@@ -0,0 +1,391 @@
+use crate::domain::entities::account_balance::AccountBalance;
+use crate::domain::entities::fill::FillWithSymbol;
+use crate::domain::entities::portfolio::{AssetValuation, PortfolioValuation, SymbolPnl};
+use crate::domain::entities::symbol::Symbol;
+use crate::domain::services::cross_rate::{PathPreference, RateGraph};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, HashMap};
+
+pub const VALUATION_CURRENCY: &str = "USDT";
+pub const FIAT_CURRENCY: &str = "USD";
+
+fn rate(graph: &RateGraph, from: &str, to: &str, now: DateTime<Utc>) -> Option<Decimal> {
+    graph
+        .find_path(from, to, PathPreference::MostLiquid, now)
+        .map(|path| path.price)
+}
+
+/// Values every balance of the snapshot in USDT and USD, grouped per account.
+/// Balances that cannot be priced are kept in the breakdown without a value
+/// and left out of the totals.
+pub fn value_balances(
+    balances: &[AccountBalance],
+    graph: &RateGraph,
+    snapshot_at: DateTime<Utc>,
+) -> Result<Vec<PortfolioValuation>> {
+    let usdt_usd = rate(graph, VALUATION_CURRENCY, FIAT_CURRENCY, snapshot_at);
+    let mut accounts: BTreeMap<(String, String), Vec<AssetValuation>> = BTreeMap::new();
+
+    for b in balances {
+        let balance: Decimal = b
+            .balance
+            .parse()
+            .with_context(|| format!("Invalid balance for {}: {}", b.currency, b.balance))?;
+        // Accounts holding only zero balances are still valued (at zero), so
+        // every snapshot leaves a valuation behind.
+        let assets = accounts
+            .entry((b.account_id.clone(), b.account_type.clone()))
+            .or_default();
+        if balance.is_zero() {
+            continue;
+        }
+        let price_usdt = rate(graph, &b.currency, VALUATION_CURRENCY, snapshot_at);
+        let value_usdt = price_usdt.map(|p| (p * balance).normalize());
+        let value_usd = rate(graph, &b.currency, FIAT_CURRENCY, snapshot_at)
+            .map(|p| p * balance)
+            .or_else(|| value_usdt.zip(usdt_usd).map(|(v, r)| v * r))
+            .map(|v| v.normalize());
+
+        assets.push(AssetValuation::new(
+            b.account_id.clone(),
+            b.account_type.clone(),
+            b.currency.clone(),
+            balance,
+            price_usdt,
+            value_usdt,
+            value_usd,
+        ));
+    }
+
+    Ok(accounts
+        .into_iter()
+        .map(|((account_id, account_type), assets)| {
+            let value_usdt: Decimal = assets.iter().filter_map(|a| a.value_usdt).sum();
+            let value_usd: Decimal = assets.iter().filter_map(|a| a.value_usd).sum();
+            PortfolioValuation::new(
+                account_id,
+                account_type,
+                snapshot_at,
+                value_usdt.normalize(),
+                value_usd.normalize(),
+                assets,
+            )
+        })
+        .collect())
+}
+
+#[derive(Default)]
+struct Position {
+    quote_currency: String,
+    size: Decimal,
+    average_cost: Decimal,
+    realized: Decimal,
+}
+
+impl Position {
+    fn buy(&mut self, size: Decimal, price: Decimal) {
+        let total = self.size + size;
+        if total.is_zero() {
+            return;
+        }
+        self.average_cost = (self.average_cost * self.size + price * size) / total;
+        self.size = total;
+    }
+
+    // Spot balances cannot go short. Selling more than the fills bought means
+    // the inventory predates the fill history; its cost is unknown, so only
+    // the tracked part realizes PnL.
+    fn sell(&mut self, size: Decimal, price: Decimal) {
+        let closing = size.min(self.size);
+        self.realized += (price - self.average_cost) * closing;
+        self.size -= closing;
+        if self.size.is_zero() {
+            self.average_cost = Decimal::ZERO;
+        }
+    }
+}
+
+/// Average-cost PnL per spot symbol in its quote currency, carried forward
+/// from the `previous` positions by the fills since. Fees reduce realized
+/// PnL; fees paid outside the quote currency are converted at the rate from
+/// the graph and left out when no path exists. Unrealized PnL marks the open
+/// position at the graph's base/quote rate; symbols missing from `symbols`
+/// keep their position unmarked.
+pub fn pnl_from_fills(
+    previous: &[SymbolPnl],
+    fills: &[FillWithSymbol],
+    symbols: &[Symbol],
+    graph: &RateGraph,
+    now: DateTime<Utc>,
+) -> Result<Vec<SymbolPnl>> {
+    let symbols: HashMap<&str, &Symbol> = symbols.iter().map(|s| (s.symbol.as_str(), s)).collect();
+    let mut positions: BTreeMap<&str, (Option<&Symbol>, Position)> = previous
+        .iter()
+        .map(|p| {
+            let position = Position {
+                quote_currency: p.quote_currency.clone(),
+                size: p.position,
+                average_cost: p.average_cost,
+                realized: p.realized_pnl,
+            };
+            (
+                p.symbol.as_str(),
+                (symbols.get(p.symbol.as_str()).copied(), position),
+            )
+        })
+        .collect();
+
+    for FillWithSymbol { fill, symbol } in fills {
+        let Some(symbol) = symbol else {
+            continue;
+        };
+        let price: Decimal = fill
+            .price
+            .parse()
+            .with_context(|| format!("Invalid fill price for {}: {}", fill.trade_id, fill.price))?;
+        let size: Decimal = fill
+            .size
+            .parse()
+            .with_context(|| format!("Invalid fill size for {}: {}", fill.trade_id, fill.size))?;
+        let fee: Decimal = fill
+            .fee
+            .parse()
+            .with_context(|| format!("Invalid fill fee for {}: {}", fill.trade_id, fill.fee))?;
+
+        let (_, position) = positions.entry(symbol.symbol.as_str()).or_insert_with(|| {
+            let position = Position {
+                quote_currency: symbol.quote_currency.clone(),
+                ..Position::default()
+            };
+            (Some(symbol), position)
+        });
+        if fill.side == "buy" {
+            position.buy(size, price);
+        } else {
+            position.sell(size, price);
+        }
+        let fee_in_quote = if fill.fee_currency == symbol.quote_currency {
+            Some(fee)
+        } else {
+            rate(graph, &fill.fee_currency, &symbol.quote_currency, now).map(|r| fee * r)
+        };
+        position.realized -= fee_in_quote.unwrap_or_default();
+    }
+
+    Ok(positions
+        .into_iter()
+        .map(|(name, (symbol, position))| {
+            let mark = symbol.and_then(|s| rate(graph, &s.base_currency, &s.quote_currency, now));
+            let unrealized =
+                mark.map(|m| ((m - position.average_cost) * position.size).normalize());
+            let to_usdt = rate(graph, &position.quote_currency, VALUATION_CURRENCY, now);
+            SymbolPnl {
+                symbol: name.to_string(),
+                quote_currency: position.quote_currency.clone(),
+                position: position.size.normalize(),
+                average_cost: position.average_cost.normalize(),
+                realized_pnl: position.realized.normalize(),
+                unrealized_pnl: unrealized,
+                realized_pnl_usdt: to_usdt.map(|r| (position.realized * r).normalize()),
+                unrealized_pnl_usdt: unrealized.zip(to_usdt).map(|(u, r)| (u * r).normalize()),
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::fiat_price::FiatPrice;
+    use crate::domain::entities::fill::Fill;
+    use crate::domain::entities::ticker::Ticker;
+
+    fn d(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn symbol(base: &str, quote: &str) -> Symbol {
+        Symbol::new(
+            format!("{}-{}", base, quote),
+            format!("{}-{}", base, quote),
+            base.to_string(),
+            quote.to_string(),
+            quote.to_string(),
+            "USDS".to_string(),
+            d("0.001"),
+            d("0.1"),
+            d("100"),
+            d("1000000"),
+            d("0.0001"),
+            d("0.01"),
+            d("0.1"),
+            d("0.1"),
+            None,
+            false,
+            true,
+            1,
+            d("1"),
+            d("1"),
+            false,
+        )
+    }
+
+    fn ticker(symbol: &str, price: &str) -> Ticker {
+        Ticker::new(
+            symbol.to_string(),
+            symbol.to_string(),
+            d("0.001"),
+            d("0.001"),
+            d("1"),
+            d("1"),
+            Some(d(price)),
+            Some(d(price)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Utc::now(),
+        )
+    }
+
+    fn graph() -> RateGraph {
+        RateGraph::new(
+            &[symbol("BTC", "USDT")],
+            &[ticker("BTC-USDT", "40000")],
+            &[FiatPrice::new(
+                "USDT".to_string(),
+                "USD".to_string(),
+                "0.999".to_string(),
+                Utc::now(),
+            )],
+        )
+    }
+
+    fn balance(account_id: &str, currency: &str, amount: &str) -> AccountBalance {
+        AccountBalance::new(
+            account_id.to_string(),
+            currency.to_string(),
+            "trade".to_string(),
+            amount.to_string(),
+            amount.to_string(),
+            "0".to_string(),
+        )
+    }
+
+    fn fill(side: &str, price: &str, size: &str, fee: &str, fee_currency: &str) -> FillWithSymbol {
+        FillWithSymbol {
+            fill: Fill::new(
+                format!("{}-{}-{}", side, price, size),
+                "BTC-USDT".to_string(),
+                "order".to_string(),
+                "counter".to_string(),
+                side.to_string(),
+                "taker".to_string(),
+                false,
+                price.to_string(),
+                size.to_string(),
+                "0".to_string(),
+                fee.to_string(),
+                "0.001".to_string(),
+                fee_currency.to_string(),
+                "TRADE".to_string(),
+                "limit".to_string(),
+                Utc::now(),
+            ),
+            symbol: Some(symbol("BTC", "USDT")),
+        }
+    }
+
+    #[test]
+    fn totals_sum_only_priced_balances() {
+        let balances = [
+            balance("a", "BTC", "0.5"),
+            balance("a", "XYZ", "100"),
+            balance("a", "USDT", "0"),
+            balance("b", "USDT", "0"),
+        ];
+        let valuations = value_balances(&balances, &graph(), Utc::now()).unwrap();
+
+        assert_eq!(valuations.len(), 2);
+        let a = &valuations[0];
+        assert_eq!(a.value_usdt, d("20000"));
+        assert_eq!(a.value_usd, d("19980"));
+        assert_eq!(a.assets.len(), 2);
+        assert!(a.assets[1].value_usdt.is_none());
+        assert!(a.assets[1].value_usd.is_none());
+
+        let b = &valuations[1];
+        assert_eq!(b.value_usdt, Decimal::ZERO);
+        assert!(b.assets.is_empty());
+    }
+
+    #[test]
+    fn pnl_uses_average_cost_and_converts_fees() {
+        let fills = [
+            fill("buy", "30000", "1", "0.001", "BTC"),
+            fill("buy", "40000", "1", "10", "USDT"),
+            fill("sell", "45000", "1", "5", "USDT"),
+        ];
+        let pnl = pnl_from_fills(&[], &fills, &[], &graph(), Utc::now()).unwrap();
+
+        assert_eq!(pnl.len(), 1);
+        assert_eq!(pnl[0].position, d("1"));
+        assert_eq!(pnl[0].average_cost, d("35000"));
+        // 10000 gained on the sale less 40 + 10 + 5 USDT of fees.
+        assert_eq!(pnl[0].realized_pnl, d("9945"));
+        assert_eq!(pnl[0].unrealized_pnl, Some(d("5000")));
+        assert_eq!(pnl[0].realized_pnl_usdt, Some(d("9945")));
+    }
+
+    #[test]
+    fn selling_more_than_bought_only_realizes_the_tracked_part() {
+        let fills = [
+            fill("buy", "30000", "1", "0", "USDT"),
+            fill("sell", "40000", "3", "0", "USDT"),
+        ];
+        let pnl = pnl_from_fills(&[], &fills, &[], &graph(), Utc::now()).unwrap();
+
+        assert_eq!(pnl[0].position, Decimal::ZERO);
+        assert_eq!(pnl[0].average_cost, Decimal::ZERO);
+        assert_eq!(pnl[0].realized_pnl, d("10000"));
+    }
+
+    #[test]
+    fn pnl_carries_previous_positions_forward() {
+        let previous = |name: &str| SymbolPnl {
+            symbol: name.to_string(),
+            quote_currency: "USDT".to_string(),
+            position: d("1"),
+            average_cost: d("30000"),
+            realized_pnl: d("100"),
+            unrealized_pnl: None,
+            realized_pnl_usdt: None,
+            unrealized_pnl_usdt: None,
+        };
+        let fills = [fill("sell", "40000", "0.5", "0", "USDT")];
+        let pnl = pnl_from_fills(
+            &[previous("BTC-USDT"), previous("OLD-USDT")],
+            &fills,
+            &[symbol("BTC", "USDT")],
+            &graph(),
+            Utc::now(),
+        )
+        .unwrap();
+
+        assert_eq!(pnl[0].symbol, "BTC-USDT");
+        assert_eq!(pnl[0].position, d("0.5"));
+        assert_eq!(pnl[0].realized_pnl, d("5100"));
+        assert_eq!(pnl[0].unrealized_pnl, Some(d("5000")));
+
+        // Unknown symbols keep their position but cannot be marked.
+        assert_eq!(pnl[1].symbol, "OLD-USDT");
+        assert_eq!(pnl[1].realized_pnl, d("100"));
+        assert!(pnl[1].unrealized_pnl.is_none());
+    }
+}
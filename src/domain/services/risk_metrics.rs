@@ -0,0 +1,60 @@
+use crate::domain::entities::candle::Candle;
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::BTreeMap;
+
+const SECONDS_PER_YEAR: f64 = 365.0 * 86_400.0;
+
+/// Close-to-close log returns keyed by the open time of the later candle.
+/// Gaps in the series are skipped rather than bridged.
+pub fn log_returns(candles: &[Candle], interval_secs: i64) -> BTreeMap<DateTime<Utc>, f64> {
+    candles
+        .windows(2)
+        .filter(|pair| (pair[1].open_time - pair[0].open_time).num_seconds() == interval_secs)
+        .filter_map(|pair| {
+            let prev = pair[0].close.to_f64()?;
+            let next = pair[1].close.to_f64()?;
+            (prev > 0.0 && next > 0.0).then(|| (pair[1].open_time, (next / prev).ln()))
+        })
+        .collect()
+}
+
+/// Annualized sample standard deviation of log returns; markets trade around
+/// the clock so a year is 365 days of intervals.
+pub fn realized_volatility(returns: &[f64], interval_secs: i64) -> Option<f64> {
+    if returns.len() < 2 || interval_secs <= 0 {
+        return None;
+    }
+    let n = returns.len() as f64;
+    let mean = returns.iter().sum::<f64>() / n;
+    let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    Some((variance * SECONDS_PER_YEAR / interval_secs as f64).sqrt())
+}
+
+/// Pearson correlation over the timestamps both series share.
+pub fn correlation(
+    a: &BTreeMap<DateTime<Utc>, f64>,
+    b: &BTreeMap<DateTime<Utc>, f64>,
+) -> Option<(f64, usize)> {
+    let pairs: Vec<(f64, f64)> = a
+        .iter()
+        .filter_map(|(time, x)| b.get(time).map(|y| (*x, *y)))
+        .collect();
+    if pairs.len() < 2 {
+        return None;
+    }
+
+    let n = pairs.len() as f64;
+    let mean_x = pairs.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = pairs.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (x, y) in &pairs {
+        cov += (x - mean_x) * (y - mean_y);
+        var_x += (x - mean_x).powi(2);
+        var_y += (y - mean_y).powi(2);
+    }
+    if var_x == 0.0 || var_y == 0.0 {
+        return None;
+    }
+    Some((cov / (var_x * var_y).sqrt(), pairs.len()))
+}
@@ -0,0 +1,125 @@
+use crate::domain::entities::arbitrage::{ArbitrageLeg, ArbitrageOpportunity, SIDE_BUY, SIDE_SELL};
+use crate::domain::entities::{symbol::Symbol, ticker::Ticker};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+
+struct Conversion<'a> {
+    to: &'a str,
+    symbol: &'a Symbol,
+    ticker: &'a Ticker,
+    side: &'static str,
+    price: Decimal,
+    fee_rate: Decimal,
+}
+
+impl Conversion<'_> {
+    fn gross(&self) -> Decimal {
+        if self.side == SIDE_SELL {
+            self.price
+        } else {
+            Decimal::ONE / self.price
+        }
+    }
+
+    fn leg(&self, from: &str) -> ArbitrageLeg {
+        ArbitrageLeg::new(
+            self.symbol.symbol.clone(),
+            self.side.to_string(),
+            from.to_string(),
+            self.to.to_string(),
+            self.price,
+            self.fee_rate,
+            self.ticker.time_point,
+        )
+    }
+}
+
+/// Walks every A -> B -> C -> A cycle through tradable symbols, selling into
+/// the best bid or buying from the best ask on each leg and paying the taker
+/// fee (`takerFeeRate * takerFeeCoefficient`) on what is received. Each cycle
+/// is reported once per direction, starting from its smallest currency code.
+pub fn find_triangular_opportunities(
+    symbols: &[Symbol],
+    tickers: &[Ticker],
+    min_net_edge: Decimal,
+) -> Vec<ArbitrageOpportunity> {
+    let tickers: HashMap<&str, &Ticker> = tickers.iter().map(|t| (t.symbol.as_str(), t)).collect();
+    let mut graph: HashMap<&str, Vec<Conversion>> = HashMap::new();
+
+    for symbol in symbols.iter().filter(|s| s.enable_trading) {
+        let Some(ticker) = tickers.get(symbol.symbol.as_str()) else {
+            continue;
+        };
+        let fee_rate = ticker.taker_fee_rate * symbol.taker_fee_coefficient;
+        if let Some(bid) = positive_price(ticker.buy) {
+            graph
+                .entry(symbol.base_currency.as_str())
+                .or_default()
+                .push(Conversion {
+                    to: &symbol.quote_currency,
+                    symbol,
+                    ticker,
+                    side: SIDE_SELL,
+                    price: bid,
+                    fee_rate,
+                });
+        }
+        if let Some(ask) = positive_price(ticker.sell) {
+            graph
+                .entry(symbol.quote_currency.as_str())
+                .or_default()
+                .push(Conversion {
+                    to: &symbol.base_currency,
+                    symbol,
+                    ticker,
+                    side: SIDE_BUY,
+                    price: ask,
+                    fee_rate,
+                });
+        }
+    }
+
+    let mut opportunities = Vec::new();
+    for (&a, first_legs) in &graph {
+        for first in first_legs.iter().filter(|c| c.to > a) {
+            let b = first.to;
+            for second in graph.get(b).into_iter().flatten() {
+                let c = second.to;
+                if c <= a || c == b {
+                    continue;
+                }
+                for third in graph.get(c).into_iter().flatten().filter(|t| t.to == a) {
+                    let legs = [first, second, third];
+                    let gross = legs.iter().fold(Decimal::ONE, |acc, l| acc * l.gross());
+                    let net = legs.iter().fold(Decimal::ONE, |acc, l| {
+                        acc * l.gross() * (Decimal::ONE - l.fee_rate)
+                    });
+                    let net_edge = net - Decimal::ONE;
+                    if net_edge < min_net_edge {
+                        continue;
+                    }
+
+                    let observed_at = legs
+                        .iter()
+                        .map(|l| l.ticker.time_point)
+                        .max()
+                        .unwrap_or_default();
+                    opportunities.push(ArbitrageOpportunity::new(
+                        format!("{}>{}>{}>{}", a, b, c, a),
+                        vec![first.leg(a), second.leg(b), third.leg(c)],
+                        (gross - Decimal::ONE).normalize(),
+                        net_edge.normalize(),
+                        observed_at,
+                    ));
+                }
+            }
+        }
+    }
+
+    opportunities.sort_by_key(|o| std::cmp::Reverse(o.net_edge));
+    opportunities
+}
+
+fn positive_price(value: Option<Decimal>) -> Option<Decimal> {
+    value.filter(|p| *p > Decimal::ZERO)
+}
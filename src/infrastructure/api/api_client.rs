@@ -1,12 +1,109 @@
-use crate::domain::entities::{currency::Currency, symbol::Symbol, ticker::Ticker};
+use crate::domain::entities::{
+    account_balance::AccountBalance,
+    announcement::Announcement,
+    candle::Candle,
+    currency::Currency,
+    data_quality::DataQualityIssue,
+    deposit::Deposit,
+    exchange_status::ExchangeStatus,
+    fiat_price::FiatPrice,
+    fill::Fill,
+    ledger_entry::LedgerEntry,
+    lending::{LendingInterestRate, LendingMarket},
+    margin::{CrossMarginSymbol, IsolatedMarginSymbol, MarginConfig},
+    order::Order,
+    order_book::OrderBookSnapshot,
+    sub_account::{SubAccount, SubAccountBalance},
+    symbol::Symbol,
+    ticker::Ticker,
+    trade::Trade,
+    withdrawal::Withdrawal,
+};
+use crate::infrastructure::ws::kucoin_ws::WsToken;
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 #[async_trait]
 pub trait ApiClient: Send + Sync {
     async fn fetch_currencies(&self) -> Result<Vec<Currency>>;
 
-    async fn fetch_symbols(&self) -> Result<Vec<Symbol>>;
+    async fn fetch_symbols(&self) -> Result<(Vec<Symbol>, Vec<DataQualityIssue>)>;
 
-    async fn fetch_tickers(&self) -> Result<Vec<Ticker>>;
+    async fn fetch_markets(&self) -> Result<Vec<String>>;
+
+    async fn fetch_tickers(&self) -> Result<(Vec<Ticker>, Vec<DataQualityIssue>)>;
+
+    async fn fetch_order_book(&self, symbol: &str) -> Result<OrderBookSnapshot>;
+
+    async fn fetch_partial_order_book(&self, symbol: &str) -> Result<OrderBookSnapshot>;
+
+    async fn fetch_trades(&self, symbol: &str) -> Result<Vec<Trade>>;
+
+    async fn fetch_candles(
+        &self,
+        symbol: &str,
+        interval: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Candle>>;
+
+    async fn fetch_deposits(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Deposit>>;
+
+    async fn fetch_withdrawals(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Withdrawal>>;
+
+    async fn fetch_fills(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<Fill>>;
+
+    async fn fetch_orders(
+        &self,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Order>>;
+
+    async fn fetch_ledger_entries(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<LedgerEntry>>;
+
+    async fn fetch_account_balances(&self) -> Result<Vec<AccountBalance>>;
+
+    async fn fetch_sub_accounts(&self) -> Result<Vec<SubAccount>>;
+
+    async fn fetch_sub_account_balances(&self) -> Result<Vec<SubAccountBalance>>;
+
+    async fn fetch_cross_margin_symbols(&self) -> Result<Vec<CrossMarginSymbol>>;
+
+    async fn fetch_isolated_margin_symbols(&self) -> Result<Vec<IsolatedMarginSymbol>>;
+
+    async fn fetch_margin_config(&self) -> Result<MarginConfig>;
+
+    async fn fetch_lending_markets(&self) -> Result<Vec<LendingMarket>>;
+
+    async fn fetch_lending_interest_rates(
+        &self,
+        currency: &str,
+    ) -> Result<Vec<LendingInterestRate>>;
+
+    async fn fetch_fiat_prices(&self, base: &str) -> Result<Vec<FiatPrice>>;
+
+    async fn fetch_service_status(&self) -> Result<ExchangeStatus>;
+
+    async fn fetch_announcements(
+        &self,
+        ann_type: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Announcement>>;
+
+    async fn fetch_ws_token(&self, private: bool) -> Result<WsToken>;
 }
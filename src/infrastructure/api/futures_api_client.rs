@@ -0,0 +1,25 @@
+use crate::domain::entities::exchange_status::ExchangeStatus;
+use crate::domain::entities::futures::{FundingRate, FuturesContract, MarkPrice, OpenInterest};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait FuturesApiClient: Send + Sync {
+    async fn fetch_contracts(&self) -> Result<Vec<FuturesContract>>;
+
+    async fn fetch_current_funding_rate(&self, symbol: &str) -> Result<FundingRate>;
+
+    async fn fetch_funding_rate_history(
+        &self,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<FundingRate>>;
+
+    async fn fetch_mark_price(&self, symbol: &str) -> Result<MarkPrice>;
+
+    async fn fetch_open_interest(&self) -> Result<Vec<OpenInterest>>;
+
+    async fn fetch_service_status(&self) -> Result<ExchangeStatus>;
+}
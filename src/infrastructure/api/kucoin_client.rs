@@ -1,16 +1,56 @@
-use crate::domain::entities::{currency::Currency, symbol::Symbol, ticker::Ticker};
+use crate::domain::entities::{
+    account_balance::AccountBalance,
+    announcement::Announcement,
+    candle::Candle,
+    currency::Currency,
+    data_quality::DataQualityIssue,
+    deposit::Deposit,
+    exchange_status::ExchangeStatus,
+    fiat_price::FiatPrice,
+    fill::Fill,
+    ledger_entry::LedgerEntry,
+    lending::{LendingInterestRate, LendingMarket},
+    margin::{CrossMarginSymbol, IsolatedMarginSymbol, MarginConfig},
+    order::Order,
+    order_book::{OrderBookSnapshot, PriceLevel},
+    sub_account::{SubAccount, SubAccountBalance},
+    symbol::Symbol,
+    ticker::Ticker,
+    trade::Trade,
+    withdrawal::Withdrawal,
+};
 use crate::infrastructure::api::api_client::ApiClient;
 use crate::infrastructure::config::Config;
+use crate::infrastructure::ws::kucoin_ws::WsToken;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use base64::Engine;
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
 use hmac::{Hmac, KeyInit, Mac};
 use reqwest::{Client, Method};
+use rust_decimal::Decimal;
 use sha2::Sha256;
+use std::collections::HashMap;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 type HmacSha256 = Hmac<Sha256>;
 
+const MAX_QUERY_SPAN: TimeDelta = TimeDelta::days(7);
+const LEDGER_QUERY_SPAN: TimeDelta = TimeDelta::days(1);
+const PAGE_SIZE: u32 = 500;
+const ANNOUNCEMENT_PAGE_SIZE: u32 = 50;
+const HF_PAGE_LIMIT: usize = 100;
+const HF_LEDGER_PAGE_LIMIT: usize = 200;
+
+#[derive(Debug, serde::Deserialize)]
+struct PaginatedData<T> {
+    #[serde(rename = "currentPage")]
+    pub current_page: u32,
+    #[serde(rename = "totalPage")]
+    pub total_page: u32,
+    pub items: Vec<T>,
+}
+
 #[derive(Debug, serde::Deserialize)]
 struct ApiV1MarketAllTickers {
     pub code: String,
@@ -20,6 +60,7 @@ struct ApiV1MarketAllTickers {
 
 #[derive(Debug, serde::Deserialize)]
 struct TickerData {
+    pub time: i64,
     pub ticker: Vec<TickerApi>,
 }
 
@@ -36,6 +77,65 @@ struct TickerApi {
     pub taker_coefficient: String,
     #[serde(rename = "makerCoefficient")]
     pub maker_coefficient: String,
+    pub buy: Option<String>,
+    pub sell: Option<String>,
+    #[serde(rename = "changeRate")]
+    pub change_rate: Option<String>,
+    #[serde(rename = "changePrice")]
+    pub change_price: Option<String>,
+    pub high: Option<String>,
+    pub low: Option<String>,
+    pub vol: Option<String>,
+    #[serde(rename = "volValue")]
+    pub vol_value: Option<String>,
+    pub last: Option<String>,
+    #[serde(rename = "averagePrice")]
+    pub average_price: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1Candles {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<Vec<[String; 7]>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1TradeHistories {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<Vec<TradeHistoryApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TradeHistoryApi {
+    pub sequence: String,
+    pub price: String,
+    pub size: String,
+    pub side: String,
+    pub time: i64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV3OrderBookLevel2 {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<OrderBookApi>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct OrderBookApi {
+    pub sequence: String,
+    pub time: i64,
+    pub bids: Vec<[String; 2]>,
+    pub asks: Vec<[String; 2]>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1Markets {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<Vec<String>>,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -107,6 +207,454 @@ struct SymbolApi {
     pub st: bool,
 }
 
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1Deposits {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<PaginatedData<DepositApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct DepositApi {
+    pub currency: String,
+    pub chain: Option<String>,
+    pub status: String,
+    pub address: Option<String>,
+    pub memo: Option<String>,
+    #[serde(rename = "isInner")]
+    pub is_inner: bool,
+    pub amount: String,
+    pub fee: String,
+    #[serde(rename = "walletTxId")]
+    pub wallet_tx_id: Option<String>,
+    pub remark: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: i64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1Withdrawals {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<PaginatedData<WithdrawalApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct WithdrawalApi {
+    pub id: String,
+    pub currency: String,
+    pub chain: Option<String>,
+    pub status: String,
+    pub address: Option<String>,
+    pub memo: Option<String>,
+    #[serde(rename = "isInner")]
+    pub is_inner: bool,
+    pub amount: String,
+    pub fee: String,
+    #[serde(rename = "walletTxId")]
+    pub wallet_tx_id: Option<String>,
+    pub remark: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: i64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1Fills {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<PaginatedData<FillApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct FillApi {
+    pub symbol: String,
+    #[serde(rename = "tradeId")]
+    pub trade_id: String,
+    #[serde(rename = "orderId")]
+    pub order_id: String,
+    #[serde(rename = "counterOrderId")]
+    pub counter_order_id: Option<String>,
+    pub side: String,
+    pub liquidity: String,
+    #[serde(rename = "forceTaker")]
+    pub force_taker: bool,
+    pub price: String,
+    pub size: String,
+    pub funds: String,
+    pub fee: String,
+    #[serde(rename = "feeRate")]
+    pub fee_rate: String,
+    #[serde(rename = "feeCurrency")]
+    pub fee_currency: String,
+    #[serde(rename = "tradeType")]
+    pub trade_type: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1HfOrdersDone {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<HfOrdersDoneData>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct HfOrdersDoneData {
+    #[serde(rename = "lastId")]
+    pub last_id: i64,
+    pub items: Vec<HfOrderApi>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1HfOrdersActive {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<Vec<HfOrderApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct HfOrderApi {
+    pub id: String,
+    #[serde(rename = "clientOid")]
+    pub client_oid: Option<String>,
+    pub symbol: String,
+    #[serde(rename = "opType")]
+    pub op_type: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub side: String,
+    pub price: String,
+    pub size: String,
+    pub funds: String,
+    #[serde(rename = "dealSize")]
+    pub deal_size: String,
+    #[serde(rename = "dealFunds")]
+    pub deal_funds: String,
+    pub fee: String,
+    #[serde(rename = "feeCurrency")]
+    pub fee_currency: String,
+    #[serde(rename = "timeInForce")]
+    pub time_in_force: String,
+    #[serde(rename = "postOnly")]
+    pub post_only: bool,
+    pub hidden: bool,
+    pub iceberg: bool,
+    #[serde(rename = "cancelExist")]
+    pub cancel_exist: bool,
+    pub active: bool,
+    #[serde(rename = "inOrderBook")]
+    pub in_order_book: bool,
+    #[serde(rename = "tradeType")]
+    pub trade_type: String,
+    pub remark: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+    #[serde(rename = "lastUpdatedAt")]
+    pub last_updated_at: i64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1AccountLedgers {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<PaginatedData<LedgerApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1HfAccountLedgers {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<Vec<LedgerApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct LedgerApi {
+    pub id: String,
+    pub currency: String,
+    pub amount: String,
+    pub fee: String,
+    pub balance: String,
+    #[serde(rename = "accountType")]
+    pub account_type: String,
+    #[serde(rename = "bizType")]
+    pub biz_type: String,
+    pub direction: String,
+    pub context: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1Accounts {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<Vec<AccountApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct AccountApi {
+    pub id: String,
+    pub currency: String,
+    #[serde(rename = "type")]
+    pub account_type: String,
+    pub balance: String,
+    pub available: String,
+    pub holds: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV2SubUser {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<PaginatedData<SubUserApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SubUserApi {
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    pub uid: i64,
+    #[serde(rename = "subName")]
+    pub sub_name: String,
+    pub status: i32,
+    #[serde(rename = "type")]
+    pub sub_type: i32,
+    pub access: String,
+    pub remarks: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1SubAccounts {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<Vec<SubAccountBalancesApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SubAccountBalancesApi {
+    #[serde(rename = "subUserId")]
+    pub sub_user_id: String,
+    #[serde(rename = "subName")]
+    pub sub_name: String,
+    #[serde(rename = "mainAccounts", default)]
+    pub main_accounts: Vec<SubAccountBalanceApi>,
+    #[serde(rename = "tradeAccounts", default)]
+    pub trade_accounts: Vec<SubAccountBalanceApi>,
+    #[serde(rename = "marginAccounts", default)]
+    pub margin_accounts: Vec<SubAccountBalanceApi>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SubAccountBalanceApi {
+    pub currency: String,
+    pub balance: String,
+    pub available: String,
+    pub holds: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV3MarginSymbols {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<MarginSymbolsData>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct MarginSymbolsData {
+    pub items: Vec<CrossMarginSymbolApi>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct CrossMarginSymbolApi {
+    pub symbol: String,
+    pub name: String,
+    #[serde(rename = "baseCurrency")]
+    pub base_currency: String,
+    #[serde(rename = "quoteCurrency")]
+    pub quote_currency: String,
+    pub market: String,
+    #[serde(rename = "enableTrading")]
+    pub enable_trading: bool,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1IsolatedSymbols {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<Vec<IsolatedMarginSymbolApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct IsolatedMarginSymbolApi {
+    pub symbol: String,
+    #[serde(rename = "symbolName")]
+    pub symbol_name: String,
+    #[serde(rename = "baseCurrency")]
+    pub base_currency: String,
+    #[serde(rename = "quoteCurrency")]
+    pub quote_currency: String,
+    #[serde(rename = "maxLeverage")]
+    pub max_leverage: i16,
+    #[serde(rename = "flDebtRatio")]
+    pub fl_debt_ratio: String,
+    #[serde(rename = "autoRenewMaxDebtRatio")]
+    pub auto_renew_max_debt_ratio: String,
+    #[serde(rename = "tradeEnable")]
+    pub trade_enable: bool,
+    #[serde(rename = "baseBorrowEnable")]
+    pub base_borrow_enable: bool,
+    #[serde(rename = "quoteBorrowEnable")]
+    pub quote_borrow_enable: bool,
+    #[serde(rename = "baseTransferInEnable")]
+    pub base_transfer_in_enable: bool,
+    #[serde(rename = "quoteTransferInEnable")]
+    pub quote_transfer_in_enable: bool,
+    #[serde(rename = "baseBorrowCoefficient")]
+    pub base_borrow_coefficient: String,
+    #[serde(rename = "quoteBorrowCoefficient")]
+    pub quote_borrow_coefficient: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1MarginConfig {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<MarginConfigApi>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct MarginConfigApi {
+    #[serde(rename = "currencyList")]
+    pub currency_list: Vec<String>,
+    #[serde(rename = "maxLeverage")]
+    pub max_leverage: i16,
+    #[serde(rename = "warningDebtRatio")]
+    pub warning_debt_ratio: String,
+    #[serde(rename = "liqDebtRatio")]
+    pub liq_debt_ratio: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV3ProjectList {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<Vec<LendingMarketApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct LendingMarketApi {
+    pub currency: String,
+    #[serde(rename = "purchaseEnable")]
+    pub purchase_enable: bool,
+    #[serde(rename = "redeemEnable")]
+    pub redeem_enable: bool,
+    #[serde(rename = "autoPurchaseEnable")]
+    pub auto_purchase_enable: bool,
+    pub increment: String,
+    #[serde(rename = "minPurchaseSize")]
+    pub min_purchase_size: String,
+    #[serde(rename = "maxPurchaseSize")]
+    pub max_purchase_size: String,
+    #[serde(rename = "minInterestRate")]
+    pub min_interest_rate: String,
+    #[serde(rename = "maxInterestRate")]
+    pub max_interest_rate: String,
+    #[serde(rename = "interestIncrement")]
+    pub interest_increment: String,
+    #[serde(rename = "marketInterestRate")]
+    pub market_interest_rate: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV3MarketInterestRate {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<Vec<MarketInterestRateApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct MarketInterestRateApi {
+    pub time: String,
+    #[serde(rename = "marketInterestRate")]
+    pub market_interest_rate: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1Prices {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct ApiV1Status {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<ServiceStatusApi>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct ServiceStatusApi {
+    pub status: String,
+    pub msg: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV3Announcements {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<PaginatedData<AnnouncementApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct AnnouncementApi {
+    #[serde(rename = "annId")]
+    pub ann_id: i64,
+    #[serde(rename = "annTitle")]
+    pub ann_title: String,
+    #[serde(rename = "annType")]
+    pub ann_type: Vec<String>,
+    #[serde(rename = "annDesc")]
+    pub ann_desc: Option<String>,
+    #[serde(rename = "cTime")]
+    pub c_time: i64,
+    pub language: String,
+    #[serde(rename = "annUrl")]
+    pub ann_url: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1Bullet {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<BulletApi>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct BulletApi {
+    pub token: String,
+    #[serde(rename = "instanceServers")]
+    pub instance_servers: Vec<InstanceServerApi>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct InstanceServerApi {
+    pub endpoint: String,
+    #[serde(rename = "pingInterval")]
+    pub ping_interval: u64,
+    #[serde(rename = "pingTimeout")]
+    pub ping_timeout: u64,
+}
+
 pub struct KuCoinClient {
     client: Client,
     api_key: String,
@@ -117,6 +665,10 @@ pub struct KuCoinClient {
 
 impl KuCoinClient {
     pub fn new(config: &Config) -> Result<Self> {
+        Self::with_base_url(config, &config.kucoin_base_url)
+    }
+
+    pub fn with_base_url(config: &Config, base_url: &str) -> Result<Self> {
         let client = Client::builder()
             .timeout(Duration::from_secs(15))
             .connect_timeout(Duration::from_secs(5))
@@ -129,7 +681,7 @@ impl KuCoinClient {
             api_key: config.kucoin_key.clone(),
             api_secret: config.kucoin_secret.clone(),
             api_passphrase: config.kucoin_passphrase.clone(),
-            base_url: config.kucoin_base_url.clone(),
+            base_url: base_url.to_string(),
         })
     }
 
@@ -147,7 +699,7 @@ impl KuCoinClient {
         Ok(base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes()))
     }
 
-    async fn make_request(
+    pub(crate) async fn make_request(
         &self,
         method: Method,
         endpoint: &str,
@@ -174,7 +726,7 @@ impl KuCoinClient {
 
             if !query_string.is_empty() {
                 str_to_sign.push('?');
-                str_to_sign.push_str(&query_string);
+                str_to_sign.push_str(query_string);
             }
             if !body_str.is_empty() {
                 str_to_sign.push_str(body_str);
@@ -232,7 +784,7 @@ impl KuCoinClient {
         }
     }
 
-    async fn get_tickers(&self) -> Result<Vec<TickerApi>> {
+    async fn get_tickers(&self) -> Result<TickerData> {
         let body = self
             .make_request(Method::GET, "/api/v1/market/allTickers", "", "", false)
             .await?;
@@ -241,7 +793,9 @@ impl KuCoinClient {
             .context("Failed to deserialize tickers response")?;
 
         if response_data.code == "200000" {
-            Ok(response_data.data.map(|d| d.ticker).unwrap_or_default())
+            response_data
+                .data
+                .context("Tickers response contains no data")
         } else {
             anyhow::bail!(
                 "KuCoin API error: code={}, msg={:?}",
@@ -251,16 +805,23 @@ impl KuCoinClient {
         }
     }
 
-    async fn get_symbols(&self) -> Result<Vec<SymbolApi>> {
+    async fn get_bullet(&self, private: bool) -> Result<BulletApi> {
+        let endpoint = if private {
+            "/api/v1/bullet-private"
+        } else {
+            "/api/v1/bullet-public"
+        };
         let body = self
-            .make_request(Method::GET, "/api/v2/symbols", "", "", false)
+            .make_request(Method::POST, endpoint, "", "", private)
             .await?;
 
-        let response_data = serde_json::from_str::<ApiV2Symbols>(&body)
-            .context("Failed to deserialize symbols response")?;
+        let response_data = serde_json::from_str::<ApiV1Bullet>(&body)
+            .context("Failed to deserialize WebSocket token response")?;
 
         if response_data.code == "200000" {
-            Ok(response_data.data.unwrap_or_default())
+            response_data
+                .data
+                .context("WebSocket token response contains no data")
         } else {
             anyhow::bail!(
                 "KuCoin API error: code={}, msg={:?}",
@@ -269,82 +830,1382 @@ impl KuCoinClient {
             )
         }
     }
-}
 
-#[async_trait]
-impl ApiClient for KuCoinClient {
-    async fn fetch_currencies(&self) -> Result<Vec<Currency>> {
-        let currencies_api = self.get_currencies().await?;
+    async fn get_candles(
+        &self,
+        symbol: &str,
+        interval: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<[String; 7]>> {
+        let query_string = format!(
+            "type={}&symbol={}&startAt={}&endAt={}",
+            urlencoding::encode(interval),
+            urlencoding::encode(symbol),
+            start.timestamp(),
+            end.timestamp()
+        );
+        let body = self
+            .make_request(
+                Method::GET,
+                "/api/v1/market/candles",
+                &query_string,
+                "",
+                false,
+            )
+            .await?;
 
-        let currencies: Vec<Currency> = currencies_api
-            .into_iter()
-            .map(|c| {
-                Currency::new(
-                    c.currency,
-                    c.name,
-                    c.full_name,
-                    c.precision,
-                    c.is_margin_enabled,
-                    c.is_debit_enabled,
-                )
-            })
-            .collect();
+        let response_data = serde_json::from_str::<ApiV1Candles>(&body)
+            .context("Failed to deserialize candles response")?;
 
-        Ok(currencies)
+        if response_data.code == "200000" {
+            Ok(response_data.data.unwrap_or_default())
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
     }
 
-    async fn fetch_symbols(&self) -> Result<Vec<Symbol>> {
-        let symbols_api = self.get_symbols().await?;
+    async fn get_order_book(&self, symbol: &str) -> Result<OrderBookApi> {
+        let query_string = format!("symbol={}", urlencoding::encode(symbol));
+        let body = self
+            .make_request(
+                Method::GET,
+                "/api/v3/market/orderbook/level2",
+                &query_string,
+                "",
+                true,
+            )
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV3OrderBookLevel2>(&body)
+            .context("Failed to deserialize order book response")?;
+
+        if response_data.code == "200000" {
+            response_data
+                .data
+                .with_context(|| format!("No order book for {}", symbol))
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_partial_order_book(&self, symbol: &str) -> Result<OrderBookApi> {
+        let query_string = format!("symbol={}", urlencoding::encode(symbol));
+        let body = self
+            .make_request(
+                Method::GET,
+                "/api/v1/market/orderbook/level2_20",
+                &query_string,
+                "",
+                false,
+            )
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV3OrderBookLevel2>(&body)
+            .context("Failed to deserialize partial order book response")?;
+
+        if response_data.code == "200000" {
+            response_data
+                .data
+                .with_context(|| format!("No order book for {}", symbol))
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_trade_histories(&self, symbol: &str) -> Result<Vec<TradeHistoryApi>> {
+        let query_string = format!("symbol={}", urlencoding::encode(symbol));
+        let body = self
+            .make_request(
+                Method::GET,
+                "/api/v1/market/histories",
+                &query_string,
+                "",
+                false,
+            )
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV1TradeHistories>(&body)
+            .context("Failed to deserialize trade histories response")?;
+
+        if response_data.code == "200000" {
+            Ok(response_data.data.unwrap_or_default())
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_markets(&self) -> Result<Vec<String>> {
+        let body = self
+            .make_request(Method::GET, "/api/v1/markets", "", "", false)
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV1Markets>(&body)
+            .context("Failed to deserialize markets response")?;
+
+        if response_data.code == "200000" {
+            Ok(response_data.data.unwrap_or_default())
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_symbols(&self) -> Result<Vec<SymbolApi>> {
+        let body = self
+            .make_request(Method::GET, "/api/v2/symbols", "", "", false)
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV2Symbols>(&body)
+            .context("Failed to deserialize symbols response")?;
+
+        if response_data.code == "200000" {
+            Ok(response_data.data.unwrap_or_default())
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_deposits(&self, start_at: i64, end_at: i64) -> Result<Vec<DepositApi>> {
+        let mut deposits = Vec::new();
+        let mut current_page = 1;
+
+        loop {
+            let query_string = format!(
+                "startAt={}&endAt={}&currentPage={}&pageSize={}",
+                start_at, end_at, current_page, PAGE_SIZE
+            );
+            let body = self
+                .make_request(Method::GET, "/api/v1/deposits", &query_string, "", true)
+                .await?;
+
+            let response_data = serde_json::from_str::<ApiV1Deposits>(&body)
+                .context("Failed to deserialize deposits response")?;
+
+            if response_data.code != "200000" {
+                anyhow::bail!(
+                    "KuCoin API error: code={}, msg={:?}",
+                    response_data.code,
+                    response_data.msg
+                )
+            }
+
+            let Some(page) = response_data.data else {
+                break;
+            };
+            deposits.extend(page.items);
+
+            if page.current_page >= page.total_page {
+                break;
+            }
+            current_page += 1;
+        }
+
+        Ok(deposits)
+    }
+
+    async fn get_withdrawals(&self, start_at: i64, end_at: i64) -> Result<Vec<WithdrawalApi>> {
+        let mut withdrawals = Vec::new();
+        let mut current_page = 1;
+
+        loop {
+            let query_string = format!(
+                "startAt={}&endAt={}&currentPage={}&pageSize={}",
+                start_at, end_at, current_page, PAGE_SIZE
+            );
+            let body = self
+                .make_request(Method::GET, "/api/v1/withdrawals", &query_string, "", true)
+                .await?;
+
+            let response_data = serde_json::from_str::<ApiV1Withdrawals>(&body)
+                .context("Failed to deserialize withdrawals response")?;
+
+            if response_data.code != "200000" {
+                anyhow::bail!(
+                    "KuCoin API error: code={}, msg={:?}",
+                    response_data.code,
+                    response_data.msg
+                )
+            }
+
+            let Some(page) = response_data.data else {
+                break;
+            };
+            withdrawals.extend(page.items);
+
+            if page.current_page >= page.total_page {
+                break;
+            }
+            current_page += 1;
+        }
+
+        Ok(withdrawals)
+    }
+
+    async fn get_fills(&self, start_at: i64, end_at: i64) -> Result<Vec<FillApi>> {
+        let mut fills = Vec::new();
+        let mut current_page = 1;
+
+        loop {
+            let query_string = format!(
+                "startAt={}&endAt={}&currentPage={}&pageSize={}",
+                start_at, end_at, current_page, PAGE_SIZE
+            );
+            let body = self
+                .make_request(Method::GET, "/api/v1/fills", &query_string, "", true)
+                .await?;
+
+            let response_data = serde_json::from_str::<ApiV1Fills>(&body)
+                .context("Failed to deserialize fills response")?;
+
+            if response_data.code != "200000" {
+                anyhow::bail!(
+                    "KuCoin API error: code={}, msg={:?}",
+                    response_data.code,
+                    response_data.msg
+                )
+            }
+
+            let Some(page) = response_data.data else {
+                break;
+            };
+            fills.extend(page.items);
+
+            if page.current_page >= page.total_page {
+                break;
+            }
+            current_page += 1;
+        }
+
+        Ok(fills)
+    }
+
+    async fn get_hf_orders_done(
+        &self,
+        symbol: &str,
+        start_at: i64,
+        end_at: i64,
+    ) -> Result<Vec<HfOrderApi>> {
+        let mut orders = Vec::new();
+        let mut last_id: Option<i64> = None;
+
+        loop {
+            let mut query_string = format!(
+                "symbol={}&startAt={}&endAt={}&limit={}",
+                urlencoding::encode(symbol),
+                start_at,
+                end_at,
+                HF_PAGE_LIMIT
+            );
+            if let Some(last_id) = last_id {
+                query_string.push_str(&format!("&lastId={}", last_id));
+            }
+
+            let body = self
+                .make_request(
+                    Method::GET,
+                    "/api/v1/hf/orders/done",
+                    &query_string,
+                    "",
+                    true,
+                )
+                .await?;
+
+            let response_data = serde_json::from_str::<ApiV1HfOrdersDone>(&body)
+                .context("Failed to deserialize done orders response")?;
+
+            if response_data.code != "200000" {
+                anyhow::bail!(
+                    "KuCoin API error: code={}, msg={:?}",
+                    response_data.code,
+                    response_data.msg
+                )
+            }
+
+            let Some(page) = response_data.data else {
+                break;
+            };
+            let page_len = page.items.len();
+            orders.extend(page.items);
+
+            if page_len < HF_PAGE_LIMIT {
+                break;
+            }
+            last_id = Some(page.last_id);
+        }
+
+        Ok(orders)
+    }
+
+    async fn get_hf_orders_active(&self, symbol: &str) -> Result<Vec<HfOrderApi>> {
+        let query_string = format!("symbol={}", urlencoding::encode(symbol));
+        let body = self
+            .make_request(
+                Method::GET,
+                "/api/v1/hf/orders/active",
+                &query_string,
+                "",
+                true,
+            )
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV1HfOrdersActive>(&body)
+            .context("Failed to deserialize active orders response")?;
+
+        if response_data.code == "200000" {
+            Ok(response_data.data.unwrap_or_default())
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_account_ledgers(&self, start_at: i64, end_at: i64) -> Result<Vec<LedgerApi>> {
+        let mut entries = Vec::new();
+        let mut current_page = 1;
+
+        loop {
+            let query_string = format!(
+                "startAt={}&endAt={}&currentPage={}&pageSize={}",
+                start_at, end_at, current_page, PAGE_SIZE
+            );
+            let body = self
+                .make_request(
+                    Method::GET,
+                    "/api/v1/accounts/ledgers",
+                    &query_string,
+                    "",
+                    true,
+                )
+                .await?;
+
+            let response_data = serde_json::from_str::<ApiV1AccountLedgers>(&body)
+                .context("Failed to deserialize account ledgers response")?;
+
+            if response_data.code != "200000" {
+                anyhow::bail!(
+                    "KuCoin API error: code={}, msg={:?}",
+                    response_data.code,
+                    response_data.msg
+                )
+            }
+
+            let Some(page) = response_data.data else {
+                break;
+            };
+            entries.extend(page.items);
+
+            if page.current_page >= page.total_page {
+                break;
+            }
+            current_page += 1;
+        }
+
+        Ok(entries)
+    }
+
+    async fn get_hf_account_ledgers(&self, start_at: i64, end_at: i64) -> Result<Vec<LedgerApi>> {
+        let mut entries = Vec::new();
+        let mut last_id: Option<String> = None;
+
+        loop {
+            let mut query_string = format!(
+                "startAt={}&endAt={}&limit={}",
+                start_at, end_at, HF_LEDGER_PAGE_LIMIT
+            );
+            if let Some(last_id) = &last_id {
+                query_string.push_str(&format!("&lastId={}", last_id));
+            }
+
+            let body = self
+                .make_request(
+                    Method::GET,
+                    "/api/v1/hf/accounts/ledgers",
+                    &query_string,
+                    "",
+                    true,
+                )
+                .await?;
+
+            let response_data = serde_json::from_str::<ApiV1HfAccountLedgers>(&body)
+                .context("Failed to deserialize HF account ledgers response")?;
+
+            if response_data.code != "200000" {
+                anyhow::bail!(
+                    "KuCoin API error: code={}, msg={:?}",
+                    response_data.code,
+                    response_data.msg
+                )
+            }
+
+            let page = response_data.data.unwrap_or_default();
+            let page_len = page.len();
+            last_id = page.last().map(|entry| entry.id.clone());
+            entries.extend(page);
+
+            if page_len < HF_LEDGER_PAGE_LIMIT {
+                break;
+            }
+        }
+
+        Ok(entries)
+    }
+
+    async fn get_accounts(&self) -> Result<Vec<AccountApi>> {
+        let body = self
+            .make_request(Method::GET, "/api/v1/accounts", "", "", true)
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV1Accounts>(&body)
+            .context("Failed to deserialize accounts response")?;
+
+        if response_data.code == "200000" {
+            Ok(response_data.data.unwrap_or_default())
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_sub_users(&self) -> Result<Vec<SubUserApi>> {
+        let mut users = Vec::new();
+        let mut current_page = 1;
+
+        loop {
+            let query_string = format!("currentPage={}&pageSize=100", current_page);
+            let body = self
+                .make_request(Method::GET, "/api/v2/sub/user", &query_string, "", true)
+                .await?;
+
+            let response_data = serde_json::from_str::<ApiV2SubUser>(&body)
+                .context("Failed to deserialize sub-accounts response")?;
+
+            if response_data.code != "200000" {
+                anyhow::bail!(
+                    "KuCoin API error: code={}, msg={:?}",
+                    response_data.code,
+                    response_data.msg
+                )
+            }
+
+            let Some(page) = response_data.data else {
+                break;
+            };
+            users.extend(page.items);
+
+            if page.current_page >= page.total_page {
+                break;
+            }
+            current_page += 1;
+        }
+
+        Ok(users)
+    }
+
+    async fn get_sub_account_balances(&self) -> Result<Vec<SubAccountBalancesApi>> {
+        let body = self
+            .make_request(Method::GET, "/api/v1/sub-accounts", "", "", true)
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV1SubAccounts>(&body)
+            .context("Failed to deserialize sub-account balances response")?;
+
+        if response_data.code == "200000" {
+            Ok(response_data.data.unwrap_or_default())
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_cross_margin_symbols(&self) -> Result<Vec<CrossMarginSymbolApi>> {
+        let body = self
+            .make_request(Method::GET, "/api/v3/margin/symbols", "", "", false)
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV3MarginSymbols>(&body)
+            .context("Failed to deserialize cross margin symbols response")?;
+
+        if response_data.code == "200000" {
+            Ok(response_data.data.map(|d| d.items).unwrap_or_default())
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_isolated_margin_symbols(&self) -> Result<Vec<IsolatedMarginSymbolApi>> {
+        let body = self
+            .make_request(Method::GET, "/api/v1/isolated/symbols", "", "", true)
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV1IsolatedSymbols>(&body)
+            .context("Failed to deserialize isolated margin symbols response")?;
+
+        if response_data.code == "200000" {
+            Ok(response_data.data.unwrap_or_default())
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_margin_config(&self) -> Result<MarginConfigApi> {
+        let body = self
+            .make_request(Method::GET, "/api/v1/margin/config", "", "", false)
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV1MarginConfig>(&body)
+            .context("Failed to deserialize margin config response")?;
+
+        if response_data.code == "200000" {
+            response_data
+                .data
+                .context("Margin config response contains no data")
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_lending_markets(&self) -> Result<Vec<LendingMarketApi>> {
+        let body = self
+            .make_request(Method::GET, "/api/v3/project/list", "", "", false)
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV3ProjectList>(&body)
+            .context("Failed to deserialize lending markets response")?;
+
+        if response_data.code == "200000" {
+            Ok(response_data.data.unwrap_or_default())
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_market_interest_rates(
+        &self,
+        currency: &str,
+    ) -> Result<Vec<MarketInterestRateApi>> {
+        let query_string = format!("currency={}", urlencoding::encode(currency));
+        let body = self
+            .make_request(
+                Method::GET,
+                "/api/v3/project/marketInterestRate",
+                &query_string,
+                "",
+                false,
+            )
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV3MarketInterestRate>(&body)
+            .context("Failed to deserialize market interest rate response")?;
+
+        if response_data.code == "200000" {
+            Ok(response_data.data.unwrap_or_default())
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_fiat_prices(&self, base: &str) -> Result<HashMap<String, String>> {
+        let query_string = format!("base={}", urlencoding::encode(base));
+        let body = self
+            .make_request(Method::GET, "/api/v1/prices", &query_string, "", false)
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV1Prices>(&body)
+            .context("Failed to deserialize fiat prices response")?;
+
+        if response_data.code == "200000" {
+            Ok(response_data.data.unwrap_or_default())
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_service_status(&self) -> Result<ServiceStatusApi> {
+        let body = self
+            .make_request(Method::GET, "/api/v1/status", "", "", false)
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV1Status>(&body)
+            .context("Failed to deserialize service status response")?;
+
+        if response_data.code == "200000" {
+            response_data
+                .data
+                .context("Service status response contains no data")
+        } else {
+            anyhow::bail!(
+                "KuCoin API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_announcements(
+        &self,
+        ann_type: &str,
+        start_at: i64,
+        end_at: i64,
+    ) -> Result<Vec<AnnouncementApi>> {
+        let mut announcements = Vec::new();
+        let mut current_page = 1;
+
+        loop {
+            let query_string = format!(
+                "annType={}&lang=en_US&startTime={}&endTime={}&currentPage={}&pageSize={}",
+                urlencoding::encode(ann_type),
+                start_at,
+                end_at,
+                current_page,
+                ANNOUNCEMENT_PAGE_SIZE
+            );
+            let body = self
+                .make_request(
+                    Method::GET,
+                    "/api/v3/announcements",
+                    &query_string,
+                    "",
+                    false,
+                )
+                .await?;
+
+            let response_data = serde_json::from_str::<ApiV3Announcements>(&body)
+                .context("Failed to deserialize announcements response")?;
+
+            if response_data.code != "200000" {
+                anyhow::bail!(
+                    "KuCoin API error: code={}, msg={:?}",
+                    response_data.code,
+                    response_data.msg
+                )
+            }
+
+            let Some(page) = response_data.data else {
+                break;
+            };
+            announcements.extend(page.items);
+
+            if page.current_page >= page.total_page {
+                break;
+            }
+            current_page += 1;
+        }
+
+        Ok(announcements)
+    }
+}
+
+fn query_windows(start: DateTime<Utc>, end: DateTime<Utc>, span: TimeDelta) -> Vec<(i64, i64)> {
+    let mut windows = Vec::new();
+    let mut window_start = start;
+
+    while window_start < end {
+        let window_end = (window_start + span).min(end);
+        windows.push((
+            window_start.timestamp_millis(),
+            window_end.timestamp_millis(),
+        ));
+        window_start = window_end;
+    }
+
+    windows
+}
+
+fn order_from_api(o: HfOrderApi) -> Result<Order> {
+    Ok(Order::new(
+        o.id,
+        o.client_oid.unwrap_or_default(),
+        o.symbol,
+        o.op_type,
+        o.order_type,
+        o.side,
+        o.price,
+        o.size,
+        o.funds,
+        o.deal_size,
+        o.deal_funds,
+        o.fee,
+        o.fee_currency,
+        o.time_in_force,
+        o.post_only,
+        o.hidden,
+        o.iceberg,
+        o.cancel_exist,
+        o.active,
+        o.in_order_book,
+        o.trade_type,
+        o.remark.unwrap_or_default(),
+        from_timestamp_ms(o.created_at)?,
+        from_timestamp_ms(o.last_updated_at)?,
+    ))
+}
+
+fn ledger_entry_from_api(l: LedgerApi) -> Result<LedgerEntry> {
+    Ok(LedgerEntry::new(
+        l.id,
+        l.currency,
+        parse_decimal(&l.amount)?,
+        parse_decimal(&l.fee)?,
+        parse_decimal(&l.balance)?,
+        l.account_type.to_uppercase(),
+        l.biz_type,
+        l.direction,
+        l.context.unwrap_or_default(),
+        from_timestamp_ms(l.created_at)?,
+    ))
+}
+
+pub(crate) fn from_timestamp_ms(timestamp_ms: i64) -> Result<DateTime<Utc>> {
+    DateTime::from_timestamp_millis(timestamp_ms)
+        .with_context(|| format!("Invalid timestamp: {}", timestamp_ms))
+}
+
+pub(crate) fn parse_decimal(value: &str) -> Result<Decimal> {
+    value
+        .parse()
+        .with_context(|| format!("Invalid decimal: {}", value))
+}
+
+fn data_quality(symbol: &str, field: &str, value: &str) -> Result<Decimal, DataQualityIssue> {
+    value.parse().map_err(|e: rust_decimal::Error| {
+        DataQualityIssue::new(
+            symbol.to_string(),
+            field.to_string(),
+            value.to_string(),
+            e.to_string(),
+        )
+    })
+}
+
+fn optional_data_quality(
+    symbol: &str,
+    field: &str,
+    value: Option<&str>,
+) -> Result<Option<Decimal>, DataQualityIssue> {
+    value
+        .filter(|v| !v.is_empty())
+        .map(|v| data_quality(symbol, field, v))
+        .transpose()
+}
+
+fn screen<T>(rows: Vec<Result<T, DataQualityIssue>>) -> (Vec<T>, Vec<DataQualityIssue>) {
+    let mut items = Vec::with_capacity(rows.len());
+    let mut issues = Vec::new();
+    for row in rows {
+        match row {
+            Ok(item) => items.push(item),
+            Err(issue) => issues.push(issue),
+        }
+    }
+    (items, issues)
+}
+
+fn price_levels(levels: &[[String; 2]]) -> Result<Vec<PriceLevel>> {
+    levels
+        .iter()
+        .map(|[price, size]| Ok(PriceLevel::new(parse_decimal(price)?, parse_decimal(size)?)))
+        .collect()
+}
+
+#[async_trait]
+impl ApiClient for KuCoinClient {
+    async fn fetch_currencies(&self) -> Result<Vec<Currency>> {
+        let currencies_api = self.get_currencies().await?;
+
+        let currencies: Vec<Currency> = currencies_api
+            .into_iter()
+            .map(|c| {
+                Currency::new(
+                    c.currency,
+                    c.name,
+                    c.full_name,
+                    c.precision,
+                    c.is_margin_enabled,
+                    c.is_debit_enabled,
+                )
+            })
+            .collect();
+
+        Ok(currencies)
+    }
+
+    async fn fetch_symbols(&self) -> Result<(Vec<Symbol>, Vec<DataQualityIssue>)> {
+        let symbols_api = self.get_symbols().await?;
 
-        let symbols: Vec<Symbol> = symbols_api
+        let rows: Vec<Result<Symbol, DataQualityIssue>> = symbols_api
             .into_iter()
             .map(|s| {
-                Symbol::new(
-                    s.symbol,
+                let field = |name: &str, value: &str| data_quality(&s.symbol, name, value);
+                Ok(Symbol::new(
+                    s.symbol.clone(),
                     s.name,
                     s.base_currency,
                     s.quote_currency,
                     s.fee_currency,
                     s.market,
-                    s.base_min_size,
-                    s.quote_min_size,
-                    s.base_max_size,
-                    s.quote_max_size,
-                    s.base_increment,
-                    s.quote_increment,
-                    s.price_increment,
-                    s.price_limit_rate,
-                    s.min_funds,
+                    field("baseMinSize", &s.base_min_size)?,
+                    field("quoteMinSize", &s.quote_min_size)?,
+                    field("baseMaxSize", &s.base_max_size)?,
+                    field("quoteMaxSize", &s.quote_max_size)?,
+                    field("baseIncrement", &s.base_increment)?,
+                    field("quoteIncrement", &s.quote_increment)?,
+                    field("priceIncrement", &s.price_increment)?,
+                    field("priceLimitRate", &s.price_limit_rate)?,
+                    s.min_funds
+                        .as_deref()
+                        .map(|v| field("minFunds", v))
+                        .transpose()?,
                     s.is_margin_enabled,
                     s.enable_trading,
                     s.fee_category,
-                    s.maker_fee_coefficient,
-                    s.taker_fee_coefficient,
+                    field("makerFeeCoefficient", &s.maker_fee_coefficient)?,
+                    field("takerFeeCoefficient", &s.taker_fee_coefficient)?,
                     s.st,
-                )
+                ))
             })
             .collect();
 
-        Ok(symbols)
+        Ok(screen(rows))
     }
 
-    async fn fetch_tickers(&self) -> Result<Vec<Ticker>> {
-        let ticker_api = self.get_tickers().await?;
+    async fn fetch_tickers(&self) -> Result<(Vec<Ticker>, Vec<DataQualityIssue>)> {
+        let ticker_data = self.get_tickers().await?;
+        let time_point = from_timestamp_ms(ticker_data.time)?;
 
-        let tickers: Vec<Ticker> = ticker_api
+        let rows: Vec<Result<Ticker, DataQualityIssue>> = ticker_data
+            .ticker
             .into_iter()
             .map(|t| {
-                Ticker::new(
-                    t.symbol,
+                let field = |name: &str, value: &str| data_quality(&t.symbol, name, value);
+                let optional =
+                    |name: &str, value: Option<&str>| optional_data_quality(&t.symbol, name, value);
+                Ok(Ticker::new(
+                    t.symbol.clone(),
                     t.symbol_name,
-                    t.taker_fee_rate,
-                    t.maker_fee_rate,
-                    t.taker_coefficient,
-                    t.maker_coefficient,
+                    field("takerFeeRate", &t.taker_fee_rate)?,
+                    field("makerFeeRate", &t.maker_fee_rate)?,
+                    field("takerCoefficient", &t.taker_coefficient)?,
+                    field("makerCoefficient", &t.maker_coefficient)?,
+                    optional("buy", t.buy.as_deref())?,
+                    optional("sell", t.sell.as_deref())?,
+                    optional("changeRate", t.change_rate.as_deref())?,
+                    optional("changePrice", t.change_price.as_deref())?,
+                    optional("high", t.high.as_deref())?,
+                    optional("low", t.low.as_deref())?,
+                    optional("vol", t.vol.as_deref())?,
+                    optional("volValue", t.vol_value.as_deref())?,
+                    optional("last", t.last.as_deref())?,
+                    optional("averagePrice", t.average_price.as_deref())?,
+                    time_point,
+                ))
+            })
+            .collect();
+
+        Ok(screen(rows))
+    }
+
+    async fn fetch_order_book(&self, symbol: &str) -> Result<OrderBookSnapshot> {
+        let b = self.get_order_book(symbol).await?;
+
+        Ok(OrderBookSnapshot::new(
+            symbol.to_string(),
+            b.sequence
+                .parse()
+                .with_context(|| format!("Invalid order book sequence: {}", b.sequence))?,
+            price_levels(&b.bids)?,
+            price_levels(&b.asks)?,
+            from_timestamp_ms(b.time)?,
+        ))
+    }
+
+    async fn fetch_partial_order_book(&self, symbol: &str) -> Result<OrderBookSnapshot> {
+        let b = self.get_partial_order_book(symbol).await?;
+
+        Ok(OrderBookSnapshot::new(
+            symbol.to_string(),
+            b.sequence
+                .parse()
+                .with_context(|| format!("Invalid order book sequence: {}", b.sequence))?,
+            price_levels(&b.bids)?,
+            price_levels(&b.asks)?,
+            from_timestamp_ms(b.time)?,
+        ))
+    }
+
+    async fn fetch_trades(&self, symbol: &str) -> Result<Vec<Trade>> {
+        let trades_api = self.get_trade_histories(symbol).await?;
+
+        trades_api
+            .into_iter()
+            .map(|t| {
+                Ok(Trade::new(
+                    symbol.to_string(),
+                    t.sequence
+                        .parse()
+                        .with_context(|| format!("Invalid trade sequence: {}", t.sequence))?,
+                    data_quality(symbol, "price", &t.price)?,
+                    data_quality(symbol, "size", &t.size)?,
+                    t.side,
+                    DateTime::from_timestamp_nanos(t.time),
+                ))
+            })
+            .collect()
+    }
+
+    async fn fetch_candles(
+        &self,
+        symbol: &str,
+        interval: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Candle>> {
+        let rows = self.get_candles(symbol, interval, start, end).await?;
+
+        let mut candles = rows
+            .iter()
+            .map(|[time, open, close, high, low, volume, turnover]| {
+                let open_time = time
+                    .parse()
+                    .ok()
+                    .and_then(|secs| DateTime::from_timestamp(secs, 0))
+                    .with_context(|| format!("Invalid candle time: {}", time))?;
+                let field = |name: &str, value: &str| data_quality(symbol, name, value);
+                Ok(Candle::new(
+                    symbol.to_string(),
+                    interval.to_string(),
+                    open_time,
+                    field("open", open)?,
+                    field("close", close)?,
+                    field("high", high)?,
+                    field("low", low)?,
+                    field("volume", volume)?,
+                    field("turnover", turnover)?,
+                ))
+            })
+            .collect::<Result<Vec<Candle>>>()?;
+        candles.sort_by_key(|c| c.open_time);
+
+        Ok(candles)
+    }
+
+    async fn fetch_markets(&self) -> Result<Vec<String>> {
+        self.get_markets().await
+    }
+
+    async fn fetch_deposits(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Deposit>> {
+        let mut deposits = Vec::new();
+
+        for (start_at, end_at) in query_windows(start, end, MAX_QUERY_SPAN) {
+            for d in self.get_deposits(start_at, end_at).await? {
+                deposits.push(Deposit::new(
+                    d.currency,
+                    d.chain.unwrap_or_default(),
+                    d.status,
+                    d.address.unwrap_or_default(),
+                    d.memo.unwrap_or_default(),
+                    d.is_inner,
+                    d.amount,
+                    d.fee,
+                    d.wallet_tx_id.filter(|id| !id.is_empty()),
+                    d.remark.unwrap_or_default(),
+                    from_timestamp_ms(d.created_at)?,
+                    from_timestamp_ms(d.updated_at)?,
+                ));
+            }
+        }
+
+        Ok(deposits)
+    }
+
+    async fn fetch_withdrawals(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Withdrawal>> {
+        let mut withdrawals = Vec::new();
+
+        for (start_at, end_at) in query_windows(start, end, MAX_QUERY_SPAN) {
+            for w in self.get_withdrawals(start_at, end_at).await? {
+                withdrawals.push(Withdrawal::new(
+                    w.id,
+                    w.currency,
+                    w.chain.unwrap_or_default(),
+                    w.status,
+                    w.address.unwrap_or_default(),
+                    w.memo.unwrap_or_default(),
+                    w.is_inner,
+                    w.amount,
+                    w.fee,
+                    w.wallet_tx_id,
+                    w.remark.unwrap_or_default(),
+                    from_timestamp_ms(w.created_at)?,
+                    from_timestamp_ms(w.updated_at)?,
+                ));
+            }
+        }
+
+        Ok(withdrawals)
+    }
+
+    async fn fetch_fills(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<Fill>> {
+        let mut fills = Vec::new();
+
+        for (start_at, end_at) in query_windows(start, end, MAX_QUERY_SPAN) {
+            for f in self.get_fills(start_at, end_at).await? {
+                fills.push(Fill::new(
+                    f.trade_id,
+                    f.symbol,
+                    f.order_id,
+                    f.counter_order_id.unwrap_or_default(),
+                    f.side,
+                    f.liquidity,
+                    f.force_taker,
+                    f.price,
+                    f.size,
+                    f.funds,
+                    f.fee,
+                    f.fee_rate,
+                    f.fee_currency,
+                    f.trade_type,
+                    f.order_type,
+                    from_timestamp_ms(f.created_at)?,
+                ));
+            }
+        }
+
+        Ok(fills)
+    }
+
+    async fn fetch_orders(
+        &self,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Order>> {
+        let mut orders = Vec::new();
+
+        for (start_at, end_at) in query_windows(start, end, MAX_QUERY_SPAN) {
+            for o in self.get_hf_orders_done(symbol, start_at, end_at).await? {
+                orders.push(order_from_api(o)?);
+            }
+        }
+        for o in self.get_hf_orders_active(symbol).await? {
+            orders.push(order_from_api(o)?);
+        }
+
+        Ok(orders)
+    }
+
+    async fn fetch_ledger_entries(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<LedgerEntry>> {
+        let mut entries = Vec::new();
+
+        for (start_at, end_at) in query_windows(start, end, LEDGER_QUERY_SPAN) {
+            for l in self.get_account_ledgers(start_at, end_at).await? {
+                entries.push(ledger_entry_from_api(l)?);
+            }
+        }
+        for (start_at, end_at) in query_windows(start, end, MAX_QUERY_SPAN) {
+            for l in self.get_hf_account_ledgers(start_at, end_at).await? {
+                entries.push(ledger_entry_from_api(l)?);
+            }
+        }
+
+        Ok(entries)
+    }
+
+    async fn fetch_account_balances(&self) -> Result<Vec<AccountBalance>> {
+        let accounts_api = self.get_accounts().await?;
+
+        let balances: Vec<AccountBalance> = accounts_api
+            .into_iter()
+            .map(|a| {
+                AccountBalance::new(
+                    a.id,
+                    a.currency,
+                    a.account_type.to_uppercase(),
+                    a.balance,
+                    a.available,
+                    a.holds,
+                )
+            })
+            .collect();
+
+        Ok(balances)
+    }
+
+    async fn fetch_sub_accounts(&self) -> Result<Vec<SubAccount>> {
+        let users_api = self.get_sub_users().await?;
+
+        users_api
+            .into_iter()
+            .map(|u| {
+                Ok(SubAccount::new(
+                    u.user_id,
+                    u.uid,
+                    u.sub_name,
+                    u.status,
+                    u.sub_type,
+                    u.access,
+                    u.remarks,
+                    from_timestamp_ms(u.created_at)?,
+                ))
+            })
+            .collect()
+    }
+
+    async fn fetch_sub_account_balances(&self) -> Result<Vec<SubAccountBalance>> {
+        let subs_api = self.get_sub_account_balances().await?;
+
+        let mut balances = Vec::new();
+        for sub in subs_api {
+            for (account_type, accounts) in [
+                ("MAIN", sub.main_accounts),
+                ("TRADE", sub.trade_accounts),
+                ("MARGIN", sub.margin_accounts),
+            ] {
+                balances.extend(accounts.into_iter().map(|a| {
+                    SubAccountBalance::new(
+                        sub.sub_user_id.clone(),
+                        sub.sub_name.clone(),
+                        a.currency,
+                        account_type.to_string(),
+                        a.balance,
+                        a.available,
+                        a.holds,
+                    )
+                }));
+            }
+        }
+
+        Ok(balances)
+    }
+
+    async fn fetch_cross_margin_symbols(&self) -> Result<Vec<CrossMarginSymbol>> {
+        let symbols_api = self.get_cross_margin_symbols().await?;
+
+        let symbols: Vec<CrossMarginSymbol> = symbols_api
+            .into_iter()
+            .map(|s| {
+                CrossMarginSymbol::new(
+                    s.symbol,
+                    s.name,
+                    s.base_currency,
+                    s.quote_currency,
+                    s.market,
+                    s.enable_trading,
+                )
+            })
+            .collect();
+
+        Ok(symbols)
+    }
+
+    async fn fetch_isolated_margin_symbols(&self) -> Result<Vec<IsolatedMarginSymbol>> {
+        let symbols_api = self.get_isolated_margin_symbols().await?;
+
+        let symbols: Vec<IsolatedMarginSymbol> = symbols_api
+            .into_iter()
+            .map(|s| {
+                IsolatedMarginSymbol::new(
+                    s.symbol,
+                    s.symbol_name,
+                    s.base_currency,
+                    s.quote_currency,
+                    s.max_leverage,
+                    s.fl_debt_ratio,
+                    s.auto_renew_max_debt_ratio,
+                    s.trade_enable,
+                    s.base_borrow_enable,
+                    s.quote_borrow_enable,
+                    s.base_transfer_in_enable,
+                    s.quote_transfer_in_enable,
+                    s.base_borrow_coefficient,
+                    s.quote_borrow_coefficient,
+                )
+            })
+            .collect();
+
+        Ok(symbols)
+    }
+
+    async fn fetch_margin_config(&self) -> Result<MarginConfig> {
+        let c = self.get_margin_config().await?;
+
+        Ok(MarginConfig::new(
+            c.currency_list,
+            c.max_leverage,
+            c.warning_debt_ratio,
+            c.liq_debt_ratio,
+        ))
+    }
+
+    async fn fetch_lending_markets(&self) -> Result<Vec<LendingMarket>> {
+        let markets_api = self.get_lending_markets().await?;
+
+        let markets: Vec<LendingMarket> = markets_api
+            .into_iter()
+            .map(|m| {
+                LendingMarket::new(
+                    m.currency,
+                    m.purchase_enable,
+                    m.redeem_enable,
+                    m.auto_purchase_enable,
+                    m.increment,
+                    m.min_purchase_size,
+                    m.max_purchase_size,
+                    m.min_interest_rate,
+                    m.max_interest_rate,
+                    m.interest_increment,
+                    m.market_interest_rate,
                 )
             })
             .collect();
 
-        Ok(tickers)
+        Ok(markets)
+    }
+
+    async fn fetch_lending_interest_rates(
+        &self,
+        currency: &str,
+    ) -> Result<Vec<LendingInterestRate>> {
+        let rates_api = self.get_market_interest_rates(currency).await?;
+
+        rates_api
+            .into_iter()
+            .map(|r| {
+                let time = NaiveDateTime::parse_from_str(&r.time, "%Y%m%d%H%M")
+                    .with_context(|| format!("Invalid interest rate time: {}", r.time))?
+                    .and_utc();
+                Ok(LendingInterestRate::new(
+                    currency.to_string(),
+                    time,
+                    r.market_interest_rate,
+                ))
+            })
+            .collect()
+    }
+
+    async fn fetch_fiat_prices(&self, base: &str) -> Result<Vec<FiatPrice>> {
+        let prices_api = self.get_fiat_prices(base).await?;
+        let time_point = Utc::now();
+
+        let prices: Vec<FiatPrice> = prices_api
+            .into_iter()
+            .map(|(currency, price)| FiatPrice::new(currency, base.to_string(), price, time_point))
+            .collect();
+
+        Ok(prices)
+    }
+
+    async fn fetch_service_status(&self) -> Result<ExchangeStatus> {
+        let s = self.get_service_status().await?;
+        Ok(ExchangeStatus::new(s.status, s.msg.unwrap_or_default()))
+    }
+
+    async fn fetch_announcements(
+        &self,
+        ann_type: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Announcement>> {
+        let announcements_api = self
+            .get_announcements(ann_type, start.timestamp_millis(), end.timestamp_millis())
+            .await?;
+
+        announcements_api
+            .into_iter()
+            .map(|a| {
+                Ok(Announcement::new(
+                    a.ann_id,
+                    a.ann_title,
+                    a.ann_type,
+                    a.ann_desc.unwrap_or_default(),
+                    a.ann_url,
+                    a.language,
+                    from_timestamp_ms(a.c_time)?,
+                ))
+            })
+            .collect()
+    }
+
+    async fn fetch_ws_token(&self, private: bool) -> Result<WsToken> {
+        let bullet = self.get_bullet(private).await?;
+        let server = bullet
+            .instance_servers
+            .into_iter()
+            .next()
+            .context("WebSocket token response contains no instance servers")?;
+
+        Ok(WsToken {
+            token: bullet.token,
+            endpoint: server.endpoint,
+            ping_interval: Duration::from_millis(server.ping_interval),
+            ping_timeout: Duration::from_millis(server.ping_timeout),
+        })
     }
 }
@@ -0,0 +1,365 @@
+use crate::domain::entities::exchange_status::ExchangeStatus;
+use crate::domain::entities::futures::{FundingRate, FuturesContract, MarkPrice, OpenInterest};
+use crate::infrastructure::api::futures_api_client::FuturesApiClient;
+use crate::infrastructure::api::kucoin_client::{
+    ApiV1Status, KuCoinClient, ServiceStatusApi, from_timestamp_ms,
+};
+use crate::infrastructure::config::Config;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use reqwest::Method;
+
+const FUNDING_HISTORY_QUERY_SPAN: TimeDelta = TimeDelta::days(3);
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1ContractsActive {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<Vec<ContractApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ContractApi {
+    pub symbol: String,
+    #[serde(rename = "rootSymbol")]
+    pub root_symbol: String,
+    #[serde(rename = "type")]
+    pub contract_type: String,
+    #[serde(rename = "baseCurrency")]
+    pub base_currency: String,
+    #[serde(rename = "quoteCurrency")]
+    pub quote_currency: String,
+    #[serde(rename = "settleCurrency")]
+    pub settle_currency: String,
+    pub status: String,
+    pub multiplier: f64,
+    #[serde(rename = "lotSize")]
+    pub lot_size: f64,
+    #[serde(rename = "tickSize")]
+    pub tick_size: f64,
+    #[serde(rename = "maxOrderQty")]
+    pub max_order_qty: f64,
+    #[serde(rename = "maxPrice")]
+    pub max_price: f64,
+    #[serde(rename = "maxLeverage")]
+    pub max_leverage: f64,
+    #[serde(rename = "initialMargin")]
+    pub initial_margin: f64,
+    #[serde(rename = "maintainMargin")]
+    pub maintain_margin: f64,
+    #[serde(rename = "makerFeeRate")]
+    pub maker_fee_rate: f64,
+    #[serde(rename = "takerFeeRate")]
+    pub taker_fee_rate: f64,
+    #[serde(rename = "isInverse")]
+    pub is_inverse: bool,
+    #[serde(rename = "isQuanto")]
+    pub is_quanto: bool,
+    #[serde(rename = "fundingRateGranularity")]
+    pub funding_rate_granularity: Option<i64>,
+    #[serde(rename = "firstOpenDate")]
+    pub first_open_date: Option<i64>,
+    #[serde(rename = "expireDate")]
+    pub expire_date: Option<i64>,
+    #[serde(rename = "openInterest")]
+    pub open_interest: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1FundingRateCurrent {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<CurrentFundingRateApi>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct CurrentFundingRateApi {
+    pub symbol: String,
+    #[serde(rename = "timePoint")]
+    pub time_point: i64,
+    pub value: f64,
+    #[serde(rename = "predictedValue")]
+    pub predicted_value: Option<f64>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1ContractFundingRates {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<Vec<HistoricalFundingRateApi>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct HistoricalFundingRateApi {
+    pub symbol: String,
+    #[serde(rename = "fundingRate")]
+    pub funding_rate: f64,
+    pub timepoint: i64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ApiV1MarkPrice {
+    pub code: String,
+    pub msg: Option<String>,
+    pub data: Option<MarkPriceApi>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct MarkPriceApi {
+    pub symbol: String,
+    #[serde(rename = "timePoint")]
+    pub time_point: i64,
+    pub value: f64,
+    #[serde(rename = "indexPrice")]
+    pub index_price: f64,
+}
+
+pub struct KuCoinFuturesClient {
+    client: KuCoinClient,
+}
+
+impl KuCoinFuturesClient {
+    pub fn new(config: &Config) -> Result<Self> {
+        Ok(Self {
+            client: KuCoinClient::with_base_url(config, &config.kucoin_futures_base_url)?,
+        })
+    }
+
+    async fn get_active_contracts(&self) -> Result<Vec<ContractApi>> {
+        let body = self
+            .client
+            .make_request(Method::GET, "/api/v1/contracts/active", "", "", false)
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV1ContractsActive>(&body)
+            .context("Failed to deserialize active contracts response")?;
+
+        if response_data.code == "200000" {
+            Ok(response_data.data.unwrap_or_default())
+        } else {
+            anyhow::bail!(
+                "KuCoin Futures API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_current_funding_rate(&self, symbol: &str) -> Result<CurrentFundingRateApi> {
+        let endpoint = format!(
+            "/api/v1/funding-rate/{}/current",
+            urlencoding::encode(symbol)
+        );
+        let body = self
+            .client
+            .make_request(Method::GET, &endpoint, "", "", false)
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV1FundingRateCurrent>(&body)
+            .context("Failed to deserialize current funding rate response")?;
+
+        if response_data.code == "200000" {
+            response_data
+                .data
+                .with_context(|| format!("No current funding rate for {}", symbol))
+        } else {
+            anyhow::bail!(
+                "KuCoin Futures API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_funding_rate_history(
+        &self,
+        symbol: &str,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<HistoricalFundingRateApi>> {
+        let query_string = format!(
+            "symbol={}&from={}&to={}",
+            urlencoding::encode(symbol),
+            from,
+            to
+        );
+        let body = self
+            .client
+            .make_request(
+                Method::GET,
+                "/api/v1/contract/funding-rates",
+                &query_string,
+                "",
+                false,
+            )
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV1ContractFundingRates>(&body)
+            .context("Failed to deserialize funding rate history response")?;
+
+        if response_data.code == "200000" {
+            Ok(response_data.data.unwrap_or_default())
+        } else {
+            anyhow::bail!(
+                "KuCoin Futures API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_mark_price(&self, symbol: &str) -> Result<MarkPriceApi> {
+        let endpoint = format!("/api/v1/mark-price/{}/current", urlencoding::encode(symbol));
+        let body = self
+            .client
+            .make_request(Method::GET, &endpoint, "", "", false)
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV1MarkPrice>(&body)
+            .context("Failed to deserialize mark price response")?;
+
+        if response_data.code == "200000" {
+            response_data
+                .data
+                .with_context(|| format!("No mark price for {}", symbol))
+        } else {
+            anyhow::bail!(
+                "KuCoin Futures API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+
+    async fn get_service_status(&self) -> Result<ServiceStatusApi> {
+        let body = self
+            .client
+            .make_request(Method::GET, "/api/v1/status", "", "", false)
+            .await?;
+
+        let response_data = serde_json::from_str::<ApiV1Status>(&body)
+            .context("Failed to deserialize futures service status response")?;
+
+        if response_data.code == "200000" {
+            response_data
+                .data
+                .context("Futures service status response contains no data")
+        } else {
+            anyhow::bail!(
+                "KuCoin Futures API error: code={}, msg={:?}",
+                response_data.code,
+                response_data.msg
+            )
+        }
+    }
+}
+
+#[async_trait]
+impl FuturesApiClient for KuCoinFuturesClient {
+    async fn fetch_contracts(&self) -> Result<Vec<FuturesContract>> {
+        let contracts_api = self.get_active_contracts().await?;
+
+        contracts_api
+            .into_iter()
+            .map(|c| {
+                Ok(FuturesContract::new(
+                    c.symbol,
+                    c.root_symbol,
+                    c.contract_type,
+                    c.base_currency,
+                    c.quote_currency,
+                    c.settle_currency,
+                    c.status,
+                    c.multiplier,
+                    c.lot_size,
+                    c.tick_size,
+                    c.max_order_qty,
+                    c.max_price,
+                    c.max_leverage,
+                    c.initial_margin,
+                    c.maintain_margin,
+                    c.maker_fee_rate,
+                    c.taker_fee_rate,
+                    c.is_inverse,
+                    c.is_quanto,
+                    c.funding_rate_granularity,
+                    c.first_open_date.map(from_timestamp_ms).transpose()?,
+                    c.expire_date.map(from_timestamp_ms).transpose()?,
+                ))
+            })
+            .collect()
+    }
+
+    async fn fetch_current_funding_rate(&self, symbol: &str) -> Result<FundingRate> {
+        let rate = self.get_current_funding_rate(symbol).await?;
+
+        Ok(FundingRate::new(
+            rate.symbol,
+            from_timestamp_ms(rate.time_point)?,
+            rate.value,
+            rate.predicted_value,
+        ))
+    }
+
+    async fn fetch_funding_rate_history(
+        &self,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<FundingRate>> {
+        let mut rates = Vec::new();
+        let mut window_start = start;
+
+        while window_start < end {
+            let window_end = (window_start + FUNDING_HISTORY_QUERY_SPAN).min(end);
+            for r in self
+                .get_funding_rate_history(
+                    symbol,
+                    window_start.timestamp_millis(),
+                    window_end.timestamp_millis(),
+                )
+                .await?
+            {
+                rates.push(FundingRate::new(
+                    r.symbol,
+                    from_timestamp_ms(r.timepoint)?,
+                    r.funding_rate,
+                    None,
+                ));
+            }
+            window_start = window_end;
+        }
+
+        Ok(rates)
+    }
+
+    async fn fetch_mark_price(&self, symbol: &str) -> Result<MarkPrice> {
+        let price = self.get_mark_price(symbol).await?;
+
+        Ok(MarkPrice::new(
+            price.symbol,
+            from_timestamp_ms(price.time_point)?,
+            price.value,
+            price.index_price,
+        ))
+    }
+
+    async fn fetch_open_interest(&self) -> Result<Vec<OpenInterest>> {
+        let time_point = Utc::now();
+        let contracts_api = self.get_active_contracts().await?;
+
+        Ok(contracts_api
+            .into_iter()
+            .filter_map(|c| {
+                c.open_interest
+                    .map(|open_interest| OpenInterest::new(c.symbol, open_interest, time_point))
+            })
+            .collect())
+    }
+
+    async fn fetch_service_status(&self) -> Result<ExchangeStatus> {
+        let s = self.get_service_status().await?;
+        Ok(ExchangeStatus::new(s.status, s.msg.unwrap_or_default()))
+    }
+}
@@ -1,2 +1,4 @@
 pub mod api_client;
+pub mod futures_api_client;
 pub mod kucoin_client;
+pub mod kucoin_futures_client;
@@ -1,23 +1,82 @@
+use crate::domain::entities::candle::interval_duration;
+use crate::domain::services::candle_aggregation::parse_interval;
+use crate::domain::services::indicators::IndicatorSpec;
 use anyhow::{Context, Result};
+use rust_decimal::Decimal;
 use std::env;
 
 pub struct Config {
     pub kucoin_base_url: String,
+    pub kucoin_futures_base_url: String,
     pub kucoin_key: String,
     pub kucoin_secret: String,
     pub kucoin_passphrase: String,
     pub database_url: String,
+    pub trading_symbols: Vec<String>,
+    pub watchlist_symbols: Vec<String>,
+    pub fiat_bases: Vec<String>,
+    pub announcement_types: Vec<String>,
+    pub funding_rate_alert_percentile: f64,
+    pub query_api_addr: String,
+    pub arbitrage_min_net_edge: Decimal,
+    pub candle_intervals: Vec<String>,
+    pub aggregated_candle_intervals: Vec<String>,
+    pub analytics_basket: Vec<String>,
+    pub analytics_interval: String,
+    pub analytics_window_days: Vec<i32>,
+    pub liquidity_candidates: usize,
+    pub liquid_universe_size: usize,
+    pub indicators: Vec<IndicatorSpec>,
 }
 
 impl Config {
     pub fn from_env() -> Result<Self> {
+        let watchlist_symbols = get_env_list("KUCOIN_WATCHLIST_SYMBOLS");
+        let mut analytics_basket = get_env_list("ANALYTICS_BASKET");
+        if analytics_basket.is_empty() {
+            analytics_basket = watchlist_symbols.clone();
+        }
+        let analytics_interval =
+            get_env("ANALYTICS_INTERVAL").unwrap_or_else(|_| "4hour".to_string());
+        let mut candle_intervals = candle_intervals()?;
+        if !candle_intervals.contains(&analytics_interval) {
+            interval_duration(&analytics_interval).with_context(|| {
+                format!("Unsupported analytics interval '{}'", analytics_interval)
+            })?;
+            candle_intervals.push(analytics_interval.clone());
+        }
+
         Ok(Self {
             kucoin_base_url: get_env("KUCOIN_BASE_URL")
                 .unwrap_or_else(|_| "https://api.kucoin.com".to_string()),
+            kucoin_futures_base_url: get_env("KUCOIN_FUTURES_BASE_URL")
+                .unwrap_or_else(|_| "https://api-futures.kucoin.com".to_string()),
             kucoin_key: get_env("KUCOIN_KEY")?,
             kucoin_secret: get_env("KUCOIN_SECRET")?,
             kucoin_passphrase: get_env("KUCOIN_PASS")?,
             database_url: get_env("DATABASE_URL").context("DATABASE_URL not set")?,
+            trading_symbols: get_env_list("KUCOIN_TRADING_SYMBOLS"),
+            watchlist_symbols,
+            fiat_bases: fiat_bases(),
+            announcement_types: announcement_types(),
+            funding_rate_alert_percentile: get_env("FUNDING_RATE_ALERT_PERCENTILE")
+                .map(|value| value.parse())
+                .unwrap_or(Ok(0.99))
+                .context("FUNDING_RATE_ALERT_PERCENTILE must be a number")?,
+            arbitrage_min_net_edge: get_env("ARBITRAGE_MIN_NET_EDGE")
+                .map(|value| value.parse())
+                .unwrap_or(Ok(Decimal::new(1, 3)))
+                .context("ARBITRAGE_MIN_NET_EDGE must be a decimal number")?,
+            candle_intervals,
+            aggregated_candle_intervals: aggregated_candle_intervals()?,
+            analytics_basket,
+            analytics_interval,
+            analytics_window_days: analytics_window_days()?,
+            indicators: indicators()?,
+            liquidity_candidates: positive_count("LIQUIDITY_CANDIDATES", 100)?,
+            liquid_universe_size: positive_count("LIQUID_UNIVERSE_SIZE", 50)?,
+            query_api_addr: get_env("QUERY_API_ADDR")
+                .unwrap_or_else(|_| "0.0.0.0:8080".to_string()),
         })
     }
 }
@@ -25,3 +84,134 @@ impl Config {
 fn get_env(key: &str) -> Result<String> {
     Ok(env::var(key)?.trim().to_string())
 }
+
+fn get_env_list(key: &str) -> Vec<String> {
+    get_env(key)
+        .map(|value| {
+            value
+                .split(',')
+                .map(|item| item.trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn fiat_bases() -> Vec<String> {
+    let mut bases = vec!["USD".to_string()];
+    for base in get_env_list("KUCOIN_FIAT_BASES") {
+        let base = base.to_uppercase();
+        if !bases.contains(&base) {
+            bases.push(base);
+        }
+    }
+    bases
+}
+
+fn announcement_types() -> Vec<String> {
+    let types = get_env_list("KUCOIN_ANNOUNCEMENT_TYPES");
+    if types.is_empty() {
+        ["new-listings", "delistings", "maintenance-updates"]
+            .iter()
+            .map(|t| t.to_string())
+            .collect()
+    } else {
+        types
+    }
+}
+
+fn candle_intervals() -> Result<Vec<String>> {
+    let mut intervals = get_env_list("KUCOIN_CANDLE_INTERVALS");
+    if intervals.is_empty() {
+        intervals.push("1hour".to_string());
+    }
+    for interval in &intervals {
+        interval_duration(interval)
+            .with_context(|| format!("Unsupported candle interval '{}'", interval))?;
+    }
+    Ok(intervals)
+}
+
+fn aggregated_candle_intervals() -> Result<Vec<String>> {
+    let mut intervals = get_env_list("AGGREGATED_CANDLE_INTERVALS");
+    if intervals.is_empty() {
+        intervals = ["15s", "1min", "5min"]
+            .iter()
+            .map(|i| i.to_string())
+            .collect();
+    }
+
+    let mut durations = intervals
+        .into_iter()
+        .map(|interval| {
+            let duration = parse_interval(&interval)
+                .with_context(|| format!("Unsupported aggregated interval '{}'", interval))?;
+            Ok((duration, interval))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    durations.sort();
+    durations.dedup_by_key(|(duration, _)| *duration);
+
+    // Coarser intervals are rolled up from the finest one, so they must be
+    // whole multiples of it.
+    let base = durations[0].0.num_milliseconds();
+    for (duration, interval) in &durations[1..] {
+        if duration.num_milliseconds() % base != 0 {
+            anyhow::bail!(
+                "Aggregated interval '{}' is not a multiple of '{}'",
+                interval,
+                durations[0].1
+            );
+        }
+    }
+    Ok(durations
+        .into_iter()
+        .map(|(_, interval)| interval)
+        .collect())
+}
+
+fn indicators() -> Result<Vec<IndicatorSpec>> {
+    let mut specs = get_env_list("INDICATORS");
+    if specs.is_empty() {
+        specs = [
+            "sma:20",
+            "ema:20",
+            "rsi:14",
+            "macd:12:26:9",
+            "bb:20:2",
+            "atr:14",
+            "vwap",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    }
+    specs.iter().map(|s| s.parse()).collect()
+}
+
+fn positive_count(key: &str, default: usize) -> Result<usize> {
+    match get_env(key) {
+        Ok(value) => value
+            .parse::<usize>()
+            .ok()
+            .filter(|count| *count > 0)
+            .with_context(|| format!("{} must be a positive integer", key)),
+        Err(_) => Ok(default),
+    }
+}
+
+fn analytics_window_days() -> Result<Vec<i32>> {
+    let windows = get_env_list("ANALYTICS_WINDOW_DAYS");
+    if windows.is_empty() {
+        return Ok(vec![7, 30]);
+    }
+    windows
+        .iter()
+        .map(|w| {
+            w.parse::<i32>()
+                .ok()
+                .filter(|days| *days > 0)
+                .with_context(|| format!("Invalid analytics window '{}'", w))
+        })
+        .collect()
+}
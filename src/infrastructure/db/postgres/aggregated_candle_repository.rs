@@ -0,0 +1,205 @@
+use crate::domain::entities::candle::{Candle, CandleConsistencyCheck};
+use crate::domain::repositories::aggregated_candle_repository::{
+    AggregatedCandleReadRepository, AggregatedCandleWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+use tracing::info;
+
+pub struct PostgresAggregatedCandleRepository {
+    pool: PgPool,
+}
+
+impl PostgresAggregatedCandleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AggregatedCandleReadRepository for PostgresAggregatedCandleRepository {
+    async fn find_latest_open_time(
+        &self,
+        exchange: &str,
+        source: &str,
+        symbol: &str,
+        interval: &str,
+    ) -> Result<Option<DateTime<Utc>>> {
+        let open_time = sqlx::query_scalar(
+            r#"
+            SELECT MAX(open_time)
+            FROM aggregated_candle
+            WHERE exchange = $1 AND source = $2 AND symbol = $3 AND interval = $4
+            "#,
+        )
+        .bind(exchange)
+        .bind(source)
+        .bind(symbol)
+        .bind(interval)
+        .fetch_one(&self.pool)
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to load latest {} {} candle for '{}'",
+                source, interval, symbol
+            )
+        })?;
+
+        Ok(open_time)
+    }
+
+    async fn find_range(
+        &self,
+        exchange: &str,
+        source: &str,
+        symbol: &str,
+        interval: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Candle>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT symbol, interval, open_time, open, close, high, low, volume, turnover
+            FROM aggregated_candle
+            WHERE exchange = $1 AND source = $2 AND symbol = $3 AND interval = $4
+              AND open_time >= $5 AND open_time < $6
+            ORDER BY open_time
+            "#,
+        )
+        .bind(exchange)
+        .bind(source)
+        .bind(symbol)
+        .bind(interval)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to load {} {} candles for '{}'",
+                source, interval, symbol
+            )
+        })?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(Candle::new(
+                    row.try_get("symbol")?,
+                    row.try_get("interval")?,
+                    row.try_get("open_time")?,
+                    row.try_get("open")?,
+                    row.try_get("close")?,
+                    row.try_get("high")?,
+                    row.try_get("low")?,
+                    row.try_get("volume")?,
+                    row.try_get("turnover")?,
+                ))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl AggregatedCandleWriteRepository for PostgresAggregatedCandleRepository {
+    async fn save(&self, exchange: &str, source: &str, candles: &[Candle]) -> Result<()> {
+        let now = chrono::Utc::now();
+        let total = candles.len();
+
+        for (index, candle) in candles.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO aggregated_candle (
+                    exchange, source, symbol, interval, open_time, open, close, high, low,
+                    volume, turnover, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                ON CONFLICT (exchange, source, symbol, interval, open_time)
+                DO UPDATE SET
+                    open = EXCLUDED.open,
+                    close = EXCLUDED.close,
+                    high = EXCLUDED.high,
+                    low = EXCLUDED.low,
+                    volume = EXCLUDED.volume,
+                    turnover = EXCLUDED.turnover,
+                    updated_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .bind(exchange)
+            .bind(source)
+            .bind(&candle.symbol)
+            .bind(&candle.interval)
+            .bind(candle.open_time)
+            .bind(candle.open)
+            .bind(candle.close)
+            .bind(candle.high)
+            .bind(candle.low)
+            .bind(candle.volume)
+            .bind(candle.turnover)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update aggregated candle at index {} for symbol '{}'",
+                    index, candle.symbol
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!(
+                    "Progress: {}/{} aggregated candles processed",
+                    index + 1,
+                    total
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn save_checks(&self, exchange: &str, checks: &[CandleConsistencyCheck]) -> Result<()> {
+        for (index, check) in checks.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO candle_consistency_check (
+                    exchange, symbol, interval, source, window_start, window_end,
+                    compared, missing, mismatched, max_price_deviation,
+                    max_volume_deviation, checked_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                ON CONFLICT (exchange, symbol, interval, source, checked_at)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&check.symbol)
+            .bind(&check.interval)
+            .bind(&check.source)
+            .bind(check.window_start)
+            .bind(check.window_end)
+            .bind(check.compared)
+            .bind(check.missing)
+            .bind(check.mismatched)
+            .bind(check.max_price_deviation)
+            .bind(check.max_volume_deviation)
+            .bind(check.checked_at)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert candle consistency check at index {} for symbol '{}'",
+                    index, check.symbol
+                )
+            })?;
+        }
+
+        info!(
+            "Successfully processed {} candle consistency checks for exchange '{}'",
+            checks.len(),
+            exchange
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use crate::domain::entities::announcement::{Announcement, AnnouncementLink};
+use crate::domain::repositories::announcement_repository::{
+    AnnouncementReadRepository, AnnouncementWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresAnnouncementRepository {
+    pool: PgPool,
+}
+
+impl PostgresAnnouncementRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AnnouncementReadRepository for PostgresAnnouncementRepository {}
+
+#[async_trait]
+impl AnnouncementWriteRepository for PostgresAnnouncementRepository {
+    async fn save(&self, exchange: &str, announcements: &[Announcement]) -> Result<()> {
+        let now = chrono::Utc::now();
+        let total = announcements.len();
+
+        for (index, announcement) in announcements.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO announcement (
+                    exchange, announcement_id, title, announcement_types, description,
+                    url, language, published_at, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                ON CONFLICT (exchange, announcement_id)
+                DO UPDATE SET
+                    title = EXCLUDED.title,
+                    announcement_types = EXCLUDED.announcement_types,
+                    description = EXCLUDED.description,
+                    url = EXCLUDED.url,
+                    language = EXCLUDED.language,
+                    published_at = EXCLUDED.published_at,
+                    updated_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .bind(exchange)
+            .bind(announcement.id)
+            .bind(&announcement.title)
+            .bind(&announcement.types)
+            .bind(&announcement.description)
+            .bind(&announcement.url)
+            .bind(&announcement.language)
+            .bind(announcement.published_at)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update announcement at index {} with id '{}'",
+                    index, announcement.id
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!("Progress: {}/{} announcements processed", index + 1, total);
+            }
+        }
+
+        info!(
+            "Successfully processed {} announcements for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+
+    async fn save_links(&self, exchange: &str, links: &[AnnouncementLink]) -> Result<()> {
+        for (index, link) in links.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO announcement_link (
+                    exchange, announcement_id, link_type, target
+                )
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (exchange, announcement_id, link_type, target)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(link.announcement_id)
+            .bind(&link.link_type)
+            .bind(&link.target)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert announcement link at index {} for '{}'",
+                    index, link.target
+                )
+            })?;
+        }
+
+        info!(
+            "Successfully processed {} announcement links for exchange '{}'",
+            links.len(),
+            exchange
+        );
+        Ok(())
+    }
+}
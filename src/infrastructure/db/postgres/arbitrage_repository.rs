@@ -0,0 +1,63 @@
+use crate::domain::entities::arbitrage::ArbitrageOpportunity;
+use crate::domain::repositories::arbitrage_repository::{
+    ArbitrageReadRepository, ArbitrageWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresArbitrageRepository {
+    pool: PgPool,
+}
+
+impl PostgresArbitrageRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ArbitrageReadRepository for PostgresArbitrageRepository {}
+
+#[async_trait]
+impl ArbitrageWriteRepository for PostgresArbitrageRepository {
+    async fn save(&self, exchange: &str, opportunities: &[ArbitrageOpportunity]) -> Result<()> {
+        let now = chrono::Utc::now();
+
+        for (index, opportunity) in opportunities.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO arbitrage_opportunity (
+                    exchange, cycle, observed_at, gross_edge, net_edge, legs, detected_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                ON CONFLICT (exchange, cycle, observed_at)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&opportunity.cycle)
+            .bind(opportunity.observed_at)
+            .bind(opportunity.gross_edge)
+            .bind(opportunity.net_edge)
+            .bind(serde_json::to_value(&opportunity.legs)?)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert arbitrage opportunity at index {} for cycle '{}'",
+                    index, opportunity.cycle
+                )
+            })?;
+        }
+
+        info!(
+            "Successfully processed {} arbitrage opportunities for exchange '{}'",
+            opportunities.len(),
+            exchange
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use crate::domain::entities::balance_change::BalanceChange;
+use crate::domain::repositories::balance_change_repository::{
+    BalanceChangeReadRepository, BalanceChangeWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+pub struct PostgresBalanceChangeRepository {
+    pool: PgPool,
+}
+
+impl PostgresBalanceChangeRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BalanceChangeReadRepository for PostgresBalanceChangeRepository {}
+
+#[async_trait]
+impl BalanceChangeWriteRepository for PostgresBalanceChangeRepository {
+    async fn save(&self, exchange: &str, changes: &[BalanceChange]) -> Result<()> {
+        for (index, change) in changes.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO balance_change (
+                    exchange, account_id, currency, total, available, hold,
+                    available_change, hold_change, relation_event, relation_event_id,
+                    changed_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                ON CONFLICT (exchange, account_id, relation_event_id, changed_at)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&change.account_id)
+            .bind(&change.currency)
+            .bind(&change.total)
+            .bind(&change.available)
+            .bind(&change.hold)
+            .bind(&change.available_change)
+            .bind(&change.hold_change)
+            .bind(&change.relation_event)
+            .bind(&change.relation_event_id)
+            .bind(change.changed_at)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert balance change at index {} for currency '{}'",
+                    index, change.currency
+                )
+            })?;
+        }
+
+        Ok(())
+    }
+}
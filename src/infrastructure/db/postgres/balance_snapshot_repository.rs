@@ -0,0 +1,181 @@
+use crate::domain::entities::account_balance::AccountBalance;
+use crate::domain::repositories::balance_snapshot_repository::{
+    BalanceSnapshotReadRepository, BalanceSnapshotWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresBalanceSnapshotRepository {
+    pool: PgPool,
+}
+
+impl PostgresBalanceSnapshotRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn load_snapshot(
+        &self,
+        exchange: &str,
+        snapshot_at: DateTime<Utc>,
+    ) -> Result<Vec<AccountBalance>> {
+        let rows: Vec<(String, String, String, String, String, String)> = sqlx::query_as(
+            r#"
+            SELECT account_id, currency, account_type, balance, available, holds
+            FROM account_balance_snapshot
+            WHERE exchange = $1 AND snapshot_at = $2
+            "#,
+        )
+        .bind(exchange)
+        .bind(snapshot_at)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load balance snapshot at {}", snapshot_at))?;
+
+        let balances = rows
+            .into_iter()
+            .map(
+                |(account_id, currency, account_type, balance, available, holds)| {
+                    AccountBalance::new(
+                        account_id,
+                        currency,
+                        account_type,
+                        balance,
+                        available,
+                        holds,
+                    )
+                },
+            )
+            .collect();
+
+        Ok(balances)
+    }
+}
+
+#[async_trait]
+impl BalanceSnapshotReadRepository for PostgresBalanceSnapshotRepository {
+    async fn find_latest(
+        &self,
+        exchange: &str,
+    ) -> Result<Option<(DateTime<Utc>, Vec<AccountBalance>)>> {
+        let snapshot_at: Option<DateTime<Utc>> = sqlx::query_scalar(
+            "SELECT MAX(snapshot_at) FROM account_balance_snapshot WHERE exchange = $1",
+        )
+        .bind(exchange)
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to query latest balance snapshot")?;
+
+        let Some(snapshot_at) = snapshot_at else {
+            return Ok(None);
+        };
+        let balances = self.load_snapshot(exchange, snapshot_at).await?;
+        Ok(Some((snapshot_at, balances)))
+    }
+
+    async fn find_latest_before(
+        &self,
+        exchange: &str,
+        before: DateTime<Utc>,
+    ) -> Result<Option<(DateTime<Utc>, Vec<AccountBalance>)>> {
+        let snapshot_at: Option<DateTime<Utc>> = sqlx::query_scalar(
+            r#"
+            SELECT MAX(snapshot_at)
+            FROM account_balance_snapshot
+            WHERE exchange = $1 AND snapshot_at <= $2
+            "#,
+        )
+        .bind(exchange)
+        .bind(before)
+        .fetch_one(&self.pool)
+        .await
+        .with_context(|| format!("Failed to query balance snapshot before {}", before))?;
+
+        let Some(snapshot_at) = snapshot_at else {
+            return Ok(None);
+        };
+        let balances = self.load_snapshot(exchange, snapshot_at).await?;
+        Ok(Some((snapshot_at, balances)))
+    }
+
+    async fn find_after(
+        &self,
+        exchange: &str,
+        after: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<(DateTime<Utc>, Vec<AccountBalance>)>> {
+        let times: Vec<DateTime<Utc>> = sqlx::query_scalar(
+            r#"
+            SELECT DISTINCT snapshot_at
+            FROM account_balance_snapshot
+            WHERE exchange = $1 AND snapshot_at > $2
+            ORDER BY snapshot_at
+            LIMIT $3
+            "#,
+        )
+        .bind(exchange)
+        .bind(after)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to query balance snapshots after {}", after))?;
+
+        let mut snapshots = Vec::new();
+        for snapshot_at in times {
+            let balances = self.load_snapshot(exchange, snapshot_at).await?;
+            snapshots.push((snapshot_at, balances));
+        }
+        Ok(snapshots)
+    }
+}
+
+#[async_trait]
+impl BalanceSnapshotWriteRepository for PostgresBalanceSnapshotRepository {
+    async fn save(
+        &self,
+        exchange: &str,
+        snapshot_at: DateTime<Utc>,
+        balances: &[AccountBalance],
+    ) -> Result<()> {
+        let total = balances.len();
+
+        for (index, balance) in balances.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO account_balance_snapshot (
+                    exchange, account_id, currency, account_type,
+                    balance, available, holds, snapshot_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ON CONFLICT (exchange, account_id, snapshot_at)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&balance.account_id)
+            .bind(&balance.currency)
+            .bind(&balance.account_type)
+            .bind(&balance.balance)
+            .bind(&balance.available)
+            .bind(&balance.holds)
+            .bind(snapshot_at)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert balance snapshot at index {} for account '{}'",
+                    index, balance.account_id
+                )
+            })?;
+        }
+
+        info!(
+            "Successfully processed {} account balances for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+}
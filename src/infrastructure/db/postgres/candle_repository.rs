@@ -0,0 +1,140 @@
+use crate::domain::entities::candle::Candle;
+use crate::domain::repositories::candle_repository::{CandleReadRepository, CandleWriteRepository};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+use tracing::info;
+
+pub struct PostgresCandleRepository {
+    pool: PgPool,
+}
+
+impl PostgresCandleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CandleReadRepository for PostgresCandleRepository {
+    async fn find_latest_open_time(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        interval: &str,
+    ) -> Result<Option<DateTime<Utc>>> {
+        let open_time = sqlx::query_scalar(
+            r#"
+            SELECT MAX(open_time)
+            FROM candle
+            WHERE exchange = $1 AND symbol = $2 AND interval = $3
+            "#,
+        )
+        .bind(exchange)
+        .bind(symbol)
+        .bind(interval)
+        .fetch_one(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load latest {} candle for '{}'", interval, symbol))?;
+
+        Ok(open_time)
+    }
+
+    async fn find_range(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        interval: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Candle>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT symbol, interval, open_time, open, close, high, low, volume, turnover
+            FROM candle
+            WHERE exchange = $1 AND symbol = $2 AND interval = $3
+              AND open_time >= $4 AND open_time < $5
+            ORDER BY open_time
+            "#,
+        )
+        .bind(exchange)
+        .bind(symbol)
+        .bind(interval)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load {} candles for '{}'", interval, symbol))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(Candle::new(
+                    row.try_get("symbol")?,
+                    row.try_get("interval")?,
+                    row.try_get("open_time")?,
+                    row.try_get("open")?,
+                    row.try_get("close")?,
+                    row.try_get("high")?,
+                    row.try_get("low")?,
+                    row.try_get("volume")?,
+                    row.try_get("turnover")?,
+                ))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl CandleWriteRepository for PostgresCandleRepository {
+    async fn save(&self, exchange: &str, candles: &[Candle]) -> Result<()> {
+        let now = chrono::Utc::now();
+        let total = candles.len();
+
+        for (index, candle) in candles.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO candle (
+                    exchange, symbol, interval, open_time, open, close, high, low,
+                    volume, turnover, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                ON CONFLICT (exchange, symbol, interval, open_time)
+                DO UPDATE SET
+                    open = EXCLUDED.open,
+                    close = EXCLUDED.close,
+                    high = EXCLUDED.high,
+                    low = EXCLUDED.low,
+                    volume = EXCLUDED.volume,
+                    turnover = EXCLUDED.turnover,
+                    updated_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .bind(exchange)
+            .bind(&candle.symbol)
+            .bind(&candle.interval)
+            .bind(candle.open_time)
+            .bind(candle.open)
+            .bind(candle.close)
+            .bind(candle.high)
+            .bind(candle.low)
+            .bind(candle.volume)
+            .bind(candle.turnover)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update candle at index {} for symbol '{}'",
+                    index, candle.symbol
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!("Progress: {}/{} candles processed", index + 1, total);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -18,7 +18,15 @@ impl PostgresCurrencyRepository {
 }
 
 #[async_trait]
-impl CurrencyReadRepository for PostgresCurrencyRepository {}
+impl CurrencyReadRepository for PostgresCurrencyRepository {
+    async fn find_codes(&self, exchange: &str) -> Result<Vec<String>> {
+        sqlx::query_scalar("SELECT currency FROM currency WHERE exchange = $1 ORDER BY currency")
+            .bind(exchange)
+            .fetch_all(&self.pool)
+            .await
+            .with_context(|| format!("Failed to load currencies for exchange '{}'", exchange))
+    }
+}
 
 #[async_trait]
 impl CurrencyWriteRepository for PostgresCurrencyRepository {
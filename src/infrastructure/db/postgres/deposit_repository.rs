@@ -0,0 +1,163 @@
+use crate::domain::entities::deposit::Deposit;
+use crate::domain::repositories::deposit_repository::{
+    DepositReadRepository, DepositWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresDepositRepository {
+    pool: PgPool,
+}
+
+impl PostgresDepositRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DepositReadRepository for PostgresDepositRepository {
+    async fn find_sync_start(&self, exchange: &str) -> Result<Option<DateTime<Utc>>> {
+        sqlx::query_scalar(
+            r#"
+            SELECT COALESCE(
+                MIN(created_at) FILTER (WHERE status NOT IN ('SUCCESS', 'FAILURE')),
+                MAX(created_at)
+            )
+            FROM deposit
+            WHERE exchange = $1
+            "#,
+        )
+        .bind(exchange)
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to query deposit sync start")
+    }
+}
+
+#[async_trait]
+impl DepositWriteRepository for PostgresDepositRepository {
+    async fn save(&self, exchange: &str, deposits: &[Deposit]) -> Result<()> {
+        let now = chrono::Utc::now();
+        let total = deposits.len();
+
+        for (index, deposit) in deposits.iter().enumerate() {
+            // KuCoin has no deposit id and inner transfers carry no wallet tx
+            // id, so the key spells out every field that tells deposits apart.
+            // wallet_tx_id is NULL for those; the unique index treats NULLs as
+            // equal (NULLS NOT DISTINCT).
+            let label = deposit.wallet_tx_id.as_deref().unwrap_or("inner");
+            let mut tx = self
+                .pool
+                .begin()
+                .await
+                .context("Failed to begin deposit transaction")?;
+
+            let previous_status: Option<String> = sqlx::query_scalar(
+                r#"
+                SELECT status FROM deposit
+                WHERE exchange = $1 AND currency = $2
+                  AND wallet_tx_id IS NOT DISTINCT FROM $3
+                  AND address = $4 AND memo = $5 AND amount = $6 AND created_at = $7
+                FOR UPDATE
+                "#,
+            )
+            .bind(exchange)
+            .bind(&deposit.currency)
+            .bind(&deposit.wallet_tx_id)
+            .bind(&deposit.address)
+            .bind(&deposit.memo)
+            .bind(&deposit.amount)
+            .bind(deposit.created_at)
+            .fetch_optional(&mut *tx)
+            .await
+            .with_context(|| format!("Failed to read status of deposit '{}'", label))?;
+
+            sqlx::query(
+                r#"
+                INSERT INTO deposit (
+                    exchange, currency, chain, status, address, memo, is_inner,
+                    amount, fee, wallet_tx_id, remark, created_at, exchange_updated_at,
+                    updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+                ON CONFLICT (exchange, currency, wallet_tx_id, address, memo, amount, created_at)
+                DO UPDATE SET
+                    chain = EXCLUDED.chain,
+                    status = EXCLUDED.status,
+                    is_inner = EXCLUDED.is_inner,
+                    fee = EXCLUDED.fee,
+                    remark = EXCLUDED.remark,
+                    exchange_updated_at = EXCLUDED.exchange_updated_at,
+                    updated_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .bind(exchange)
+            .bind(&deposit.currency)
+            .bind(&deposit.chain)
+            .bind(&deposit.status)
+            .bind(&deposit.address)
+            .bind(&deposit.memo)
+            .bind(deposit.is_inner)
+            .bind(&deposit.amount)
+            .bind(&deposit.fee)
+            .bind(&deposit.wallet_tx_id)
+            .bind(&deposit.remark)
+            .bind(deposit.created_at)
+            .bind(deposit.updated_at)
+            .bind(now)
+            .execute(&mut *tx)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update deposit at index {} with wallet tx '{}'",
+                    index, label
+                )
+            })?;
+
+            if previous_status.as_deref() != Some(deposit.status.as_str()) {
+                sqlx::query(
+                    r#"
+                    INSERT INTO deposit_status_transition (
+                        exchange, currency, wallet_tx_id, address, memo, amount,
+                        created_at, from_status, to_status, changed_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                    "#,
+                )
+                .bind(exchange)
+                .bind(&deposit.currency)
+                .bind(&deposit.wallet_tx_id)
+                .bind(&deposit.address)
+                .bind(&deposit.memo)
+                .bind(&deposit.amount)
+                .bind(deposit.created_at)
+                .bind(&previous_status)
+                .bind(&deposit.status)
+                .bind(deposit.updated_at)
+                .execute(&mut *tx)
+                .await
+                .with_context(|| {
+                    format!("Failed to record status transition of deposit '{}'", label)
+                })?;
+            }
+
+            tx.commit()
+                .await
+                .context("Failed to commit deposit transaction")?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!("Progress: {}/{} deposits processed", index + 1, total);
+            }
+        }
+
+        info!(
+            "Successfully processed {} deposits for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use crate::domain::entities::event::Event;
+use crate::domain::repositories::event_repository::{EventReadRepository, EventWriteRepository};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresEventRepository {
+    pool: PgPool,
+}
+
+impl PostgresEventRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EventReadRepository for PostgresEventRepository {}
+
+#[async_trait]
+impl EventWriteRepository for PostgresEventRepository {
+    async fn save(&self, exchange: &str, events: &[Event]) -> Result<()> {
+        let now = chrono::Utc::now();
+
+        for event in events {
+            sqlx::query(
+                r#"
+                INSERT INTO event (exchange, kind, subject, message, payload, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                "#,
+            )
+            .bind(exchange)
+            .bind(&event.kind)
+            .bind(&event.subject)
+            .bind(&event.message)
+            .bind(&event.payload)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert '{}' event for '{}'",
+                    event.kind, event.subject
+                )
+            })?;
+        }
+
+        info!(
+            "Successfully recorded {} events for exchange '{}'",
+            events.len(),
+            exchange
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use crate::domain::entities::exchange_status::ExchangeStatus;
+use crate::domain::repositories::exchange_status_repository::{
+    ExchangeStatusReadRepository, ExchangeStatusWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresExchangeStatusRepository {
+    pool: PgPool,
+}
+
+impl PostgresExchangeStatusRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExchangeStatusReadRepository for PostgresExchangeStatusRepository {
+    async fn find_current(&self, exchange: &str) -> Result<Option<ExchangeStatus>> {
+        let row: Option<(String, String)> =
+            sqlx::query_as("SELECT status, message FROM exchange_status WHERE exchange = $1")
+                .bind(exchange)
+                .fetch_optional(&self.pool)
+                .await
+                .context("Failed to query current exchange status")?;
+
+        Ok(row.map(|(status, message)| ExchangeStatus::new(status, message)))
+    }
+}
+
+#[async_trait]
+impl ExchangeStatusWriteRepository for PostgresExchangeStatusRepository {
+    async fn save(&self, exchange: &str, status: &ExchangeStatus) -> Result<()> {
+        let now = chrono::Utc::now();
+
+        let previous_status: Option<String> =
+            sqlx::query_scalar("SELECT status FROM exchange_status WHERE exchange = $1")
+                .bind(exchange)
+                .fetch_optional(&self.pool)
+                .await
+                .with_context(|| format!("Failed to read status of exchange '{}'", exchange))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO exchange_status (exchange, status, message, updated_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (exchange)
+            DO UPDATE SET
+                status = EXCLUDED.status,
+                message = EXCLUDED.message,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(exchange)
+        .bind(&status.status)
+        .bind(&status.message)
+        .bind(now)
+        .execute(&self.pool)
+        .await
+        .with_context(|| format!("Failed to insert/update status of exchange '{}'", exchange))?;
+
+        if previous_status.as_deref() != Some(status.status.as_str()) {
+            sqlx::query(
+                r#"
+                INSERT INTO exchange_status_transition (
+                    exchange, from_status, to_status, message, changed_at
+                )
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+            )
+            .bind(exchange)
+            .bind(&previous_status)
+            .bind(&status.status)
+            .bind(&status.message)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to record status transition of exchange '{}'",
+                    exchange
+                )
+            })?;
+
+            info!(
+                "Exchange '{}' status changed from {:?} to '{}'",
+                exchange, previous_status, status.status
+            );
+        }
+
+        Ok(())
+    }
+}
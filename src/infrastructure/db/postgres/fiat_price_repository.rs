@@ -0,0 +1,112 @@
+use crate::domain::entities::fiat_price::FiatPrice;
+use crate::domain::repositories::fiat_price_repository::{
+    FiatPriceReadRepository, FiatPriceWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row, postgres::PgRow};
+use tracing::info;
+
+pub struct PostgresFiatPriceRepository {
+    pool: PgPool,
+}
+
+impl PostgresFiatPriceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn fiat_price_from_row(row: &PgRow) -> Result<FiatPrice> {
+    Ok(FiatPrice::new(
+        row.try_get("currency")?,
+        row.try_get("base")?,
+        row.try_get("price")?,
+        row.try_get("time_point")?,
+    ))
+}
+
+#[async_trait]
+impl FiatPriceReadRepository for PostgresFiatPriceRepository {
+    async fn find_latest(&self, exchange: &str) -> Result<Vec<FiatPrice>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT DISTINCT ON (currency, base)
+                currency, base, price, time_point
+            FROM fiat_price
+            WHERE exchange = $1
+            ORDER BY currency, base, time_point DESC
+            "#,
+        )
+        .bind(exchange)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load fiat prices for exchange '{}'", exchange))?;
+
+        rows.iter().map(fiat_price_from_row).collect()
+    }
+
+    async fn find_latest_at(&self, exchange: &str, at: DateTime<Utc>) -> Result<Vec<FiatPrice>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT DISTINCT ON (currency, base)
+                currency, base, price, time_point
+            FROM fiat_price
+            WHERE exchange = $1 AND time_point <= $2
+            ORDER BY currency, base, time_point DESC
+            "#,
+        )
+        .bind(exchange)
+        .bind(at)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load fiat prices as of {}", at))?;
+
+        rows.iter().map(fiat_price_from_row).collect()
+    }
+}
+
+#[async_trait]
+impl FiatPriceWriteRepository for PostgresFiatPriceRepository {
+    async fn save(&self, exchange: &str, prices: &[FiatPrice]) -> Result<()> {
+        let total = prices.len();
+
+        for (index, price) in prices.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO fiat_price (
+                    exchange, currency, base, time_point, price
+                )
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (exchange, currency, base, time_point)
+                DO UPDATE SET
+                    price = EXCLUDED.price
+                "#,
+            )
+            .bind(exchange)
+            .bind(&price.currency)
+            .bind(&price.base)
+            .bind(price.time_point)
+            .bind(&price.price)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update fiat price at index {} for currency '{}' in '{}'",
+                    index, price.currency, price.base
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!("Progress: {}/{} fiat prices processed", index + 1, total);
+            }
+        }
+
+        info!(
+            "Successfully processed {} fiat prices for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use crate::domain::entities::fill::{Fill, FillWithSymbol};
+use crate::domain::repositories::fill_repository::{FillReadRepository, FillWriteRepository};
+use crate::infrastructure::db::postgres::symbol_repository::prefixed_symbol_from_row;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row, postgres::PgRow};
+use tracing::info;
+
+pub struct PostgresFillRepository {
+    pool: PgPool,
+}
+
+impl PostgresFillRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn fill_from_row(row: &PgRow) -> Result<Fill> {
+    Ok(Fill::new(
+        row.try_get("trade_id")?,
+        row.try_get("symbol")?,
+        row.try_get("order_id")?,
+        row.try_get("counter_order_id")?,
+        row.try_get("side")?,
+        row.try_get("liquidity")?,
+        row.try_get("force_taker")?,
+        row.try_get("price")?,
+        row.try_get("size")?,
+        row.try_get("funds")?,
+        row.try_get("fee")?,
+        row.try_get("fee_rate")?,
+        row.try_get("fee_currency")?,
+        row.try_get("trade_type")?,
+        row.try_get("order_type")?,
+        row.try_get("created_at")?,
+    ))
+}
+
+#[async_trait]
+impl FillReadRepository for PostgresFillRepository {
+    async fn find_with_symbols(
+        &self,
+        exchange: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<FillWithSymbol>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                f.trade_id, f.symbol, f.order_id, f.counter_order_id, f.side, f.liquidity,
+                f.force_taker, f.price, f.size, f.funds, f.fee, f.fee_rate, f.fee_currency,
+                f.trade_type, f.order_type, f.created_at,
+                s.symbol AS s_symbol, s.symbol_name AS s_symbol_name,
+                s.base_currency AS s_base_currency, s.quote_currency AS s_quote_currency,
+                s.fee_currency AS s_fee_currency, s.market AS s_market,
+                s.base_min_size AS s_base_min_size, s.quote_min_size AS s_quote_min_size,
+                s.base_max_size AS s_base_max_size, s.quote_max_size AS s_quote_max_size,
+                s.base_increment AS s_base_increment, s.quote_increment AS s_quote_increment,
+                s.price_increment AS s_price_increment,
+                s.price_limit_rate AS s_price_limit_rate, s.min_funds AS s_min_funds,
+                s.is_margin_enabled AS s_is_margin_enabled,
+                s.enable_trading AS s_enable_trading, s.fee_category AS s_fee_category,
+                s.maker_fee_coefficient AS s_maker_fee_coefficient,
+                s.taker_fee_coefficient AS s_taker_fee_coefficient, s.st AS s_st
+            FROM fill f
+            LEFT JOIN symbol s ON s.exchange = f.exchange AND s.symbol = f.symbol
+            WHERE f.exchange = $1 AND f.created_at >= $2 AND f.created_at < $3
+            ORDER BY f.created_at, f.trade_id
+            "#,
+        )
+        .bind(exchange)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to load fills with symbols for exchange '{}'",
+                exchange
+            )
+        })?;
+
+        rows.iter()
+            .map(|row| {
+                let known: Option<String> = row.try_get("s_symbol")?;
+                Ok(FillWithSymbol {
+                    fill: fill_from_row(row)?,
+                    symbol: known
+                        .map(|_| prefixed_symbol_from_row(row, "s_"))
+                        .transpose()?,
+                })
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl FillWriteRepository for PostgresFillRepository {
+    async fn save(&self, exchange: &str, fills: &[Fill]) -> Result<()> {
+        let now = chrono::Utc::now();
+        let total = fills.len();
+
+        for (index, fill) in fills.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO fill (
+                    exchange, trade_id, symbol, order_id, counter_order_id, side,
+                    liquidity, force_taker, price, size, funds, fee, fee_rate,
+                    fee_currency, trade_type, order_type, created_at, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)
+                ON CONFLICT (exchange, symbol, trade_id)
+                DO UPDATE SET
+                    order_id = EXCLUDED.order_id,
+                    counter_order_id = EXCLUDED.counter_order_id,
+                    side = EXCLUDED.side,
+                    liquidity = EXCLUDED.liquidity,
+                    force_taker = EXCLUDED.force_taker,
+                    price = EXCLUDED.price,
+                    size = EXCLUDED.size,
+                    funds = EXCLUDED.funds,
+                    fee = EXCLUDED.fee,
+                    fee_rate = EXCLUDED.fee_rate,
+                    fee_currency = EXCLUDED.fee_currency,
+                    trade_type = EXCLUDED.trade_type,
+                    order_type = EXCLUDED.order_type,
+                    updated_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .bind(exchange)
+            .bind(&fill.trade_id)
+            .bind(&fill.symbol)
+            .bind(&fill.order_id)
+            .bind(&fill.counter_order_id)
+            .bind(&fill.side)
+            .bind(&fill.liquidity)
+            .bind(fill.force_taker)
+            .bind(&fill.price)
+            .bind(&fill.size)
+            .bind(&fill.funds)
+            .bind(&fill.fee)
+            .bind(&fill.fee_rate)
+            .bind(&fill.fee_currency)
+            .bind(&fill.trade_type)
+            .bind(&fill.order_type)
+            .bind(fill.created_at)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update fill at index {} with trade id '{}'",
+                    index, fill.trade_id
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!("Progress: {}/{} fills processed", index + 1, total);
+            }
+        }
+
+        info!(
+            "Successfully processed {} fills for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+}
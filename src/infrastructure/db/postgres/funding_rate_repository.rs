@@ -0,0 +1,96 @@
+use crate::domain::entities::futures::FundingRate;
+use crate::domain::repositories::funding_rate_repository::{
+    FundingRateReadRepository, FundingRateWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresFundingRateRepository {
+    pool: PgPool,
+}
+
+impl PostgresFundingRateRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl FundingRateReadRepository for PostgresFundingRateRepository {
+    async fn find_abs_percentile(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        percentile: f64,
+    ) -> Result<(Option<f64>, i64)> {
+        sqlx::query_as(
+            r#"
+            SELECT
+                percentile_cont($3) WITHIN GROUP (ORDER BY ABS(funding_rate)),
+                COUNT(*)
+            FROM futures_funding_rate
+            WHERE exchange = $1 AND symbol = $2
+            "#,
+        )
+        .bind(exchange)
+        .bind(symbol)
+        .bind(percentile)
+        .fetch_one(&self.pool)
+        .await
+        .with_context(|| format!("Failed to compute funding rate percentile for '{}'", symbol))
+    }
+}
+
+#[async_trait]
+impl FundingRateWriteRepository for PostgresFundingRateRepository {
+    async fn save(&self, exchange: &str, rates: &[FundingRate]) -> Result<()> {
+        let now = chrono::Utc::now();
+        let total = rates.len();
+
+        for (index, rate) in rates.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO futures_funding_rate (
+                    exchange, symbol, time_point, funding_rate, predicted_funding_rate,
+                    updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (exchange, symbol, time_point)
+                DO UPDATE SET
+                    funding_rate = EXCLUDED.funding_rate,
+                    predicted_funding_rate = COALESCE(
+                        EXCLUDED.predicted_funding_rate,
+                        futures_funding_rate.predicted_funding_rate
+                    ),
+                    updated_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .bind(exchange)
+            .bind(&rate.symbol)
+            .bind(rate.time_point)
+            .bind(rate.funding_rate)
+            .bind(rate.predicted_funding_rate)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update funding rate at index {} with symbol '{}'",
+                    index, rate.symbol
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!("Progress: {}/{} funding rates processed", index + 1, total);
+            }
+        }
+
+        info!(
+            "Successfully processed {} funding rates for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use crate::domain::entities::futures::FuturesContract;
+use crate::domain::repositories::futures_contract_repository::{
+    FuturesContractReadRepository, FuturesContractWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresFuturesContractRepository {
+    pool: PgPool,
+}
+
+impl PostgresFuturesContractRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl FuturesContractReadRepository for PostgresFuturesContractRepository {
+    async fn find_open_symbols(&self, exchange: &str) -> Result<Vec<String>> {
+        sqlx::query_scalar(
+            "SELECT symbol FROM futures_contract WHERE exchange = $1 AND status = 'Open' ORDER BY symbol",
+        )
+        .bind(exchange)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load open contracts for exchange '{}'", exchange))
+    }
+}
+
+#[async_trait]
+impl FuturesContractWriteRepository for PostgresFuturesContractRepository {
+    async fn save(&self, exchange: &str, contracts: &[FuturesContract]) -> Result<()> {
+        let now = chrono::Utc::now();
+        let total = contracts.len();
+
+        for (index, contract) in contracts.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO futures_contract (
+                    exchange, symbol, root_symbol, contract_type, base_currency,
+                    quote_currency, settle_currency, status, multiplier, lot_size,
+                    tick_size, max_order_qty, max_price, max_leverage, initial_margin,
+                    maintain_margin, maker_fee_rate, taker_fee_rate, is_inverse, is_quanto,
+                    funding_rate_granularity, first_open_date, expire_date, updated_at
+                )
+                VALUES (
+                    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16,
+                    $17, $18, $19, $20, $21, $22, $23, $24
+                )
+                ON CONFLICT (exchange, symbol)
+                DO UPDATE SET
+                    root_symbol = EXCLUDED.root_symbol,
+                    contract_type = EXCLUDED.contract_type,
+                    base_currency = EXCLUDED.base_currency,
+                    quote_currency = EXCLUDED.quote_currency,
+                    settle_currency = EXCLUDED.settle_currency,
+                    status = EXCLUDED.status,
+                    multiplier = EXCLUDED.multiplier,
+                    lot_size = EXCLUDED.lot_size,
+                    tick_size = EXCLUDED.tick_size,
+                    max_order_qty = EXCLUDED.max_order_qty,
+                    max_price = EXCLUDED.max_price,
+                    max_leverage = EXCLUDED.max_leverage,
+                    initial_margin = EXCLUDED.initial_margin,
+                    maintain_margin = EXCLUDED.maintain_margin,
+                    maker_fee_rate = EXCLUDED.maker_fee_rate,
+                    taker_fee_rate = EXCLUDED.taker_fee_rate,
+                    is_inverse = EXCLUDED.is_inverse,
+                    is_quanto = EXCLUDED.is_quanto,
+                    funding_rate_granularity = EXCLUDED.funding_rate_granularity,
+                    first_open_date = EXCLUDED.first_open_date,
+                    expire_date = EXCLUDED.expire_date,
+                    updated_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .bind(exchange)
+            .bind(&contract.symbol)
+            .bind(&contract.root_symbol)
+            .bind(&contract.contract_type)
+            .bind(&contract.base_currency)
+            .bind(&contract.quote_currency)
+            .bind(&contract.settle_currency)
+            .bind(&contract.status)
+            .bind(contract.multiplier)
+            .bind(contract.lot_size)
+            .bind(contract.tick_size)
+            .bind(contract.max_order_qty)
+            .bind(contract.max_price)
+            .bind(contract.max_leverage)
+            .bind(contract.initial_margin)
+            .bind(contract.maintain_margin)
+            .bind(contract.maker_fee_rate)
+            .bind(contract.taker_fee_rate)
+            .bind(contract.is_inverse)
+            .bind(contract.is_quanto)
+            .bind(contract.funding_rate_granularity)
+            .bind(contract.first_open_date)
+            .bind(contract.expire_date)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update futures contract at index {} with symbol '{}'",
+                    index, contract.symbol
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!(
+                    "Progress: {}/{} futures contracts processed",
+                    index + 1,
+                    total
+                );
+            }
+        }
+
+        info!(
+            "Successfully processed {} futures contracts for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+}
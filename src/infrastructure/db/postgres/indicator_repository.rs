@@ -0,0 +1,134 @@
+use crate::domain::entities::indicator::IndicatorValue;
+use crate::domain::repositories::indicator_repository::{
+    IndicatorReadRepository, IndicatorWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+use tracing::info;
+
+pub struct PostgresIndicatorRepository {
+    pool: PgPool,
+}
+
+impl PostgresIndicatorRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IndicatorReadRepository for PostgresIndicatorRepository {
+    async fn find_recent(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        interval: &str,
+        indicator: &str,
+        limit: i64,
+    ) -> Result<Vec<IndicatorValue>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT symbol, interval, indicator, open_time, value
+            FROM indicator_value
+            WHERE exchange = $1 AND symbol = $2 AND interval = $3
+              AND (indicator = $4 OR indicator LIKE $4 || '.%')
+            ORDER BY open_time DESC, indicator
+            LIMIT $5
+            "#,
+        )
+        .bind(exchange)
+        .bind(symbol)
+        .bind(interval)
+        .bind(indicator)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load indicator '{}' for '{}'", indicator, symbol))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(IndicatorValue::new(
+                    row.try_get("symbol")?,
+                    row.try_get("interval")?,
+                    row.try_get("indicator")?,
+                    row.try_get("open_time")?,
+                    row.try_get("value")?,
+                ))
+            })
+            .collect()
+    }
+
+    async fn find_latest_open_time(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        interval: &str,
+    ) -> Result<Option<DateTime<Utc>>> {
+        let open_time = sqlx::query_scalar(
+            r#"
+            SELECT MAX(open_time)
+            FROM indicator_value
+            WHERE exchange = $1 AND symbol = $2 AND interval = $3
+            "#,
+        )
+        .bind(exchange)
+        .bind(symbol)
+        .bind(interval)
+        .fetch_one(&self.pool)
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to load latest {} indicator time for '{}'",
+                interval, symbol
+            )
+        })?;
+
+        Ok(open_time)
+    }
+}
+
+#[async_trait]
+impl IndicatorWriteRepository for PostgresIndicatorRepository {
+    async fn save(&self, exchange: &str, values: &[IndicatorValue]) -> Result<()> {
+        let total = values.len();
+
+        for (index, value) in values.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO indicator_value (
+                    exchange, symbol, interval, indicator, open_time, value
+                )
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (exchange, symbol, interval, indicator, open_time)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&value.symbol)
+            .bind(&value.interval)
+            .bind(&value.indicator)
+            .bind(value.open_time)
+            .bind(value.value)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert indicator value at index {} for '{}'",
+                    index, value.indicator
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!(
+                    "Progress: {}/{} indicator values processed",
+                    index + 1,
+                    total
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use crate::domain::entities::job_run::JobRun;
+use crate::domain::repositories::job_run_repository::{
+    JobRunReadRepository, JobRunWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+pub struct PostgresJobRunRepository {
+    pool: PgPool,
+}
+
+impl PostgresJobRunRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl JobRunReadRepository for PostgresJobRunRepository {}
+
+#[async_trait]
+impl JobRunWriteRepository for PostgresJobRunRepository {
+    async fn save(&self, exchange: &str, run: &JobRun) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO job_run (
+                exchange, job_name, started_at, finished_at, success, error,
+                exchange_status, during_maintenance
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(exchange)
+        .bind(&run.job_name)
+        .bind(run.started_at)
+        .bind(run.finished_at)
+        .bind(run.success)
+        .bind(&run.error)
+        .bind(&run.exchange_status)
+        .bind(run.during_maintenance)
+        .execute(&self.pool)
+        .await
+        .with_context(|| format!("Failed to insert run of job '{}'", run.job_name))?;
+
+        Ok(())
+    }
+}
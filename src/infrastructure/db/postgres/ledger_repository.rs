@@ -0,0 +1,110 @@
+use crate::domain::entities::ledger_entry::{LedgerEntry, RebuiltBalance};
+use crate::domain::repositories::ledger_repository::{LedgerReadRepository, LedgerWriteRepository};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresLedgerRepository {
+    pool: PgPool,
+}
+
+impl PostgresLedgerRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LedgerReadRepository for PostgresLedgerRepository {
+    async fn find_rebuilt_balances(
+        &self,
+        exchange: &str,
+        as_of: DateTime<Utc>,
+    ) -> Result<Vec<RebuiltBalance>> {
+        let rows: Vec<(String, String, Decimal)> = sqlx::query_as(
+            r#"
+            WITH signed AS (
+                SELECT
+                    currency, account_type, created_at, ledger_id, balance,
+                    CASE WHEN direction = 'in' THEN amount ELSE -amount END AS delta
+                FROM ledger_entry
+                WHERE exchange = $1 AND created_at <= $2
+            )
+            SELECT
+                currency,
+                account_type,
+                (ARRAY_AGG(balance - delta ORDER BY created_at, ledger_id))[1] + SUM(delta)
+                    AS balance
+            FROM signed
+            GROUP BY currency, account_type
+            "#,
+        )
+        .bind(exchange)
+        .bind(as_of)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to rebuild balances from ledger")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(currency, account_type, balance)| {
+                RebuiltBalance::new(currency, account_type, balance)
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl LedgerWriteRepository for PostgresLedgerRepository {
+    async fn save(&self, exchange: &str, entries: &[LedgerEntry]) -> Result<()> {
+        let now = chrono::Utc::now();
+        let total = entries.len();
+
+        for (index, entry) in entries.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO ledger_entry (
+                    exchange, ledger_id, currency, amount, fee, balance, account_type,
+                    biz_type, direction, context, created_at, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                ON CONFLICT (exchange, account_type, ledger_id)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&entry.id)
+            .bind(&entry.currency)
+            .bind(entry.amount)
+            .bind(entry.fee)
+            .bind(entry.balance)
+            .bind(&entry.account_type)
+            .bind(&entry.biz_type)
+            .bind(&entry.direction)
+            .bind(&entry.context)
+            .bind(entry.created_at)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert ledger entry at index {} with id '{}'",
+                    index, entry.id
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!("Progress: {}/{} ledger entries processed", index + 1, total);
+            }
+        }
+
+        info!(
+            "Successfully processed {} ledger entries for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+}
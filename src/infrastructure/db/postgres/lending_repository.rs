@@ -0,0 +1,126 @@
+use crate::domain::entities::lending::{LendingInterestRate, LendingMarket};
+use crate::domain::repositories::lending_repository::{
+    LendingReadRepository, LendingWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresLendingRepository {
+    pool: PgPool,
+}
+
+impl PostgresLendingRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LendingReadRepository for PostgresLendingRepository {}
+
+#[async_trait]
+impl LendingWriteRepository for PostgresLendingRepository {
+    async fn save_markets(&self, exchange: &str, markets: &[LendingMarket]) -> Result<()> {
+        let now = chrono::Utc::now();
+        let total = markets.len();
+
+        for (index, market) in markets.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO lending_market (
+                    exchange, currency, purchase_enable, redeem_enable, auto_purchase_enable,
+                    increment, min_purchase_size, max_purchase_size, min_interest_rate,
+                    max_interest_rate, interest_increment, market_interest_rate, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+                ON CONFLICT (exchange, currency)
+                DO UPDATE SET
+                    purchase_enable = EXCLUDED.purchase_enable,
+                    redeem_enable = EXCLUDED.redeem_enable,
+                    auto_purchase_enable = EXCLUDED.auto_purchase_enable,
+                    increment = EXCLUDED.increment,
+                    min_purchase_size = EXCLUDED.min_purchase_size,
+                    max_purchase_size = EXCLUDED.max_purchase_size,
+                    min_interest_rate = EXCLUDED.min_interest_rate,
+                    max_interest_rate = EXCLUDED.max_interest_rate,
+                    interest_increment = EXCLUDED.interest_increment,
+                    market_interest_rate = EXCLUDED.market_interest_rate,
+                    updated_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .bind(exchange)
+            .bind(&market.currency)
+            .bind(market.purchase_enable)
+            .bind(market.redeem_enable)
+            .bind(market.auto_purchase_enable)
+            .bind(&market.increment)
+            .bind(&market.min_purchase_size)
+            .bind(&market.max_purchase_size)
+            .bind(&market.min_interest_rate)
+            .bind(&market.max_interest_rate)
+            .bind(&market.interest_increment)
+            .bind(&market.market_interest_rate)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update lending market at index {} with currency '{}'",
+                    index, market.currency
+                )
+            })?;
+        }
+
+        info!(
+            "Successfully processed {} lending markets for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+
+    async fn save_interest_rates(
+        &self,
+        exchange: &str,
+        rates: &[LendingInterestRate],
+    ) -> Result<()> {
+        let total = rates.len();
+
+        for (index, rate) in rates.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO lending_interest_rate (
+                    exchange, currency, rate_time, market_interest_rate
+                )
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (exchange, currency, rate_time)
+                DO UPDATE SET
+                    market_interest_rate = EXCLUDED.market_interest_rate
+                "#,
+            )
+            .bind(exchange)
+            .bind(&rate.currency)
+            .bind(rate.time)
+            .bind(&rate.market_interest_rate)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update interest rate at index {} for currency '{}'",
+                    index, rate.currency
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!("Progress: {}/{} interest rates processed", index + 1, total);
+            }
+        }
+
+        info!(
+            "Successfully processed {} interest rates for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+}
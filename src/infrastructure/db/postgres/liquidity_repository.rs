@@ -0,0 +1,101 @@
+use crate::domain::entities::liquidity::{LiquidityInputs, LiquidityScore};
+use crate::domain::repositories::liquidity_repository::{
+    LiquidityReadRepository, LiquidityWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use tracing::info;
+
+pub struct PostgresLiquidityRepository {
+    pool: PgPool,
+}
+
+impl PostgresLiquidityRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LiquidityReadRepository for PostgresLiquidityRepository {
+    async fn find_latest(&self, exchange: &str) -> Result<Vec<LiquidityScore>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                symbol, volume_usdt, spread, depth_usdt, trades_per_hour,
+                score, rank, computed_at
+            FROM liquidity_score
+            WHERE exchange = $1
+              AND computed_at = (
+                  SELECT MAX(computed_at) FROM liquidity_score WHERE exchange = $1
+              )
+            ORDER BY rank
+            "#,
+        )
+        .bind(exchange)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load liquidity scores for '{}'", exchange))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(LiquidityScore::new(
+                    LiquidityInputs::new(
+                        row.try_get("symbol")?,
+                        row.try_get("volume_usdt")?,
+                        row.try_get("spread")?,
+                        row.try_get("depth_usdt")?,
+                        row.try_get("trades_per_hour")?,
+                    ),
+                    row.try_get("score")?,
+                    row.try_get("rank")?,
+                    row.try_get("computed_at")?,
+                ))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl LiquidityWriteRepository for PostgresLiquidityRepository {
+    async fn save(&self, exchange: &str, scores: &[LiquidityScore]) -> Result<()> {
+        for (index, score) in scores.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO liquidity_score (
+                    exchange, symbol, computed_at, volume_usdt, spread, depth_usdt,
+                    trades_per_hour, score, rank
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                ON CONFLICT (exchange, symbol, computed_at)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&score.inputs.symbol)
+            .bind(score.computed_at)
+            .bind(score.inputs.volume_usdt)
+            .bind(score.inputs.spread)
+            .bind(score.inputs.depth_usdt)
+            .bind(score.inputs.trades_per_hour)
+            .bind(score.score)
+            .bind(score.rank)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert liquidity score at index {} for symbol '{}'",
+                    index, score.inputs.symbol
+                )
+            })?;
+        }
+
+        info!(
+            "Successfully processed {} liquidity scores for exchange '{}'",
+            scores.len(),
+            exchange
+        );
+        Ok(())
+    }
+}
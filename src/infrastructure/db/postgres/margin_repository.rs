@@ -0,0 +1,193 @@
+use crate::domain::entities::margin::{CrossMarginSymbol, IsolatedMarginSymbol, MarginConfig};
+use crate::domain::repositories::margin_repository::{MarginReadRepository, MarginWriteRepository};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresMarginRepository {
+    pool: PgPool,
+}
+
+impl PostgresMarginRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MarginReadRepository for PostgresMarginRepository {}
+
+#[async_trait]
+impl MarginWriteRepository for PostgresMarginRepository {
+    async fn save_cross_symbols(
+        &self,
+        exchange: &str,
+        symbols: &[CrossMarginSymbol],
+    ) -> Result<()> {
+        let now = chrono::Utc::now();
+        let total = symbols.len();
+
+        for (index, symbol) in symbols.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO cross_margin_symbol (
+                    exchange, symbol, symbol_name, base_currency, quote_currency,
+                    market, enable_trading, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ON CONFLICT (exchange, symbol)
+                DO UPDATE SET
+                    symbol_name = EXCLUDED.symbol_name,
+                    base_currency = EXCLUDED.base_currency,
+                    quote_currency = EXCLUDED.quote_currency,
+                    market = EXCLUDED.market,
+                    enable_trading = EXCLUDED.enable_trading,
+                    updated_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .bind(exchange)
+            .bind(&symbol.symbol)
+            .bind(&symbol.name)
+            .bind(&symbol.base_currency)
+            .bind(&symbol.quote_currency)
+            .bind(&symbol.market)
+            .bind(symbol.enable_trading)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update cross margin symbol at index {} with symbol '{}'",
+                    index, symbol.symbol
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!(
+                    "Progress: {}/{} cross margin symbols processed",
+                    index + 1,
+                    total
+                );
+            }
+        }
+
+        info!(
+            "Successfully processed {} cross margin symbols for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+
+    async fn save_isolated_symbols(
+        &self,
+        exchange: &str,
+        symbols: &[IsolatedMarginSymbol],
+    ) -> Result<()> {
+        let now = chrono::Utc::now();
+        let total = symbols.len();
+
+        for (index, symbol) in symbols.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO isolated_margin_symbol (
+                    exchange, symbol, symbol_name, base_currency, quote_currency,
+                    max_leverage, liquidation_debt_ratio, auto_renew_max_debt_ratio,
+                    trade_enable, base_borrow_enable, quote_borrow_enable,
+                    base_transfer_in_enable, quote_transfer_in_enable,
+                    base_borrow_coefficient, quote_borrow_coefficient, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+                ON CONFLICT (exchange, symbol)
+                DO UPDATE SET
+                    symbol_name = EXCLUDED.symbol_name,
+                    base_currency = EXCLUDED.base_currency,
+                    quote_currency = EXCLUDED.quote_currency,
+                    max_leverage = EXCLUDED.max_leverage,
+                    liquidation_debt_ratio = EXCLUDED.liquidation_debt_ratio,
+                    auto_renew_max_debt_ratio = EXCLUDED.auto_renew_max_debt_ratio,
+                    trade_enable = EXCLUDED.trade_enable,
+                    base_borrow_enable = EXCLUDED.base_borrow_enable,
+                    quote_borrow_enable = EXCLUDED.quote_borrow_enable,
+                    base_transfer_in_enable = EXCLUDED.base_transfer_in_enable,
+                    quote_transfer_in_enable = EXCLUDED.quote_transfer_in_enable,
+                    base_borrow_coefficient = EXCLUDED.base_borrow_coefficient,
+                    quote_borrow_coefficient = EXCLUDED.quote_borrow_coefficient,
+                    updated_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .bind(exchange)
+            .bind(&symbol.symbol)
+            .bind(&symbol.symbol_name)
+            .bind(&symbol.base_currency)
+            .bind(&symbol.quote_currency)
+            .bind(symbol.max_leverage)
+            .bind(&symbol.liquidation_debt_ratio)
+            .bind(&symbol.auto_renew_max_debt_ratio)
+            .bind(symbol.trade_enable)
+            .bind(symbol.base_borrow_enable)
+            .bind(symbol.quote_borrow_enable)
+            .bind(symbol.base_transfer_in_enable)
+            .bind(symbol.quote_transfer_in_enable)
+            .bind(&symbol.base_borrow_coefficient)
+            .bind(&symbol.quote_borrow_coefficient)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update isolated margin symbol at index {} with symbol '{}'",
+                    index, symbol.symbol
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!(
+                    "Progress: {}/{} isolated margin symbols processed",
+                    index + 1,
+                    total
+                );
+            }
+        }
+
+        info!(
+            "Successfully processed {} isolated margin symbols for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+
+    async fn save_config(&self, exchange: &str, config: &MarginConfig) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO margin_config (
+                exchange, currencies, max_leverage, warning_debt_ratio,
+                liquidation_debt_ratio, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (exchange)
+            DO UPDATE SET
+                currencies = EXCLUDED.currencies,
+                max_leverage = EXCLUDED.max_leverage,
+                warning_debt_ratio = EXCLUDED.warning_debt_ratio,
+                liquidation_debt_ratio = EXCLUDED.liquidation_debt_ratio,
+                updated_at = CURRENT_TIMESTAMP
+            "#,
+        )
+        .bind(exchange)
+        .bind(&config.currencies)
+        .bind(config.max_leverage)
+        .bind(&config.warning_debt_ratio)
+        .bind(&config.liquidation_debt_ratio)
+        .bind(chrono::Utc::now())
+        .execute(&self.pool)
+        .await
+        .context("Failed to insert/update margin config")?;
+
+        info!(
+            "Successfully processed margin config for exchange '{}'",
+            exchange
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::domain::entities::futures::MarkPrice;
+use crate::domain::repositories::mark_price_repository::{
+    MarkPriceReadRepository, MarkPriceWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresMarkPriceRepository {
+    pool: PgPool,
+}
+
+impl PostgresMarkPriceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MarkPriceReadRepository for PostgresMarkPriceRepository {}
+
+#[async_trait]
+impl MarkPriceWriteRepository for PostgresMarkPriceRepository {
+    async fn save(&self, exchange: &str, prices: &[MarkPrice]) -> Result<()> {
+        let total = prices.len();
+
+        for (index, price) in prices.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO futures_mark_price (
+                    exchange, symbol, time_point, mark_price, index_price
+                )
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (exchange, symbol, time_point)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&price.symbol)
+            .bind(price.time_point)
+            .bind(price.mark_price)
+            .bind(price.index_price)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert mark price at index {} with symbol '{}'",
+                    index, price.symbol
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!("Progress: {}/{} mark prices processed", index + 1, total);
+            }
+        }
+
+        info!(
+            "Successfully processed {} mark prices for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+}
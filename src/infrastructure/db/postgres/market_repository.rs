@@ -0,0 +1,79 @@
+use crate::domain::repositories::market_repository::{MarketReadRepository, MarketWriteRepository};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresMarketRepository {
+    pool: PgPool,
+}
+
+impl PostgresMarketRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MarketReadRepository for PostgresMarketRepository {
+    async fn find_active(&self, exchange: &str) -> Result<Vec<String>> {
+        sqlx::query_scalar(
+            "SELECT market FROM market WHERE exchange = $1 AND retired_at IS NULL ORDER BY market",
+        )
+        .bind(exchange)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load markets for exchange '{}'", exchange))
+    }
+}
+
+#[async_trait]
+impl MarketWriteRepository for PostgresMarketRepository {
+    async fn save(&self, exchange: &str, markets: &[String]) -> Result<()> {
+        let now = chrono::Utc::now();
+
+        for (index, market) in markets.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO market (exchange, market, first_seen_at, last_seen_at, retired_at)
+                VALUES ($1, $2, $3, $3, NULL)
+                ON CONFLICT (exchange, market)
+                DO UPDATE SET
+                    last_seen_at = EXCLUDED.last_seen_at,
+                    retired_at = NULL
+                "#,
+            )
+            .bind(exchange)
+            .bind(market)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update market at index {} with name '{}'",
+                    index, market
+                )
+            })?;
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE market SET retired_at = $3
+            WHERE exchange = $1 AND retired_at IS NULL AND NOT (market = ANY($2))
+            "#,
+        )
+        .bind(exchange)
+        .bind(markets)
+        .bind(now)
+        .execute(&self.pool)
+        .await
+        .with_context(|| format!("Failed to retire markets for exchange '{}'", exchange))?;
+
+        info!(
+            "Successfully processed {} markets for exchange '{}'",
+            markets.len(),
+            exchange
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,36 @@
+pub mod aggregated_candle_repository;
+pub mod announcement_repository;
+pub mod arbitrage_repository;
+pub mod balance_change_repository;
+pub mod balance_snapshot_repository;
+pub mod candle_repository;
 pub mod connection;
 pub mod currency_repository;
+pub mod deposit_repository;
+pub mod event_repository;
+pub mod exchange_status_repository;
+pub mod fiat_price_repository;
+pub mod fill_repository;
+pub mod funding_rate_repository;
+pub mod futures_contract_repository;
+pub mod indicator_repository;
+pub mod job_run_repository;
+pub mod ledger_repository;
+pub mod lending_repository;
+pub mod liquidity_repository;
+pub mod margin_repository;
+pub mod mark_price_repository;
+pub mod market_repository;
+pub mod open_interest_repository;
+pub mod order_book_repository;
+pub mod order_event_repository;
+pub mod order_repository;
+pub mod portfolio_repository;
+pub mod risk_metric_repository;
+pub mod sub_account_repository;
 pub mod symbol_repository;
+pub mod sync_cursor_repository;
 pub mod ticker_repository;
+pub mod trade_repository;
+pub mod watchlist_repository;
+pub mod withdrawal_repository;
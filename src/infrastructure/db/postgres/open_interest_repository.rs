@@ -0,0 +1,63 @@
+use crate::domain::entities::futures::OpenInterest;
+use crate::domain::repositories::open_interest_repository::{
+    OpenInterestReadRepository, OpenInterestWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresOpenInterestRepository {
+    pool: PgPool,
+}
+
+impl PostgresOpenInterestRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OpenInterestReadRepository for PostgresOpenInterestRepository {}
+
+#[async_trait]
+impl OpenInterestWriteRepository for PostgresOpenInterestRepository {
+    async fn save(&self, exchange: &str, open_interests: &[OpenInterest]) -> Result<()> {
+        let total = open_interests.len();
+
+        for (index, open_interest) in open_interests.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO futures_open_interest (
+                    exchange, symbol, time_point, open_interest
+                )
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (exchange, symbol, time_point)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&open_interest.symbol)
+            .bind(open_interest.time_point)
+            .bind(&open_interest.open_interest)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert open interest at index {} with symbol '{}'",
+                    index, open_interest.symbol
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!("Progress: {}/{} open interests processed", index + 1, total);
+            }
+        }
+
+        info!(
+            "Successfully processed {} open interests for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+}
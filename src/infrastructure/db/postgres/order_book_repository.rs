@@ -0,0 +1,67 @@
+use crate::domain::entities::order_book::{OrderBookDepth, PriceLevel};
+use crate::domain::repositories::order_book_repository::{
+    OrderBookReadRepository, OrderBookWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresOrderBookRepository {
+    pool: PgPool,
+}
+
+impl PostgresOrderBookRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OrderBookReadRepository for PostgresOrderBookRepository {}
+
+#[async_trait]
+impl OrderBookWriteRepository for PostgresOrderBookRepository {
+    async fn save(&self, exchange: &str, depths: &[OrderBookDepth]) -> Result<()> {
+        for (index, depth) in depths.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO order_book_snapshot (
+                    exchange, symbol, time_point, sequence, bids, asks
+                )
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (exchange, symbol, sequence)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&depth.symbol)
+            .bind(depth.time_point)
+            .bind(depth.sequence)
+            .bind(levels_json(&depth.bids))
+            .bind(levels_json(&depth.asks))
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert order book snapshot at index {} for symbol '{}'",
+                    index, depth.symbol
+                )
+            })?;
+        }
+
+        info!(
+            "Successfully processed {} order book snapshots for exchange '{}'",
+            depths.len(),
+            exchange
+        );
+        Ok(())
+    }
+}
+
+fn levels_json(levels: &[PriceLevel]) -> serde_json::Value {
+    levels
+        .iter()
+        .map(|l| serde_json::json!([l.price.to_string(), l.size.to_string()]))
+        .collect()
+}
@@ -0,0 +1,72 @@
+use crate::domain::entities::order_event::OrderEvent;
+use crate::domain::repositories::order_event_repository::{
+    OrderEventReadRepository, OrderEventWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+pub struct PostgresOrderEventRepository {
+    pool: PgPool,
+}
+
+impl PostgresOrderEventRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OrderEventReadRepository for PostgresOrderEventRepository {}
+
+#[async_trait]
+impl OrderEventWriteRepository for PostgresOrderEventRepository {
+    async fn save(&self, exchange: &str, events: &[OrderEvent]) -> Result<()> {
+        for (index, event) in events.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO order_event (
+                    exchange, order_id, client_oid, symbol, event_type, status, side,
+                    order_type, price, size, origin_size, filled_size, remain_size,
+                    canceled_size, match_price, match_size, trade_id, liquidity, event_time
+                )
+                VALUES (
+                    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10,
+                    $11, $12, $13, $14, $15, $16, $17, $18, $19
+                )
+                ON CONFLICT (exchange, order_id, event_type, event_time)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&event.order_id)
+            .bind(&event.client_oid)
+            .bind(&event.symbol)
+            .bind(&event.event_type)
+            .bind(&event.status)
+            .bind(&event.side)
+            .bind(&event.order_type)
+            .bind(&event.price)
+            .bind(&event.size)
+            .bind(&event.origin_size)
+            .bind(&event.filled_size)
+            .bind(&event.remain_size)
+            .bind(&event.canceled_size)
+            .bind(&event.match_price)
+            .bind(&event.match_size)
+            .bind(&event.trade_id)
+            .bind(&event.liquidity)
+            .bind(event.event_time)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert order event at index {} for order '{}'",
+                    index, event.order_id
+                )
+            })?;
+        }
+
+        Ok(())
+    }
+}
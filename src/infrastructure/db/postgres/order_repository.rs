@@ -0,0 +1,107 @@
+use crate::domain::entities::order::Order;
+use crate::domain::repositories::order_repository::{OrderReadRepository, OrderWriteRepository};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresOrderRepository {
+    pool: PgPool,
+}
+
+impl PostgresOrderRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OrderReadRepository for PostgresOrderRepository {}
+
+#[async_trait]
+impl OrderWriteRepository for PostgresOrderRepository {
+    async fn save(&self, exchange: &str, orders: &[Order]) -> Result<()> {
+        let now = chrono::Utc::now();
+        let total = orders.len();
+
+        for (index, order) in orders.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO spot_order (
+                    exchange, order_id, client_oid, symbol, op_type, order_type, side,
+                    price, size, funds, deal_size, deal_funds, fee, fee_currency,
+                    time_in_force, post_only, hidden, iceberg, cancel_exist, active,
+                    in_order_book, trade_type, remark, created_at, last_updated_at,
+                    updated_at
+                )
+                VALUES (
+                    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16,
+                    $17, $18, $19, $20, $21, $22, $23, $24, $25, $26
+                )
+                ON CONFLICT (exchange, order_id)
+                DO UPDATE SET
+                    client_oid = EXCLUDED.client_oid,
+                    op_type = EXCLUDED.op_type,
+                    price = EXCLUDED.price,
+                    size = EXCLUDED.size,
+                    funds = EXCLUDED.funds,
+                    deal_size = EXCLUDED.deal_size,
+                    deal_funds = EXCLUDED.deal_funds,
+                    fee = EXCLUDED.fee,
+                    fee_currency = EXCLUDED.fee_currency,
+                    cancel_exist = EXCLUDED.cancel_exist,
+                    active = EXCLUDED.active,
+                    in_order_book = EXCLUDED.in_order_book,
+                    remark = EXCLUDED.remark,
+                    last_updated_at = EXCLUDED.last_updated_at,
+                    updated_at = CURRENT_TIMESTAMP
+                WHERE spot_order.last_updated_at <= EXCLUDED.last_updated_at
+                "#,
+            )
+            .bind(exchange)
+            .bind(&order.id)
+            .bind(&order.client_oid)
+            .bind(&order.symbol)
+            .bind(&order.op_type)
+            .bind(&order.order_type)
+            .bind(&order.side)
+            .bind(&order.price)
+            .bind(&order.size)
+            .bind(&order.funds)
+            .bind(&order.deal_size)
+            .bind(&order.deal_funds)
+            .bind(&order.fee)
+            .bind(&order.fee_currency)
+            .bind(&order.time_in_force)
+            .bind(order.post_only)
+            .bind(order.hidden)
+            .bind(order.iceberg)
+            .bind(order.cancel_exist)
+            .bind(order.active)
+            .bind(order.in_order_book)
+            .bind(&order.trade_type)
+            .bind(&order.remark)
+            .bind(order.created_at)
+            .bind(order.last_updated_at)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update order at index {} with id '{}'",
+                    index, order.id
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!("Progress: {}/{} orders processed", index + 1, total);
+            }
+        }
+
+        info!(
+            "Successfully processed {} orders for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+}
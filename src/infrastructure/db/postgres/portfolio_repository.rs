@@ -0,0 +1,329 @@
+use crate::domain::entities::portfolio::{
+    AssetValuation, PortfolioBalance, PortfolioValuation, SymbolPnl,
+};
+use crate::domain::repositories::portfolio_repository::{
+    PortfolioReadRepository, PortfolioWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+use std::collections::HashMap;
+use tracing::info;
+
+pub struct PostgresPortfolioRepository {
+    pool: PgPool,
+}
+
+impl PostgresPortfolioRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PortfolioReadRepository for PostgresPortfolioRepository {
+    async fn find_valuations(
+        &self,
+        exchange: &str,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<PortfolioValuation>> {
+        let asset_rows = sqlx::query(
+            r#"
+            SELECT
+                account_id, account_type, snapshot_at, currency, balance,
+                price_usdt, value_usdt, value_usd
+            FROM portfolio_asset_valuation
+            WHERE exchange = $1 AND snapshot_at >= $2
+            ORDER BY currency
+            "#,
+        )
+        .bind(exchange)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to load portfolio asset valuations")?;
+
+        let mut assets: HashMap<(String, String, DateTime<Utc>), Vec<AssetValuation>> =
+            HashMap::new();
+        for row in &asset_rows {
+            let account_id: String = row.try_get("account_id")?;
+            let account_type: String = row.try_get("account_type")?;
+            assets
+                .entry((
+                    account_id.clone(),
+                    account_type.clone(),
+                    row.try_get("snapshot_at")?,
+                ))
+                .or_default()
+                .push(AssetValuation::new(
+                    account_id,
+                    account_type,
+                    row.try_get("currency")?,
+                    row.try_get("balance")?,
+                    row.try_get("price_usdt")?,
+                    row.try_get("value_usdt")?,
+                    row.try_get("value_usd")?,
+                ));
+        }
+
+        let rows = sqlx::query(
+            r#"
+            SELECT account_id, account_type, snapshot_at, value_usdt, value_usd
+            FROM portfolio_valuation
+            WHERE exchange = $1 AND snapshot_at >= $2
+            ORDER BY snapshot_at, account_type, account_id
+            "#,
+        )
+        .bind(exchange)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to load portfolio valuations")?;
+
+        rows.iter()
+            .map(|row| {
+                let account_id: String = row.try_get("account_id")?;
+                let account_type: String = row.try_get("account_type")?;
+                let snapshot_at: DateTime<Utc> = row.try_get("snapshot_at")?;
+                let breakdown = assets
+                    .remove(&(account_id.clone(), account_type.clone(), snapshot_at))
+                    .unwrap_or_default();
+                Ok(PortfolioValuation::new(
+                    account_id,
+                    account_type,
+                    snapshot_at,
+                    row.try_get("value_usdt")?,
+                    row.try_get("value_usd")?,
+                    breakdown,
+                ))
+            })
+            .collect()
+    }
+
+    async fn find_latest_valuation_at(&self, exchange: &str) -> Result<Option<DateTime<Utc>>> {
+        sqlx::query_scalar("SELECT MAX(snapshot_at) FROM portfolio_valuation WHERE exchange = $1")
+            .bind(exchange)
+            .fetch_one(&self.pool)
+            .await
+            .context("Failed to query latest portfolio valuation")
+    }
+
+    async fn find_pnl_before(
+        &self,
+        exchange: &str,
+        before: DateTime<Utc>,
+    ) -> Result<Option<(DateTime<Utc>, Vec<SymbolPnl>)>> {
+        let snapshot_at: Option<DateTime<Utc>> = sqlx::query_scalar(
+            r#"
+            SELECT MAX(snapshot_at)
+            FROM portfolio_pnl
+            WHERE exchange = $1 AND snapshot_at < $2
+            "#,
+        )
+        .bind(exchange)
+        .bind(before)
+        .fetch_one(&self.pool)
+        .await
+        .with_context(|| format!("Failed to query PnL before {}", before))?;
+
+        let Some(snapshot_at) = snapshot_at else {
+            return Ok(None);
+        };
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                symbol, quote_currency, position, average_cost, realized_pnl,
+                unrealized_pnl, realized_pnl_usdt, unrealized_pnl_usdt
+            FROM portfolio_pnl
+            WHERE exchange = $1 AND snapshot_at = $2
+            ORDER BY symbol
+            "#,
+        )
+        .bind(exchange)
+        .bind(snapshot_at)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load PnL at {}", snapshot_at))?;
+
+        let pnl = rows
+            .iter()
+            .map(|row| {
+                Ok(SymbolPnl {
+                    symbol: row.try_get("symbol")?,
+                    quote_currency: row.try_get("quote_currency")?,
+                    position: row.try_get("position")?,
+                    average_cost: row.try_get("average_cost")?,
+                    realized_pnl: row.try_get("realized_pnl")?,
+                    unrealized_pnl: row.try_get("unrealized_pnl")?,
+                    realized_pnl_usdt: row.try_get("realized_pnl_usdt")?,
+                    unrealized_pnl_usdt: row.try_get("unrealized_pnl_usdt")?,
+                })
+            })
+            .collect::<Result<Vec<SymbolPnl>>>()?;
+        Ok(Some((snapshot_at, pnl)))
+    }
+}
+
+#[async_trait]
+impl PortfolioWriteRepository for PostgresPortfolioRepository {
+    async fn save(
+        &self,
+        exchange: &str,
+        snapshot_at: DateTime<Utc>,
+        balances: &[PortfolioBalance],
+    ) -> Result<()> {
+        for (index, balance) in balances.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO portfolio_balance (
+                    exchange, currency, balance, available, holds, account_count, snapshot_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                ON CONFLICT (exchange, currency, snapshot_at)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&balance.currency)
+            .bind(&balance.balance)
+            .bind(&balance.available)
+            .bind(&balance.holds)
+            .bind(balance.account_count)
+            .bind(snapshot_at)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert portfolio balance at index {} for currency '{}'",
+                    index, balance.currency
+                )
+            })?;
+        }
+
+        info!(
+            "Successfully processed {} portfolio balances for exchange '{}'",
+            balances.len(),
+            exchange
+        );
+        Ok(())
+    }
+
+    async fn save_valuations(
+        &self,
+        exchange: &str,
+        valuations: &[PortfolioValuation],
+    ) -> Result<()> {
+        for valuation in valuations {
+            sqlx::query(
+                r#"
+                INSERT INTO portfolio_valuation (
+                    exchange, account_id, account_type, snapshot_at, value_usdt, value_usd
+                )
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (exchange, account_id, account_type, snapshot_at)
+                DO UPDATE SET
+                    value_usdt = EXCLUDED.value_usdt,
+                    value_usd = EXCLUDED.value_usd
+                "#,
+            )
+            .bind(exchange)
+            .bind(&valuation.account_id)
+            .bind(&valuation.account_type)
+            .bind(valuation.snapshot_at)
+            .bind(valuation.value_usdt)
+            .bind(valuation.value_usd)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update valuation for account '{}'",
+                    valuation.account_id
+                )
+            })?;
+
+            for asset in &valuation.assets {
+                sqlx::query(
+                    r#"
+                    INSERT INTO portfolio_asset_valuation (
+                        exchange, account_id, account_type, snapshot_at, currency,
+                        balance, price_usdt, value_usdt, value_usd
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                    ON CONFLICT (exchange, account_id, account_type, snapshot_at, currency)
+                    DO UPDATE SET
+                        balance = EXCLUDED.balance,
+                        price_usdt = EXCLUDED.price_usdt,
+                        value_usdt = EXCLUDED.value_usdt,
+                        value_usd = EXCLUDED.value_usd
+                    "#,
+                )
+                .bind(exchange)
+                .bind(&asset.account_id)
+                .bind(&asset.account_type)
+                .bind(valuation.snapshot_at)
+                .bind(&asset.currency)
+                .bind(asset.balance)
+                .bind(asset.price_usdt)
+                .bind(asset.value_usdt)
+                .bind(asset.value_usd)
+                .execute(&self.pool)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to insert/update asset valuation for '{}' in account '{}'",
+                        asset.currency, asset.account_id
+                    )
+                })?;
+            }
+        }
+
+        info!(
+            "Successfully processed {} portfolio valuations for exchange '{}'",
+            valuations.len(),
+            exchange
+        );
+        Ok(())
+    }
+
+    async fn save_pnl(
+        &self,
+        exchange: &str,
+        snapshot_at: DateTime<Utc>,
+        pnl: &[SymbolPnl],
+    ) -> Result<()> {
+        for entry in pnl {
+            sqlx::query(
+                r#"
+                INSERT INTO portfolio_pnl (
+                    exchange, symbol, snapshot_at, quote_currency, position, average_cost,
+                    realized_pnl, unrealized_pnl, realized_pnl_usdt, unrealized_pnl_usdt
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                ON CONFLICT (exchange, symbol, snapshot_at)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&entry.symbol)
+            .bind(snapshot_at)
+            .bind(&entry.quote_currency)
+            .bind(entry.position)
+            .bind(entry.average_cost)
+            .bind(entry.realized_pnl)
+            .bind(entry.unrealized_pnl)
+            .bind(entry.realized_pnl_usdt)
+            .bind(entry.unrealized_pnl_usdt)
+            .execute(&self.pool)
+            .await
+            .with_context(|| format!("Failed to insert PnL for symbol '{}'", entry.symbol))?;
+        }
+
+        info!(
+            "Successfully processed PnL for {} symbols for exchange '{}'",
+            pnl.len(),
+            exchange
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use crate::domain::entities::risk_metric::{CorrelationMetric, VolatilityMetric};
+use crate::domain::repositories::risk_metric_repository::{
+    RiskMetricReadRepository, RiskMetricWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use tracing::info;
+
+pub struct PostgresRiskMetricRepository {
+    pool: PgPool,
+}
+
+impl PostgresRiskMetricRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RiskMetricReadRepository for PostgresRiskMetricRepository {
+    async fn find_latest_volatility(
+        &self,
+        exchange: &str,
+        window_days: i32,
+    ) -> Result<Vec<VolatilityMetric>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT DISTINCT ON (symbol, interval)
+                symbol, interval, window_days, sample_count, realized_volatility, computed_at
+            FROM volatility_metric
+            WHERE exchange = $1 AND window_days = $2
+            ORDER BY symbol, interval, computed_at DESC
+            "#,
+        )
+        .bind(exchange)
+        .bind(window_days)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load {}-day volatility", window_days))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(VolatilityMetric::new(
+                    row.try_get("symbol")?,
+                    row.try_get("interval")?,
+                    row.try_get("window_days")?,
+                    row.try_get("sample_count")?,
+                    row.try_get("realized_volatility")?,
+                    row.try_get("computed_at")?,
+                ))
+            })
+            .collect()
+    }
+
+    async fn find_latest_correlations(
+        &self,
+        exchange: &str,
+        window_days: i32,
+    ) -> Result<Vec<CorrelationMetric>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT DISTINCT ON (symbol_a, symbol_b, interval)
+                symbol_a, symbol_b, interval, window_days, sample_count, correlation, computed_at
+            FROM correlation_metric
+            WHERE exchange = $1 AND window_days = $2
+            ORDER BY symbol_a, symbol_b, interval, computed_at DESC
+            "#,
+        )
+        .bind(exchange)
+        .bind(window_days)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load {}-day correlations", window_days))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(CorrelationMetric::new(
+                    row.try_get("symbol_a")?,
+                    row.try_get("symbol_b")?,
+                    row.try_get("interval")?,
+                    row.try_get("window_days")?,
+                    row.try_get("sample_count")?,
+                    row.try_get("correlation")?,
+                    row.try_get("computed_at")?,
+                ))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl RiskMetricWriteRepository for PostgresRiskMetricRepository {
+    async fn save_volatility(&self, exchange: &str, metrics: &[VolatilityMetric]) -> Result<()> {
+        for (index, metric) in metrics.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO volatility_metric (
+                    exchange, symbol, interval, window_days, sample_count,
+                    realized_volatility, computed_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                ON CONFLICT (exchange, symbol, interval, window_days, computed_at)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&metric.symbol)
+            .bind(&metric.interval)
+            .bind(metric.window_days)
+            .bind(metric.sample_count)
+            .bind(metric.realized_volatility)
+            .bind(metric.computed_at)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert volatility metric at index {} for symbol '{}'",
+                    index, metric.symbol
+                )
+            })?;
+        }
+
+        info!(
+            "Successfully processed {} volatility metrics for exchange '{}'",
+            metrics.len(),
+            exchange
+        );
+        Ok(())
+    }
+
+    async fn save_correlations(&self, exchange: &str, metrics: &[CorrelationMetric]) -> Result<()> {
+        for (index, metric) in metrics.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO correlation_metric (
+                    exchange, symbol_a, symbol_b, interval, window_days, sample_count,
+                    correlation, computed_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ON CONFLICT (exchange, symbol_a, symbol_b, interval, window_days, computed_at)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&metric.symbol_a)
+            .bind(&metric.symbol_b)
+            .bind(&metric.interval)
+            .bind(metric.window_days)
+            .bind(metric.sample_count)
+            .bind(metric.correlation)
+            .bind(metric.computed_at)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert correlation metric at index {} for '{}'/'{}'",
+                    index, metric.symbol_a, metric.symbol_b
+                )
+            })?;
+        }
+
+        info!(
+            "Successfully processed {} correlation metrics for exchange '{}'",
+            metrics.len(),
+            exchange
+        );
+        Ok(())
+    }
+}
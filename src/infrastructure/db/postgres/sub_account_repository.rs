@@ -0,0 +1,129 @@
+use crate::domain::entities::sub_account::{SubAccount, SubAccountBalance};
+use crate::domain::repositories::sub_account_repository::{
+    SubAccountReadRepository, SubAccountWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresSubAccountRepository {
+    pool: PgPool,
+}
+
+impl PostgresSubAccountRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SubAccountReadRepository for PostgresSubAccountRepository {}
+
+#[async_trait]
+impl SubAccountWriteRepository for PostgresSubAccountRepository {
+    async fn save_accounts(&self, exchange: &str, accounts: &[SubAccount]) -> Result<()> {
+        let now = chrono::Utc::now();
+
+        for (index, account) in accounts.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO sub_account (
+                    exchange, user_id, uid, sub_name, status, sub_type, access,
+                    remarks, created_at, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                ON CONFLICT (exchange, user_id)
+                DO UPDATE SET
+                    uid = EXCLUDED.uid,
+                    sub_name = EXCLUDED.sub_name,
+                    status = EXCLUDED.status,
+                    sub_type = EXCLUDED.sub_type,
+                    access = EXCLUDED.access,
+                    remarks = EXCLUDED.remarks,
+                    updated_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .bind(exchange)
+            .bind(&account.user_id)
+            .bind(account.uid)
+            .bind(&account.sub_name)
+            .bind(account.status)
+            .bind(account.sub_type)
+            .bind(&account.access)
+            .bind(&account.remarks)
+            .bind(account.created_at)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update sub-account at index {} with name '{}'",
+                    index, account.sub_name
+                )
+            })?;
+        }
+
+        info!(
+            "Successfully processed {} sub-accounts for exchange '{}'",
+            accounts.len(),
+            exchange
+        );
+        Ok(())
+    }
+
+    async fn save_balances(
+        &self,
+        exchange: &str,
+        snapshot_at: DateTime<Utc>,
+        balances: &[SubAccountBalance],
+    ) -> Result<()> {
+        let total = balances.len();
+
+        for (index, balance) in balances.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO sub_account_balance_snapshot (
+                    exchange, sub_user_id, sub_name, currency, account_type,
+                    balance, available, holds, snapshot_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                ON CONFLICT (exchange, sub_user_id, currency, account_type, snapshot_at)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&balance.sub_user_id)
+            .bind(&balance.sub_name)
+            .bind(&balance.currency)
+            .bind(&balance.account_type)
+            .bind(&balance.balance)
+            .bind(&balance.available)
+            .bind(&balance.holds)
+            .bind(snapshot_at)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert sub-account balance at index {} for '{}'",
+                    index, balance.sub_name
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!(
+                    "Progress: {}/{} sub-account balances processed",
+                    index + 1,
+                    total
+                );
+            }
+        }
+
+        info!(
+            "Successfully processed {} sub-account balances for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use crate::domain::entities::symbol::Symbol;
 use crate::domain::repositories::symbol_repository::{SymbolReadRepository, SymbolWriteRepository};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgPool, Row, postgres::PgRow};
 use tracing::info;
 
 pub struct PostgresSymbolRepository {
@@ -15,8 +15,106 @@ impl PostgresSymbolRepository {
     }
 }
 
+fn symbol_from_row(row: &PgRow) -> Result<Symbol> {
+    prefixed_symbol_from_row(row, "")
+}
+
+pub(crate) fn prefixed_symbol_from_row(row: &PgRow, prefix: &str) -> Result<Symbol> {
+    let column = |name: &str| format!("{}{}", prefix, name);
+    Ok(Symbol::new(
+        row.try_get(column("symbol").as_str())?,
+        row.try_get(column("symbol_name").as_str())?,
+        row.try_get(column("base_currency").as_str())?,
+        row.try_get(column("quote_currency").as_str())?,
+        row.try_get(column("fee_currency").as_str())?,
+        row.try_get(column("market").as_str())?,
+        row.try_get(column("base_min_size").as_str())?,
+        row.try_get(column("quote_min_size").as_str())?,
+        row.try_get(column("base_max_size").as_str())?,
+        row.try_get(column("quote_max_size").as_str())?,
+        row.try_get(column("base_increment").as_str())?,
+        row.try_get(column("quote_increment").as_str())?,
+        row.try_get(column("price_increment").as_str())?,
+        row.try_get(column("price_limit_rate").as_str())?,
+        row.try_get(column("min_funds").as_str())?,
+        row.try_get(column("is_margin_enabled").as_str())?,
+        row.try_get(column("enable_trading").as_str())?,
+        row.try_get(column("fee_category").as_str())?,
+        row.try_get(column("maker_fee_coefficient").as_str())?,
+        row.try_get(column("taker_fee_coefficient").as_str())?,
+        row.try_get(column("st").as_str())?,
+    ))
+}
+
 #[async_trait]
-impl SymbolReadRepository for PostgresSymbolRepository {}
+impl SymbolReadRepository for PostgresSymbolRepository {
+    async fn find_all(&self, exchange: &str) -> Result<Vec<Symbol>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                symbol, symbol_name, base_currency, quote_currency, fee_currency,
+                market, base_min_size, quote_min_size, base_max_size, quote_max_size,
+                base_increment, quote_increment, price_increment, price_limit_rate,
+                min_funds, is_margin_enabled, enable_trading, fee_category,
+                maker_fee_coefficient, taker_fee_coefficient, st
+            FROM symbol
+            WHERE exchange = $1
+            ORDER BY symbol
+            "#,
+        )
+        .bind(exchange)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load symbols for exchange '{}'", exchange))?;
+
+        rows.iter().map(symbol_from_row).collect()
+    }
+
+    async fn find_by_market(&self, exchange: &str, market: &str) -> Result<Vec<Symbol>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                symbol, symbol_name, base_currency, quote_currency, fee_currency,
+                market, base_min_size, quote_min_size, base_max_size, quote_max_size,
+                base_increment, quote_increment, price_increment, price_limit_rate,
+                min_funds, is_margin_enabled, enable_trading, fee_category,
+                maker_fee_coefficient, taker_fee_coefficient, st
+            FROM symbol
+            WHERE exchange = $1 AND market = $2
+            ORDER BY symbol
+            "#,
+        )
+        .bind(exchange)
+        .bind(market)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load symbols in market '{}'", market))?;
+
+        rows.iter().map(symbol_from_row).collect()
+    }
+
+    async fn find_by_symbol(&self, exchange: &str, symbol: &str) -> Result<Option<Symbol>> {
+        let row = sqlx::query(
+            r#"
+            SELECT
+                symbol, symbol_name, base_currency, quote_currency, fee_currency,
+                market, base_min_size, quote_min_size, base_max_size, quote_max_size,
+                base_increment, quote_increment, price_increment, price_limit_rate,
+                min_funds, is_margin_enabled, enable_trading, fee_category,
+                maker_fee_coefficient, taker_fee_coefficient, st
+            FROM symbol
+            WHERE exchange = $1 AND symbol = $2
+            "#,
+        )
+        .bind(exchange)
+        .bind(symbol)
+        .fetch_optional(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load symbol '{}'", symbol))?;
+
+        row.as_ref().map(symbol_from_row).transpose()
+    }
+}
 
 #[async_trait]
 impl SymbolWriteRepository for PostgresSymbolRepository {
@@ -67,20 +165,20 @@ impl SymbolWriteRepository for PostgresSymbolRepository {
             .bind(&symbol.quote_currency)
             .bind(&symbol.fee_currency)
             .bind(&symbol.market)
-            .bind(&symbol.base_min_size)
-            .bind(&symbol.quote_min_size)
-            .bind(&symbol.base_max_size)
-            .bind(&symbol.quote_max_size)
-            .bind(&symbol.base_increment)
-            .bind(&symbol.quote_increment)
-            .bind(&symbol.price_increment)
-            .bind(&symbol.price_limit_rate)
-            .bind(&symbol.min_funds)
+            .bind(symbol.base_min_size)
+            .bind(symbol.quote_min_size)
+            .bind(symbol.base_max_size)
+            .bind(symbol.quote_max_size)
+            .bind(symbol.base_increment)
+            .bind(symbol.quote_increment)
+            .bind(symbol.price_increment)
+            .bind(symbol.price_limit_rate)
+            .bind(symbol.min_funds)
             .bind(symbol.is_margin_enabled)
             .bind(symbol.enable_trading)
-            .bind(&symbol.fee_category)
-            .bind(&symbol.maker_fee_coefficient)
-            .bind(&symbol.taker_fee_coefficient)
+            .bind(symbol.fee_category)
+            .bind(symbol.maker_fee_coefficient)
+            .bind(symbol.taker_fee_coefficient)
             .bind(symbol.st)
             .bind(now)
             .execute(&self.pool)
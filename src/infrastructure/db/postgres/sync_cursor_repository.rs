@@ -0,0 +1,72 @@
+use crate::domain::repositories::sync_cursor_repository::{
+    SyncCursorReadRepository, SyncCursorWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+pub struct PostgresSyncCursorRepository {
+    pool: PgPool,
+}
+
+impl PostgresSyncCursorRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SyncCursorReadRepository for PostgresSyncCursorRepository {
+    async fn find(
+        &self,
+        exchange: &str,
+        dataset: &str,
+        key: &str,
+    ) -> Result<Option<DateTime<Utc>>> {
+        sqlx::query_scalar(
+            r#"
+            SELECT cursor FROM sync_cursor
+            WHERE exchange = $1 AND dataset = $2 AND cursor_key = $3
+            "#,
+        )
+        .bind(exchange)
+        .bind(dataset)
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await
+        .with_context(|| format!("Failed to read sync cursor '{}/{}'", dataset, key))
+    }
+}
+
+#[async_trait]
+impl SyncCursorWriteRepository for PostgresSyncCursorRepository {
+    async fn save(
+        &self,
+        exchange: &str,
+        dataset: &str,
+        key: &str,
+        cursor: DateTime<Utc>,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO sync_cursor (exchange, dataset, cursor_key, cursor, updated_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (exchange, dataset, cursor_key)
+            DO UPDATE SET
+                cursor = EXCLUDED.cursor,
+                updated_at = CURRENT_TIMESTAMP
+            "#,
+        )
+        .bind(exchange)
+        .bind(dataset)
+        .bind(key)
+        .bind(cursor)
+        .bind(chrono::Utc::now())
+        .execute(&self.pool)
+        .await
+        .with_context(|| format!("Failed to save sync cursor '{}/{}'", dataset, key))?;
+
+        Ok(())
+    }
+}
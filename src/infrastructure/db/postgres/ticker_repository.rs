@@ -1,8 +1,9 @@
-use crate::domain::entities::ticker::Ticker;
+use crate::domain::entities::ticker::{Ticker, TickerSnapshot};
 use crate::domain::repositories::ticker_repository::{TickerReadRepository, TickerWriteRepository};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row, postgres::PgRow};
 use tracing::info;
 
 pub struct PostgresTickerRepository {
@@ -15,8 +16,130 @@ impl PostgresTickerRepository {
     }
 }
 
+fn ticker_from_row(row: &PgRow) -> Result<Ticker> {
+    Ok(Ticker::new(
+        row.try_get("symbol")?,
+        row.try_get("symbol_name")?,
+        row.try_get("taker_fee_rate")?,
+        row.try_get("maker_fee_rate")?,
+        row.try_get("taker_coefficient")?,
+        row.try_get("maker_coefficient")?,
+        row.try_get("buy")?,
+        row.try_get("sell")?,
+        row.try_get("change_rate")?,
+        row.try_get("change_price")?,
+        row.try_get("high")?,
+        row.try_get("low")?,
+        row.try_get("vol")?,
+        row.try_get("vol_value")?,
+        row.try_get("last")?,
+        row.try_get("average_price")?,
+        row.try_get("time_point")?,
+    ))
+}
+
 #[async_trait]
-impl TickerReadRepository for PostgresTickerRepository {}
+impl TickerReadRepository for PostgresTickerRepository {
+    async fn find_all(&self, exchange: &str) -> Result<Vec<Ticker>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                symbol, symbol_name, taker_fee_rate, maker_fee_rate,
+                taker_coefficient, maker_coefficient, buy, sell, change_rate,
+                change_price, high, low, vol, vol_value, last, average_price, time_point
+            FROM ticker
+            WHERE exchange = $1
+            ORDER BY symbol
+            "#,
+        )
+        .bind(exchange)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load tickers for exchange '{}'", exchange))?;
+
+        rows.iter().map(ticker_from_row).collect()
+    }
+
+    async fn find_all_at(&self, exchange: &str, at: DateTime<Utc>) -> Result<Vec<Ticker>> {
+        // History rows carry no name or coefficients; those come from the
+        // current ticker.
+        let rows = sqlx::query(
+            r#"
+            SELECT DISTINCT ON (h.symbol)
+                h.symbol, t.symbol_name, h.taker_fee_rate, h.maker_fee_rate,
+                t.taker_coefficient, t.maker_coefficient, h.buy, h.sell, h.change_rate,
+                h.change_price, h.high, h.low, h.vol, h.vol_value, h.last, h.average_price,
+                h.time_point
+            FROM ticker_history h
+            JOIN ticker t ON t.exchange = h.exchange AND t.symbol = h.symbol
+            WHERE h.exchange = $1 AND h.time_point <= $2
+            ORDER BY h.symbol, h.time_point DESC
+            "#,
+        )
+        .bind(exchange)
+        .bind(at)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load tickers as of {}", at))?;
+
+        rows.iter().map(ticker_from_row).collect()
+    }
+
+    async fn find_latest(&self, exchange: &str, symbol: &str) -> Result<Option<Ticker>> {
+        let row = sqlx::query(
+            r#"
+            SELECT
+                symbol, symbol_name, taker_fee_rate, maker_fee_rate,
+                taker_coefficient, maker_coefficient, buy, sell, change_rate,
+                change_price, high, low, vol, vol_value, last, average_price, time_point
+            FROM ticker
+            WHERE exchange = $1 AND symbol = $2
+            "#,
+        )
+        .bind(exchange)
+        .bind(symbol)
+        .fetch_optional(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load ticker for symbol '{}'", symbol))?;
+
+        row.as_ref().map(ticker_from_row).transpose()
+    }
+
+    async fn find_history(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<TickerSnapshot>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT symbol, time_point, last
+            FROM ticker_history
+            WHERE exchange = $1 AND symbol = $2 AND time_point >= $3 AND time_point < $4
+              AND last IS NOT NULL
+            ORDER BY time_point
+            "#,
+        )
+        .bind(exchange)
+        .bind(symbol)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load ticker history for '{}'", symbol))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(TickerSnapshot::new(
+                    row.try_get("symbol")?,
+                    row.try_get("time_point")?,
+                    row.try_get("last")?,
+                ))
+            })
+            .collect()
+    }
+}
 
 #[async_trait]
 impl TickerWriteRepository for PostgresTickerRepository {
@@ -31,9 +154,11 @@ impl TickerWriteRepository for PostgresTickerRepository {
                     exchange, symbol, symbol_name, 
                     taker_fee_rate, maker_fee_rate, 
                     taker_coefficient, maker_coefficient, 
+                    buy, sell, change_rate, change_price, high, low,
+                    vol, vol_value, last, average_price, time_point,
                     updated_at
                 )
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
                 ON CONFLICT (exchange, symbol)
                 DO UPDATE SET
                     symbol_name = EXCLUDED.symbol_name,
@@ -41,16 +166,39 @@ impl TickerWriteRepository for PostgresTickerRepository {
                     maker_fee_rate = EXCLUDED.maker_fee_rate,
                     taker_coefficient = EXCLUDED.taker_coefficient,
                     maker_coefficient = EXCLUDED.maker_coefficient,
+                    buy = EXCLUDED.buy,
+                    sell = EXCLUDED.sell,
+                    change_rate = EXCLUDED.change_rate,
+                    change_price = EXCLUDED.change_price,
+                    high = EXCLUDED.high,
+                    low = EXCLUDED.low,
+                    vol = EXCLUDED.vol,
+                    vol_value = EXCLUDED.vol_value,
+                    last = EXCLUDED.last,
+                    average_price = EXCLUDED.average_price,
+                    time_point = EXCLUDED.time_point,
                     updated_at = CURRENT_TIMESTAMP
+                WHERE ticker.time_point IS NULL OR ticker.time_point <= EXCLUDED.time_point
                 "#,
             )
             .bind(exchange)
             .bind(&ticker.symbol)
             .bind(&ticker.symbol_name)
-            .bind(&ticker.taker_fee_rate)
-            .bind(&ticker.maker_fee_rate)
-            .bind(&ticker.taker_coefficient)
-            .bind(&ticker.maker_coefficient)
+            .bind(ticker.taker_fee_rate)
+            .bind(ticker.maker_fee_rate)
+            .bind(ticker.taker_coefficient)
+            .bind(ticker.maker_coefficient)
+            .bind(ticker.buy)
+            .bind(ticker.sell)
+            .bind(ticker.change_rate)
+            .bind(ticker.change_price)
+            .bind(ticker.high)
+            .bind(ticker.low)
+            .bind(ticker.vol)
+            .bind(ticker.vol_value)
+            .bind(ticker.last)
+            .bind(ticker.average_price)
+            .bind(ticker.time_point)
             .bind(now)
             .execute(&self.pool)
             .await
@@ -61,6 +209,42 @@ impl TickerWriteRepository for PostgresTickerRepository {
                 )
             })?;
 
+            sqlx::query(
+                r#"
+                INSERT INTO ticker_history (
+                    exchange, symbol, time_point, buy, sell, change_rate, change_price,
+                    high, low, vol, vol_value, last, average_price,
+                    taker_fee_rate, maker_fee_rate
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+                ON CONFLICT (exchange, symbol, time_point)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&ticker.symbol)
+            .bind(ticker.time_point)
+            .bind(ticker.buy)
+            .bind(ticker.sell)
+            .bind(ticker.change_rate)
+            .bind(ticker.change_price)
+            .bind(ticker.high)
+            .bind(ticker.low)
+            .bind(ticker.vol)
+            .bind(ticker.vol_value)
+            .bind(ticker.last)
+            .bind(ticker.average_price)
+            .bind(ticker.taker_fee_rate)
+            .bind(ticker.maker_fee_rate)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert ticker history at index {} with symbol '{}'",
+                    index, ticker.symbol
+                )
+            })?;
+
             if (index + 1) % 500 == 0 || index + 1 == total {
                 info!("Progress: {}/{} tickers processed", index + 1, total);
             }
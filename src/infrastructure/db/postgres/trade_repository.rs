@@ -0,0 +1,189 @@
+use crate::domain::entities::trade::{Trade, TradeGap};
+use crate::domain::repositories::trade_repository::{TradeReadRepository, TradeWriteRepository};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row, postgres::PgRow};
+use tracing::info;
+
+pub struct PostgresTradeRepository {
+    pool: PgPool,
+}
+
+impl PostgresTradeRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TradeReadRepository for PostgresTradeRepository {
+    async fn find_range(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Trade>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT symbol, sequence, price, size, side, time_point
+            FROM trade
+            WHERE exchange = $1 AND symbol = $2 AND time_point >= $3 AND time_point < $4
+            ORDER BY time_point, sequence
+            "#,
+        )
+        .bind(exchange)
+        .bind(symbol)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load trades for '{}'", symbol))?;
+
+        rows.iter().map(trade_from_row).collect()
+    }
+
+    async fn find_latest(&self, exchange: &str, symbol: &str) -> Result<Option<Trade>> {
+        let row = sqlx::query(
+            r#"
+            SELECT symbol, sequence, price, size, side, time_point
+            FROM trade
+            WHERE exchange = $1 AND symbol = $2
+            ORDER BY sequence DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(exchange)
+        .bind(symbol)
+        .fetch_optional(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load latest trade for '{}'", symbol))?;
+
+        row.as_ref().map(trade_from_row).transpose()
+    }
+
+    async fn find_gaps(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<TradeGap>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT symbol, last_sequence, next_sequence, gap_start, gap_end, detected_at
+            FROM trade_gap
+            WHERE exchange = $1 AND symbol = $2 AND gap_end >= $3 AND gap_start < $4
+            ORDER BY gap_start
+            "#,
+        )
+        .bind(exchange)
+        .bind(symbol)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load trade gaps for '{}'", symbol))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(TradeGap {
+                    symbol: row.try_get("symbol")?,
+                    last_sequence: row.try_get("last_sequence")?,
+                    next_sequence: row.try_get("next_sequence")?,
+                    start: row.try_get("gap_start")?,
+                    end: row.try_get("gap_end")?,
+                    detected_at: row.try_get("detected_at")?,
+                })
+            })
+            .collect()
+    }
+}
+
+fn trade_from_row(row: &PgRow) -> Result<Trade> {
+    Ok(Trade::new(
+        row.try_get("symbol")?,
+        row.try_get("sequence")?,
+        row.try_get("price")?,
+        row.try_get("size")?,
+        row.try_get("side")?,
+        row.try_get("time_point")?,
+    ))
+}
+
+#[async_trait]
+impl TradeWriteRepository for PostgresTradeRepository {
+    async fn save(&self, exchange: &str, trades: &[Trade]) -> Result<()> {
+        let total = trades.len();
+
+        for (index, trade) in trades.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO trade (
+                    exchange, symbol, sequence, price, size, side, time_point
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                ON CONFLICT (exchange, symbol, sequence)
+                DO NOTHING
+                "#,
+            )
+            .bind(exchange)
+            .bind(&trade.symbol)
+            .bind(trade.sequence)
+            .bind(trade.price)
+            .bind(trade.size)
+            .bind(&trade.side)
+            .bind(trade.time_point)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert trade at index {} for symbol '{}'",
+                    index, trade.symbol
+                )
+            })?;
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!("Progress: {}/{} trades processed", index + 1, total);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn save_gaps(&self, exchange: &str, gaps: &[TradeGap]) -> Result<()> {
+        for (index, gap) in gaps.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO trade_gap (
+                    exchange, symbol, last_sequence, next_sequence,
+                    gap_start, gap_end, detected_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                ON CONFLICT (exchange, symbol, last_sequence)
+                DO UPDATE SET
+                    next_sequence = LEAST(trade_gap.next_sequence, EXCLUDED.next_sequence),
+                    gap_end = LEAST(trade_gap.gap_end, EXCLUDED.gap_end)
+                "#,
+            )
+            .bind(exchange)
+            .bind(&gap.symbol)
+            .bind(gap.last_sequence)
+            .bind(gap.next_sequence)
+            .bind(gap.start)
+            .bind(gap.end)
+            .bind(gap.detected_at)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert trade gap at index {} for symbol '{}'",
+                    index, gap.symbol
+                )
+            })?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use crate::domain::repositories::watchlist_repository::{
+    WatchlistReadRepository, WatchlistWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresWatchlistRepository {
+    pool: PgPool,
+}
+
+impl PostgresWatchlistRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WatchlistReadRepository for PostgresWatchlistRepository {
+    async fn find_symbols(&self, exchange: &str, name: &str) -> Result<Vec<String>> {
+        sqlx::query_scalar(
+            r#"
+            SELECT symbol
+            FROM watchlist
+            WHERE exchange = $1 AND name = $2
+            ORDER BY position
+            "#,
+        )
+        .bind(exchange)
+        .bind(name)
+        .fetch_all(&self.pool)
+        .await
+        .with_context(|| format!("Failed to load watchlist '{}'", name))
+    }
+}
+
+#[async_trait]
+impl WatchlistWriteRepository for PostgresWatchlistRepository {
+    async fn replace(&self, exchange: &str, name: &str, symbols: &[String]) -> Result<()> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .context("Failed to begin watchlist transaction")?;
+
+        sqlx::query("DELETE FROM watchlist WHERE exchange = $1 AND name = $2")
+            .bind(exchange)
+            .bind(name)
+            .execute(&mut *tx)
+            .await
+            .with_context(|| format!("Failed to clear watchlist '{}'", name))?;
+
+        for (index, symbol) in symbols.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO watchlist (exchange, name, symbol, position, updated_at)
+                VALUES ($1, $2, $3, $4, CURRENT_TIMESTAMP)
+                "#,
+            )
+            .bind(exchange)
+            .bind(name)
+            .bind(symbol)
+            .bind(index as i32 + 1)
+            .execute(&mut *tx)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert symbol '{}' into watchlist '{}'",
+                    symbol, name
+                )
+            })?;
+        }
+
+        tx.commit()
+            .await
+            .context("Failed to commit watchlist transaction")?;
+
+        info!("Watchlist '{}' now holds {} symbols", name, symbols.len());
+        Ok(())
+    }
+}
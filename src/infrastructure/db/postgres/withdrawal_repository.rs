@@ -0,0 +1,140 @@
+use crate::domain::entities::withdrawal::Withdrawal;
+use crate::domain::repositories::withdrawal_repository::{
+    WithdrawalReadRepository, WithdrawalWriteRepository,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use tracing::info;
+
+pub struct PostgresWithdrawalRepository {
+    pool: PgPool,
+}
+
+impl PostgresWithdrawalRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WithdrawalReadRepository for PostgresWithdrawalRepository {
+    async fn find_sync_start(&self, exchange: &str) -> Result<Option<DateTime<Utc>>> {
+        sqlx::query_scalar(
+            r#"
+            SELECT COALESCE(
+                MIN(created_at) FILTER (WHERE status NOT IN ('SUCCESS', 'FAILURE')),
+                MAX(created_at)
+            )
+            FROM withdrawal
+            WHERE exchange = $1
+            "#,
+        )
+        .bind(exchange)
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to query withdrawal sync start")
+    }
+}
+
+#[async_trait]
+impl WithdrawalWriteRepository for PostgresWithdrawalRepository {
+    async fn save(&self, exchange: &str, withdrawals: &[Withdrawal]) -> Result<()> {
+        let now = chrono::Utc::now();
+        let total = withdrawals.len();
+
+        for (index, withdrawal) in withdrawals.iter().enumerate() {
+            let previous_status: Option<String> = sqlx::query_scalar(
+                "SELECT status FROM withdrawal WHERE exchange = $1 AND withdrawal_id = $2",
+            )
+            .bind(exchange)
+            .bind(&withdrawal.id)
+            .fetch_optional(&self.pool)
+            .await
+            .with_context(|| format!("Failed to read status of withdrawal '{}'", withdrawal.id))?;
+
+            sqlx::query(
+                r#"
+                INSERT INTO withdrawal (
+                    exchange, withdrawal_id, currency, chain, status, address, memo,
+                    is_inner, amount, fee, wallet_tx_id, remark, created_at,
+                    exchange_updated_at, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+                ON CONFLICT (exchange, withdrawal_id)
+                DO UPDATE SET
+                    currency = EXCLUDED.currency,
+                    chain = EXCLUDED.chain,
+                    status = EXCLUDED.status,
+                    address = EXCLUDED.address,
+                    memo = EXCLUDED.memo,
+                    is_inner = EXCLUDED.is_inner,
+                    amount = EXCLUDED.amount,
+                    fee = EXCLUDED.fee,
+                    wallet_tx_id = EXCLUDED.wallet_tx_id,
+                    remark = EXCLUDED.remark,
+                    exchange_updated_at = EXCLUDED.exchange_updated_at,
+                    updated_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .bind(exchange)
+            .bind(&withdrawal.id)
+            .bind(&withdrawal.currency)
+            .bind(&withdrawal.chain)
+            .bind(&withdrawal.status)
+            .bind(&withdrawal.address)
+            .bind(&withdrawal.memo)
+            .bind(withdrawal.is_inner)
+            .bind(&withdrawal.amount)
+            .bind(&withdrawal.fee)
+            .bind(&withdrawal.wallet_tx_id)
+            .bind(&withdrawal.remark)
+            .bind(withdrawal.created_at)
+            .bind(withdrawal.updated_at)
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to insert/update withdrawal at index {} with id '{}'",
+                    index, withdrawal.id
+                )
+            })?;
+
+            if previous_status.as_deref() != Some(withdrawal.status.as_str()) {
+                sqlx::query(
+                    r#"
+                    INSERT INTO withdrawal_status_transition (
+                        exchange, withdrawal_id, from_status, to_status, changed_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5)
+                    "#,
+                )
+                .bind(exchange)
+                .bind(&withdrawal.id)
+                .bind(&previous_status)
+                .bind(&withdrawal.status)
+                .bind(withdrawal.updated_at)
+                .execute(&self.pool)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to record status transition of withdrawal '{}'",
+                        withdrawal.id
+                    )
+                })?;
+            }
+
+            if (index + 1) % 500 == 0 || index + 1 == total {
+                info!("Progress: {}/{} withdrawals processed", index + 1, total);
+            }
+        }
+
+        info!(
+            "Successfully processed {} withdrawals for exchange '{}'",
+            total, exchange
+        );
+        Ok(())
+    }
+}
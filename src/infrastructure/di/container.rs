@@ -1,52 +1,426 @@
 use crate::application::factories::job_factory::JobFactory;
+use crate::application::services::account_service::{AccountService, AccountServiceImpl};
+use crate::application::services::analytics_service::{AnalyticsService, AnalyticsServiceImpl};
+use crate::application::services::announcement_service::{
+    AnnouncementService, AnnouncementServiceImpl,
+};
+use crate::application::services::candle_service::{CandleService, CandleServiceImpl};
+use crate::application::services::cross_rate_service::{CrossRateService, CrossRateServiceImpl};
+use crate::application::services::fee_service::{FeeService, FeeServiceImpl};
+use crate::application::services::futures_service::{FuturesService, FuturesServiceImpl};
+use crate::application::services::liquidity_service::{LiquidityService, LiquidityServiceImpl};
 use crate::application::services::monitoring_service::{MonitoringService, MonitoringServiceImpl};
+use crate::application::services::order_book_service::{OrderBookService, OrderBookServiceImpl};
+use crate::application::services::portfolio_service::{PortfolioService, PortfolioServiceImpl};
+use crate::application::services::private_feed_service::{
+    PrivateFeedService, PrivateFeedServiceImpl,
+};
+use crate::application::services::status_service::{StatusService, StatusServiceImpl};
+use crate::domain::repositories::aggregated_candle_repository::AggregatedCandleRepository;
+use crate::domain::repositories::announcement_repository::AnnouncementRepository;
+use crate::domain::repositories::arbitrage_repository::ArbitrageRepository;
+use crate::domain::repositories::balance_change_repository::BalanceChangeRepository;
+use crate::domain::repositories::balance_snapshot_repository::BalanceSnapshotRepository;
+use crate::domain::repositories::candle_repository::CandleRepository;
 use crate::domain::repositories::currency_repository::CurrencyRepository;
+use crate::domain::repositories::deposit_repository::DepositRepository;
+use crate::domain::repositories::event_repository::EventRepository;
+use crate::domain::repositories::exchange_status_repository::ExchangeStatusRepository;
+use crate::domain::repositories::fiat_price_repository::FiatPriceRepository;
+use crate::domain::repositories::fill_repository::FillRepository;
+use crate::domain::repositories::funding_rate_repository::FundingRateRepository;
+use crate::domain::repositories::futures_contract_repository::FuturesContractRepository;
+use crate::domain::repositories::indicator_repository::IndicatorRepository;
+use crate::domain::repositories::job_run_repository::JobRunRepository;
+use crate::domain::repositories::ledger_repository::LedgerRepository;
+use crate::domain::repositories::lending_repository::LendingRepository;
+use crate::domain::repositories::liquidity_repository::LiquidityRepository;
+use crate::domain::repositories::margin_repository::MarginRepository;
+use crate::domain::repositories::mark_price_repository::MarkPriceRepository;
+use crate::domain::repositories::market_repository::MarketRepository;
+use crate::domain::repositories::open_interest_repository::OpenInterestRepository;
+use crate::domain::repositories::order_book_repository::OrderBookRepository;
+use crate::domain::repositories::order_event_repository::OrderEventRepository;
+use crate::domain::repositories::order_repository::OrderRepository;
+use crate::domain::repositories::portfolio_repository::PortfolioRepository;
+use crate::domain::repositories::risk_metric_repository::RiskMetricRepository;
+use crate::domain::repositories::sub_account_repository::SubAccountRepository;
 use crate::domain::repositories::symbol_repository::SymbolRepository;
+use crate::domain::repositories::sync_cursor_repository::SyncCursorRepository;
 use crate::domain::repositories::ticker_repository::TickerRepository;
+use crate::domain::repositories::trade_repository::TradeRepository;
+use crate::domain::repositories::watchlist_repository::WatchlistRepository;
+use crate::domain::repositories::withdrawal_repository::WithdrawalRepository;
 use crate::infrastructure::api::api_client::ApiClient;
+use crate::infrastructure::api::futures_api_client::FuturesApiClient;
 use crate::infrastructure::api::kucoin_client::KuCoinClient;
+use crate::infrastructure::api::kucoin_futures_client::KuCoinFuturesClient;
 use crate::infrastructure::config::Config;
+use crate::infrastructure::db::postgres::aggregated_candle_repository::PostgresAggregatedCandleRepository;
+use crate::infrastructure::db::postgres::announcement_repository::PostgresAnnouncementRepository;
+use crate::infrastructure::db::postgres::arbitrage_repository::PostgresArbitrageRepository;
+use crate::infrastructure::db::postgres::balance_change_repository::PostgresBalanceChangeRepository;
+use crate::infrastructure::db::postgres::balance_snapshot_repository::PostgresBalanceSnapshotRepository;
+use crate::infrastructure::db::postgres::candle_repository::PostgresCandleRepository;
 use crate::infrastructure::db::postgres::currency_repository::PostgresCurrencyRepository;
+use crate::infrastructure::db::postgres::deposit_repository::PostgresDepositRepository;
+use crate::infrastructure::db::postgres::event_repository::PostgresEventRepository;
+use crate::infrastructure::db::postgres::exchange_status_repository::PostgresExchangeStatusRepository;
+use crate::infrastructure::db::postgres::fiat_price_repository::PostgresFiatPriceRepository;
+use crate::infrastructure::db::postgres::fill_repository::PostgresFillRepository;
+use crate::infrastructure::db::postgres::funding_rate_repository::PostgresFundingRateRepository;
+use crate::infrastructure::db::postgres::futures_contract_repository::PostgresFuturesContractRepository;
+use crate::infrastructure::db::postgres::indicator_repository::PostgresIndicatorRepository;
+use crate::infrastructure::db::postgres::job_run_repository::PostgresJobRunRepository;
+use crate::infrastructure::db::postgres::ledger_repository::PostgresLedgerRepository;
+use crate::infrastructure::db::postgres::lending_repository::PostgresLendingRepository;
+use crate::infrastructure::db::postgres::liquidity_repository::PostgresLiquidityRepository;
+use crate::infrastructure::db::postgres::margin_repository::PostgresMarginRepository;
+use crate::infrastructure::db::postgres::mark_price_repository::PostgresMarkPriceRepository;
+use crate::infrastructure::db::postgres::market_repository::PostgresMarketRepository;
+use crate::infrastructure::db::postgres::open_interest_repository::PostgresOpenInterestRepository;
+use crate::infrastructure::db::postgres::order_book_repository::PostgresOrderBookRepository;
+use crate::infrastructure::db::postgres::order_event_repository::PostgresOrderEventRepository;
+use crate::infrastructure::db::postgres::order_repository::PostgresOrderRepository;
+use crate::infrastructure::db::postgres::portfolio_repository::PostgresPortfolioRepository;
+use crate::infrastructure::db::postgres::risk_metric_repository::PostgresRiskMetricRepository;
+use crate::infrastructure::db::postgres::sub_account_repository::PostgresSubAccountRepository;
 use crate::infrastructure::db::postgres::symbol_repository::PostgresSymbolRepository;
+use crate::infrastructure::db::postgres::sync_cursor_repository::PostgresSyncCursorRepository;
 use crate::infrastructure::db::postgres::ticker_repository::PostgresTickerRepository;
+use crate::infrastructure::db::postgres::trade_repository::PostgresTradeRepository;
+use crate::infrastructure::db::postgres::watchlist_repository::PostgresWatchlistRepository;
+use crate::infrastructure::db::postgres::withdrawal_repository::PostgresWithdrawalRepository;
+use crate::infrastructure::http::query_api::QueryApiState;
+use crate::infrastructure::ws::kucoin_market_stream_client::KuCoinMarketStreamClient;
+use crate::infrastructure::ws::kucoin_private_stream_client::KuCoinPrivateStreamClient;
+use crate::infrastructure::ws::market_stream_client::MarketStreamClient;
+use crate::infrastructure::ws::private_stream_client::PrivateStreamClient;
 use anyhow::Result;
 use sqlx::PgPool;
 use std::sync::Arc;
 pub struct Container {
     pub config: Config,
     pub api_client: Arc<dyn ApiClient>,
+    pub futures_api_client: Arc<dyn FuturesApiClient>,
+    pub private_stream_client: Arc<dyn PrivateStreamClient>,
+    pub market_stream_client: Arc<dyn MarketStreamClient>,
     pub currency_repo: Arc<dyn CurrencyRepository>,
     pub symbol_repo: Arc<dyn SymbolRepository>,
+    pub market_repo: Arc<dyn MarketRepository>,
     pub ticker_repo: Arc<dyn TickerRepository>,
+    pub margin_repo: Arc<dyn MarginRepository>,
+    pub lending_repo: Arc<dyn LendingRepository>,
+    pub fiat_price_repo: Arc<dyn FiatPriceRepository>,
+    pub deposit_repo: Arc<dyn DepositRepository>,
+    pub withdrawal_repo: Arc<dyn WithdrawalRepository>,
+    pub fill_repo: Arc<dyn FillRepository>,
+    pub order_repo: Arc<dyn OrderRepository>,
+    pub sync_cursor_repo: Arc<dyn SyncCursorRepository>,
+    pub ledger_repo: Arc<dyn LedgerRepository>,
+    pub balance_snapshot_repo: Arc<dyn BalanceSnapshotRepository>,
+    pub event_repo: Arc<dyn EventRepository>,
+    pub futures_contract_repo: Arc<dyn FuturesContractRepository>,
+    pub funding_rate_repo: Arc<dyn FundingRateRepository>,
+    pub mark_price_repo: Arc<dyn MarkPriceRepository>,
+    pub open_interest_repo: Arc<dyn OpenInterestRepository>,
+    pub exchange_status_repo: Arc<dyn ExchangeStatusRepository>,
+    pub job_run_repo: Arc<dyn JobRunRepository>,
+    pub announcement_repo: Arc<dyn AnnouncementRepository>,
+    pub balance_change_repo: Arc<dyn BalanceChangeRepository>,
+    pub order_event_repo: Arc<dyn OrderEventRepository>,
+    pub order_book_repo: Arc<dyn OrderBookRepository>,
+    pub sub_account_repo: Arc<dyn SubAccountRepository>,
+    pub portfolio_repo: Arc<dyn PortfolioRepository>,
+    pub arbitrage_repo: Arc<dyn ArbitrageRepository>,
+    pub candle_repo: Arc<dyn CandleRepository>,
+    pub indicator_repo: Arc<dyn IndicatorRepository>,
+    pub risk_metric_repo: Arc<dyn RiskMetricRepository>,
+    pub trade_repo: Arc<dyn TradeRepository>,
+    pub liquidity_repo: Arc<dyn LiquidityRepository>,
+    pub watchlist_repo: Arc<dyn WatchlistRepository>,
+    pub aggregated_candle_repo: Arc<dyn AggregatedCandleRepository>,
     pub monitoring_service: Arc<dyn MonitoringService>,
+    pub account_service: Arc<dyn AccountService>,
+    pub futures_service: Arc<dyn FuturesService>,
+    pub announcement_service: Arc<dyn AnnouncementService>,
+    pub status_service: Arc<dyn StatusService>,
+    pub private_feed_service: Arc<dyn PrivateFeedService>,
+    pub order_book_service: Arc<dyn OrderBookService>,
+    pub portfolio_service: Arc<dyn PortfolioService>,
+    pub fee_service: Arc<dyn FeeService>,
+    pub cross_rate_service: Arc<dyn CrossRateService>,
+    pub analytics_service: Arc<dyn AnalyticsService>,
+    pub candle_service: Arc<dyn CandleService>,
+    pub liquidity_service: Arc<dyn LiquidityService>,
     pub job_factory: JobFactory,
+    pub query_api_state: QueryApiState,
 }
 
 impl Container {
     pub async fn build(config: Config, pool: PgPool) -> Result<Self> {
         let api_client = Arc::new(KuCoinClient::new(&config)?);
+        let futures_api_client = Arc::new(KuCoinFuturesClient::new(&config)?);
+        let private_stream_client = Arc::new(KuCoinPrivateStreamClient::new(api_client.clone()));
+        let market_stream_client = Arc::new(KuCoinMarketStreamClient::new(api_client.clone()));
 
         let currency_repo = Arc::new(PostgresCurrencyRepository::new(pool.clone()));
         let symbol_repo = Arc::new(PostgresSymbolRepository::new(pool.clone()));
+        let market_repo = Arc::new(PostgresMarketRepository::new(pool.clone()));
         let ticker_repo = Arc::new(PostgresTickerRepository::new(pool.clone()));
+        let margin_repo = Arc::new(PostgresMarginRepository::new(pool.clone()));
+        let lending_repo = Arc::new(PostgresLendingRepository::new(pool.clone()));
+        let fiat_price_repo = Arc::new(PostgresFiatPriceRepository::new(pool.clone()));
+        let deposit_repo = Arc::new(PostgresDepositRepository::new(pool.clone()));
+        let withdrawal_repo = Arc::new(PostgresWithdrawalRepository::new(pool.clone()));
+        let fill_repo = Arc::new(PostgresFillRepository::new(pool.clone()));
+        let order_repo = Arc::new(PostgresOrderRepository::new(pool.clone()));
+        let sync_cursor_repo = Arc::new(PostgresSyncCursorRepository::new(pool.clone()));
+        let ledger_repo = Arc::new(PostgresLedgerRepository::new(pool.clone()));
+        let balance_snapshot_repo = Arc::new(PostgresBalanceSnapshotRepository::new(pool.clone()));
+        let event_repo = Arc::new(PostgresEventRepository::new(pool.clone()));
+        let futures_contract_repo = Arc::new(PostgresFuturesContractRepository::new(pool.clone()));
+        let funding_rate_repo = Arc::new(PostgresFundingRateRepository::new(pool.clone()));
+        let mark_price_repo = Arc::new(PostgresMarkPriceRepository::new(pool.clone()));
+        let open_interest_repo = Arc::new(PostgresOpenInterestRepository::new(pool.clone()));
+        let exchange_status_repo = Arc::new(PostgresExchangeStatusRepository::new(pool.clone()));
+        let job_run_repo = Arc::new(PostgresJobRunRepository::new(pool.clone()));
+        let announcement_repo = Arc::new(PostgresAnnouncementRepository::new(pool.clone()));
+        let balance_change_repo = Arc::new(PostgresBalanceChangeRepository::new(pool.clone()));
+        let order_event_repo = Arc::new(PostgresOrderEventRepository::new(pool.clone()));
+        let order_book_repo = Arc::new(PostgresOrderBookRepository::new(pool.clone()));
+        let sub_account_repo = Arc::new(PostgresSubAccountRepository::new(pool.clone()));
+        let portfolio_repo = Arc::new(PostgresPortfolioRepository::new(pool.clone()));
+        let arbitrage_repo = Arc::new(PostgresArbitrageRepository::new(pool.clone()));
+        let candle_repo = Arc::new(PostgresCandleRepository::new(pool.clone()));
+        let indicator_repo = Arc::new(PostgresIndicatorRepository::new(pool.clone()));
+        let risk_metric_repo = Arc::new(PostgresRiskMetricRepository::new(pool.clone()));
+        let trade_repo = Arc::new(PostgresTradeRepository::new(pool.clone()));
+        let liquidity_repo = Arc::new(PostgresLiquidityRepository::new(pool.clone()));
+        let watchlist_repo = Arc::new(PostgresWatchlistRepository::new(pool.clone()));
+        let aggregated_candle_repo =
+            Arc::new(PostgresAggregatedCandleRepository::new(pool.clone()));
 
         let monitoring_service = Arc::new(MonitoringServiceImpl::new(
             api_client.clone(),
             currency_repo.clone(),
             symbol_repo.clone(),
+            market_repo.clone(),
+            event_repo.clone(),
             ticker_repo.clone(),
+            margin_repo.clone(),
+            lending_repo.clone(),
+            fiat_price_repo.clone(),
+            watchlist_repo.clone(),
+            config.fiat_bases.clone(),
+            config.watchlist_symbols.clone(),
         ));
 
-        let job_factory = JobFactory::new(monitoring_service.clone(), "kucoin".to_string());
+        let account_service = Arc::new(AccountServiceImpl::new(
+            api_client.clone(),
+            deposit_repo.clone(),
+            withdrawal_repo.clone(),
+            fill_repo.clone(),
+            order_repo.clone(),
+            symbol_repo.clone(),
+            sync_cursor_repo.clone(),
+            ledger_repo.clone(),
+            balance_snapshot_repo.clone(),
+            event_repo.clone(),
+            config.trading_symbols.clone(),
+        ));
+
+        let futures_service = Arc::new(FuturesServiceImpl::new(
+            futures_api_client.clone(),
+            futures_contract_repo.clone(),
+            funding_rate_repo.clone(),
+            mark_price_repo.clone(),
+            open_interest_repo.clone(),
+            sync_cursor_repo.clone(),
+            event_repo.clone(),
+            config.funding_rate_alert_percentile,
+        ));
+
+        let announcement_service = Arc::new(AnnouncementServiceImpl::new(
+            api_client.clone(),
+            announcement_repo.clone(),
+            symbol_repo.clone(),
+            currency_repo.clone(),
+            sync_cursor_repo.clone(),
+            config.announcement_types.clone(),
+        ));
+
+        let status_service = Arc::new(StatusServiceImpl::new(
+            api_client.clone(),
+            futures_api_client.clone(),
+            exchange_status_repo.clone(),
+            job_run_repo.clone(),
+        ));
+
+        let private_feed_service = Arc::new(PrivateFeedServiceImpl::new(
+            private_stream_client.clone(),
+            account_service.clone(),
+            balance_change_repo.clone(),
+            order_event_repo.clone(),
+        ));
+
+        let order_book_service = Arc::new(OrderBookServiceImpl::new(
+            api_client.clone(),
+            market_stream_client.clone(),
+            order_book_repo.clone(),
+            config.watchlist_symbols.clone(),
+        ));
+
+        let fee_service = Arc::new(FeeServiceImpl::new(
+            symbol_repo.clone(),
+            ticker_repo.clone(),
+        ));
+
+        let cross_rate_service = Arc::new(CrossRateServiceImpl::new(
+            symbol_repo.clone(),
+            ticker_repo.clone(),
+            fiat_price_repo.clone(),
+        ));
+
+        let mut candle_symbols = config.watchlist_symbols.clone();
+        for symbol in &config.analytics_basket {
+            if !candle_symbols.contains(symbol) {
+                candle_symbols.push(symbol.clone());
+            }
+        }
+        let candle_service = Arc::new(CandleServiceImpl::new(
+            api_client.clone(),
+            market_stream_client.clone(),
+            candle_repo.clone(),
+            indicator_repo.clone(),
+            trade_repo.clone(),
+            ticker_repo.clone(),
+            aggregated_candle_repo.clone(),
+            event_repo.clone(),
+            candle_symbols,
+            config.candle_intervals.clone(),
+            config.aggregated_candle_intervals.clone(),
+            config.indicators.clone(),
+        ));
+
+        let liquidity_service = Arc::new(LiquidityServiceImpl::new(
+            api_client.clone(),
+            cross_rate_service.clone(),
+            symbol_repo.clone(),
+            ticker_repo.clone(),
+            trade_repo.clone(),
+            liquidity_repo.clone(),
+            watchlist_repo.clone(),
+            config.liquidity_candidates,
+            config.liquid_universe_size,
+        ));
+
+        let portfolio_service = Arc::new(PortfolioServiceImpl::new(
+            api_client.clone(),
+            sub_account_repo.clone(),
+            portfolio_repo.clone(),
+            balance_snapshot_repo.clone(),
+            fill_repo.clone(),
+            symbol_repo.clone(),
+            cross_rate_service.clone(),
+        ));
+
+        let query_api_state = QueryApiState {
+            exchange: "kucoin".to_string(),
+            fee_service: fee_service.clone(),
+            cross_rate_service: cross_rate_service.clone(),
+            indicator_repo: indicator_repo.clone(),
+            risk_metric_repo: risk_metric_repo.clone(),
+            liquidity_repo: liquidity_repo.clone(),
+            watchlist_repo: watchlist_repo.clone(),
+            portfolio_repo: portfolio_repo.clone(),
+            symbol_repo: symbol_repo.clone(),
+        };
+
+        let analytics_service = Arc::new(AnalyticsServiceImpl::new(
+            symbol_repo.clone(),
+            ticker_repo.clone(),
+            arbitrage_repo.clone(),
+            config.arbitrage_min_net_edge,
+            candle_repo.clone(),
+            risk_metric_repo.clone(),
+            config.analytics_basket.clone(),
+            config.analytics_interval.clone(),
+            config.analytics_window_days.clone(),
+        ));
+
+        let job_factory = JobFactory::new(
+            monitoring_service.clone(),
+            account_service.clone(),
+            futures_service.clone(),
+            announcement_service.clone(),
+            status_service.clone(),
+            private_feed_service.clone(),
+            order_book_service.clone(),
+            portfolio_service.clone(),
+            analytics_service.clone(),
+            candle_service.clone(),
+            liquidity_service.clone(),
+            "kucoin".to_string(),
+            "kucoin-futures".to_string(),
+        );
 
         Ok(Self {
             config,
             api_client,
+            futures_api_client,
+            private_stream_client,
+            market_stream_client,
             currency_repo,
             symbol_repo,
+            market_repo,
             ticker_repo,
+            margin_repo,
+            lending_repo,
+            fiat_price_repo,
+            deposit_repo,
+            withdrawal_repo,
+            fill_repo,
+            order_repo,
+            sync_cursor_repo,
+            ledger_repo,
+            balance_snapshot_repo,
+            event_repo,
+            futures_contract_repo,
+            funding_rate_repo,
+            mark_price_repo,
+            open_interest_repo,
+            exchange_status_repo,
+            job_run_repo,
+            announcement_repo,
+            balance_change_repo,
+            order_event_repo,
+            order_book_repo,
+            sub_account_repo,
+            portfolio_repo,
+            arbitrage_repo,
+            candle_repo,
+            indicator_repo,
+            risk_metric_repo,
+            trade_repo,
+            liquidity_repo,
+            watchlist_repo,
+            aggregated_candle_repo,
             monitoring_service,
+            account_service,
+            futures_service,
+            announcement_service,
+            status_service,
+            private_feed_service,
+            order_book_service,
+            portfolio_service,
+            fee_service,
+            cross_rate_service,
+            analytics_service,
+            candle_service,
+            liquidity_service,
             job_factory,
+            query_api_state,
         })
     }
 }
@@ -0,0 +1,263 @@
+use crate::application::services::cross_rate_service::CrossRateService;
+use crate::application::services::fee_service::{FeeService, UnknownSymbol};
+use crate::domain::repositories::indicator_repository::IndicatorRepository;
+use crate::domain::repositories::liquidity_repository::LiquidityRepository;
+use crate::domain::repositories::portfolio_repository::PortfolioRepository;
+use crate::domain::repositories::risk_metric_repository::RiskMetricRepository;
+use crate::domain::repositories::symbol_repository::SymbolRepository;
+use crate::domain::repositories::watchlist_repository::WatchlistRepository;
+use crate::domain::services::cross_rate::PathPreference;
+use crate::domain::services::fee_calculator::{FeeOverflow, VipFeeRates};
+use anyhow::{Context, Result};
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[derive(Clone)]
+pub struct QueryApiState {
+    pub exchange: String,
+    pub fee_service: Arc<dyn FeeService>,
+    pub cross_rate_service: Arc<dyn CrossRateService>,
+    pub indicator_repo: Arc<dyn IndicatorRepository>,
+    pub risk_metric_repo: Arc<dyn RiskMetricRepository>,
+    pub liquidity_repo: Arc<dyn LiquidityRepository>,
+    pub watchlist_repo: Arc<dyn WatchlistRepository>,
+    pub portfolio_repo: Arc<dyn PortfolioRepository>,
+    pub symbol_repo: Arc<dyn SymbolRepository>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FeeQuery {
+    price: Decimal,
+    size: Decimal,
+    maker_fee_rate: Option<Decimal>,
+    taker_fee_rate: Option<Decimal>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RateQuery {
+    prefer: Option<PathPreference>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IndicatorQuery {
+    limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RiskQuery {
+    window_days: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SymbolQuery {
+    market: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ValuationQuery {
+    hours: Option<i64>,
+}
+
+struct ApiError(StatusCode, String);
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.0, Json(serde_json::json!({ "error": self.1 }))).into_response()
+    }
+}
+
+pub fn router(state: QueryApiState) -> Router {
+    Router::new()
+        .route("/symbols", get(get_symbols))
+        .route("/fees/{symbol}", get(get_fees))
+        .route("/rates/{from}/{to}", get(get_rate))
+        .route(
+            "/indicators/{symbol}/{interval}/{indicator}",
+            get(get_indicator),
+        )
+        .route("/volatility", get(get_volatility))
+        .route("/correlations", get(get_correlations))
+        .route("/liquidity", get(get_liquidity))
+        .route("/watchlists/{name}", get(get_watchlist))
+        .route("/portfolio/valuations", get(get_valuations))
+        .with_state(state)
+}
+
+pub async fn serve(addr: String, state: QueryApiState) {
+    if let Err(e) = run(&addr, state).await {
+        error!("Query API stopped: {:#}", e);
+    }
+}
+
+async fn run(addr: &str, state: QueryApiState) -> Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind query API on {}", addr))?;
+    info!("Query API listening on {}", addr);
+    axum::serve(listener, router(state))
+        .await
+        .context("Query API server error")
+}
+
+async fn get_symbols(
+    State(state): State<QueryApiState>,
+    Query(query): Query<SymbolQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let symbols = match &query.market {
+        Some(market) => {
+            state
+                .symbol_repo
+                .find_by_market(&state.exchange, market)
+                .await
+        }
+        None => state.symbol_repo.find_all(&state.exchange).await,
+    };
+
+    symbols
+        .map(Json)
+        .map_err(|e| ApiError(StatusCode::INTERNAL_SERVER_ERROR, format!("{:#}", e)))
+}
+
+async fn get_fees(
+    State(state): State<QueryApiState>,
+    Path(symbol): Path<String>,
+    Query(query): Query<FeeQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let vip = match (query.maker_fee_rate, query.taker_fee_rate) {
+        (Some(maker_fee_rate), Some(taker_fee_rate)) => Some(VipFeeRates {
+            maker_fee_rate,
+            taker_fee_rate,
+        }),
+        (None, None) => None,
+        _ => {
+            return Err(ApiError(
+                StatusCode::BAD_REQUEST,
+                "maker_fee_rate and taker_fee_rate must be given together".to_string(),
+            ));
+        }
+    };
+
+    state
+        .fee_service
+        .estimate(&state.exchange, &symbol, query.price, query.size, vip)
+        .await
+        .map(Json)
+        .map_err(|e| {
+            let status = if e.downcast_ref::<UnknownSymbol>().is_some() {
+                StatusCode::NOT_FOUND
+            } else if e.downcast_ref::<FeeOverflow>().is_some() {
+                StatusCode::BAD_REQUEST
+            } else {
+                StatusCode::INTERNAL_SERVER_ERROR
+            };
+            ApiError(status, format!("{:#}", e))
+        })
+}
+
+async fn get_rate(
+    State(state): State<QueryApiState>,
+    Path((from, to)): Path<(String, String)>,
+    Query(query): Query<RateQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let preference = query.prefer.unwrap_or(PathPreference::MostLiquid);
+
+    match state
+        .cross_rate_service
+        .convert(&state.exchange, &from, &to, preference)
+        .await
+    {
+        Ok(Some(path)) => Ok(Json(path)),
+        Ok(None) => Err(ApiError(
+            StatusCode::NOT_FOUND,
+            format!("No conversion path from {} to {}", from, to),
+        )),
+        Err(e) => Err(ApiError(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("{:#}", e),
+        )),
+    }
+}
+
+async fn get_indicator(
+    State(state): State<QueryApiState>,
+    Path((symbol, interval, indicator)): Path<(String, String, String)>,
+    Query(query): Query<IndicatorQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let limit = query.limit.unwrap_or(100).clamp(1, 1000);
+
+    state
+        .indicator_repo
+        .find_recent(&state.exchange, &symbol, &interval, &indicator, limit)
+        .await
+        .map(Json)
+        .map_err(|e| ApiError(StatusCode::INTERNAL_SERVER_ERROR, format!("{:#}", e)))
+}
+
+async fn get_volatility(
+    State(state): State<QueryApiState>,
+    Query(query): Query<RiskQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    state
+        .risk_metric_repo
+        .find_latest_volatility(&state.exchange, query.window_days.unwrap_or(30))
+        .await
+        .map(Json)
+        .map_err(|e| ApiError(StatusCode::INTERNAL_SERVER_ERROR, format!("{:#}", e)))
+}
+
+async fn get_correlations(
+    State(state): State<QueryApiState>,
+    Query(query): Query<RiskQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    state
+        .risk_metric_repo
+        .find_latest_correlations(&state.exchange, query.window_days.unwrap_or(30))
+        .await
+        .map(Json)
+        .map_err(|e| ApiError(StatusCode::INTERNAL_SERVER_ERROR, format!("{:#}", e)))
+}
+
+async fn get_liquidity(State(state): State<QueryApiState>) -> Result<impl IntoResponse, ApiError> {
+    state
+        .liquidity_repo
+        .find_latest(&state.exchange)
+        .await
+        .map(Json)
+        .map_err(|e| ApiError(StatusCode::INTERNAL_SERVER_ERROR, format!("{:#}", e)))
+}
+
+async fn get_watchlist(
+    State(state): State<QueryApiState>,
+    Path(name): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    state
+        .watchlist_repo
+        .find_symbols(&state.exchange, &name)
+        .await
+        .map(Json)
+        .map_err(|e| ApiError(StatusCode::INTERNAL_SERVER_ERROR, format!("{:#}", e)))
+}
+
+async fn get_valuations(
+    State(state): State<QueryApiState>,
+    Query(query): Query<ValuationQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let hours = query.hours.unwrap_or(24).clamp(1, 24 * 90);
+    let since = chrono::Utc::now() - chrono::TimeDelta::hours(hours);
+
+    state
+        .portfolio_repo
+        .find_valuations(&state.exchange, since)
+        .await
+        .map(Json)
+        .map_err(|e| ApiError(StatusCode::INTERNAL_SERVER_ERROR, format!("{:#}", e)))
+}
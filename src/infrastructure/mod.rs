@@ -2,4 +2,6 @@ pub mod api;
 pub mod config;
 pub mod db;
 pub mod di;
+pub mod http;
 pub mod logging;
+pub mod ws;
@@ -0,0 +1,157 @@
+use crate::domain::entities::order_book::{LevelChange, OrderBookUpdate};
+use crate::domain::entities::trade::Trade;
+use crate::infrastructure::api::api_client::ApiClient;
+use crate::infrastructure::api::kucoin_client::{from_timestamp_ms, parse_decimal};
+use crate::infrastructure::ws::kucoin_ws::KuCoinWsConnection;
+use crate::infrastructure::ws::market_stream_client::{
+    Level2Stream, MarketStreamClient, TradeStream,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::DateTime;
+use std::sync::Arc;
+
+const LEVEL2_TOPIC: &str = "/market/level2";
+const MATCH_TOPIC: &str = "/market/match";
+const SYMBOLS_PER_TOPIC: usize = 100;
+
+#[derive(Debug, serde::Deserialize)]
+struct Level2Ws {
+    pub symbol: String,
+    #[serde(rename = "sequenceStart")]
+    pub sequence_start: i64,
+    #[serde(rename = "sequenceEnd")]
+    pub sequence_end: i64,
+    pub changes: Level2ChangesWs,
+    pub time: i64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Level2ChangesWs {
+    pub asks: Vec<[String; 3]>,
+    pub bids: Vec<[String; 3]>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct MatchWs {
+    pub symbol: String,
+    pub sequence: String,
+    pub side: String,
+    pub price: String,
+    pub size: String,
+    pub time: String,
+}
+
+pub struct KuCoinMarketStreamClient {
+    api_client: Arc<dyn ApiClient>,
+}
+
+impl KuCoinMarketStreamClient {
+    pub fn new(api_client: Arc<dyn ApiClient>) -> Self {
+        Self { api_client }
+    }
+
+    async fn connect(&self, topic: &str, symbols: &[String]) -> Result<KuCoinWsConnection> {
+        let token = self.api_client.fetch_ws_token(false).await?;
+        let mut connection = KuCoinWsConnection::connect(&token).await?;
+        for chunk in symbols.chunks(SYMBOLS_PER_TOPIC) {
+            let topic = format!("{}:{}", topic, chunk.join(","));
+            connection.subscribe(&topic, false).await?;
+        }
+        Ok(connection)
+    }
+}
+
+#[async_trait]
+impl MarketStreamClient for KuCoinMarketStreamClient {
+    async fn connect_level2(&self, symbols: &[String]) -> Result<Box<dyn Level2Stream>> {
+        let connection = self.connect(LEVEL2_TOPIC, symbols).await?;
+        Ok(Box::new(KuCoinLevel2Stream { connection }))
+    }
+
+    async fn connect_trades(&self, symbols: &[String]) -> Result<Box<dyn TradeStream>> {
+        let connection = self.connect(MATCH_TOPIC, symbols).await?;
+        Ok(Box::new(KuCoinTradeStream { connection }))
+    }
+}
+
+struct KuCoinLevel2Stream {
+    connection: KuCoinWsConnection,
+}
+
+#[async_trait]
+impl Level2Stream for KuCoinLevel2Stream {
+    async fn next_update(&mut self) -> Result<OrderBookUpdate> {
+        loop {
+            let message = self.connection.next_message().await?;
+            let (Some(topic), Some(data)) = (message.topic, message.data) else {
+                continue;
+            };
+            if !topic.starts_with(LEVEL2_TOPIC) {
+                continue;
+            }
+
+            let l: Level2Ws =
+                serde_json::from_value(data).context("Failed to deserialize level2 message")?;
+            return Ok(OrderBookUpdate::new(
+                l.symbol,
+                l.sequence_start,
+                l.sequence_end,
+                level_changes(&l.changes.bids)?,
+                level_changes(&l.changes.asks)?,
+                from_timestamp_ms(l.time)?,
+            ));
+        }
+    }
+}
+
+struct KuCoinTradeStream {
+    connection: KuCoinWsConnection,
+}
+
+#[async_trait]
+impl TradeStream for KuCoinTradeStream {
+    async fn next_trade(&mut self) -> Result<Trade> {
+        loop {
+            let message = self.connection.next_message().await?;
+            let (Some(topic), Some(data)) = (message.topic, message.data) else {
+                continue;
+            };
+            if !topic.starts_with(MATCH_TOPIC) {
+                continue;
+            }
+
+            let m: MatchWs =
+                serde_json::from_value(data).context("Failed to deserialize match message")?;
+            let time: i64 = m
+                .time
+                .parse()
+                .with_context(|| format!("Invalid match time: {}", m.time))?;
+            return Ok(Trade::new(
+                m.symbol,
+                m.sequence
+                    .parse()
+                    .with_context(|| format!("Invalid match sequence: {}", m.sequence))?,
+                parse_decimal(&m.price)?,
+                parse_decimal(&m.size)?,
+                m.side,
+                DateTime::from_timestamp_nanos(time),
+            ));
+        }
+    }
+}
+
+fn level_changes(changes: &[[String; 3]]) -> Result<Vec<LevelChange>> {
+    changes
+        .iter()
+        .map(|[price, size, sequence]| {
+            Ok(LevelChange::new(
+                parse_decimal(price)?,
+                parse_decimal(size)?,
+                sequence
+                    .parse()
+                    .with_context(|| format!("Invalid level2 sequence: {}", sequence))?,
+            ))
+        })
+        .collect()
+}
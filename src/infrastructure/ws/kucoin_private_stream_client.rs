@@ -0,0 +1,153 @@
+use crate::domain::entities::balance_change::BalanceChange;
+use crate::domain::entities::order_event::OrderEvent;
+use crate::infrastructure::api::api_client::ApiClient;
+use crate::infrastructure::api::kucoin_client::from_timestamp_ms;
+use crate::infrastructure::ws::kucoin_ws::KuCoinWsConnection;
+use crate::infrastructure::ws::private_stream_client::{
+    PrivateEvent, PrivateStream, PrivateStreamClient,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::DateTime;
+use std::sync::Arc;
+use tracing::warn;
+
+const BALANCE_TOPIC: &str = "/account/balance";
+const ORDERS_TOPIC: &str = "/spotMarket/tradeOrdersV2";
+
+#[derive(Debug, serde::Deserialize)]
+struct BalanceWs {
+    #[serde(rename = "accountId")]
+    pub account_id: String,
+    pub currency: String,
+    pub total: String,
+    pub available: String,
+    pub hold: String,
+    #[serde(rename = "availableChange")]
+    pub available_change: String,
+    #[serde(rename = "holdChange")]
+    pub hold_change: String,
+    #[serde(rename = "relationEvent")]
+    pub relation_event: String,
+    #[serde(rename = "relationEventId")]
+    pub relation_event_id: String,
+    pub time: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct OrderWs {
+    #[serde(rename = "orderId")]
+    pub order_id: String,
+    #[serde(rename = "clientOid")]
+    pub client_oid: Option<String>,
+    pub symbol: String,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub status: String,
+    pub side: String,
+    #[serde(rename = "orderType")]
+    pub order_type: String,
+    pub price: Option<String>,
+    pub size: Option<String>,
+    #[serde(rename = "originSize")]
+    pub origin_size: Option<String>,
+    #[serde(rename = "filledSize")]
+    pub filled_size: Option<String>,
+    #[serde(rename = "remainSize")]
+    pub remain_size: Option<String>,
+    #[serde(rename = "canceledSize")]
+    pub canceled_size: Option<String>,
+    #[serde(rename = "matchPrice")]
+    pub match_price: Option<String>,
+    #[serde(rename = "matchSize")]
+    pub match_size: Option<String>,
+    #[serde(rename = "tradeId")]
+    pub trade_id: Option<String>,
+    pub liquidity: Option<String>,
+    pub ts: i64,
+}
+
+pub struct KuCoinPrivateStreamClient {
+    api_client: Arc<dyn ApiClient>,
+}
+
+impl KuCoinPrivateStreamClient {
+    pub fn new(api_client: Arc<dyn ApiClient>) -> Self {
+        Self { api_client }
+    }
+}
+
+#[async_trait]
+impl PrivateStreamClient for KuCoinPrivateStreamClient {
+    async fn connect(&self) -> Result<Box<dyn PrivateStream>> {
+        let token = self.api_client.fetch_ws_token(true).await?;
+        let mut connection = KuCoinWsConnection::connect(&token).await?;
+        connection.subscribe(BALANCE_TOPIC, true).await?;
+        connection.subscribe(ORDERS_TOPIC, true).await?;
+        Ok(Box::new(KuCoinPrivateStream { connection }))
+    }
+}
+
+struct KuCoinPrivateStream {
+    connection: KuCoinWsConnection,
+}
+
+#[async_trait]
+impl PrivateStream for KuCoinPrivateStream {
+    async fn next_event(&mut self) -> Result<PrivateEvent> {
+        loop {
+            let message = self.connection.next_message().await?;
+            let (Some(topic), Some(data)) = (message.topic, message.data) else {
+                continue;
+            };
+
+            match topic.as_str() {
+                BALANCE_TOPIC => {
+                    let b: BalanceWs = serde_json::from_value(data)
+                        .context("Failed to deserialize balance message")?;
+                    let time = b
+                        .time
+                        .parse()
+                        .with_context(|| format!("Invalid balance time: {}", b.time))?;
+                    return Ok(PrivateEvent::Balance(BalanceChange::new(
+                        b.account_id,
+                        b.currency,
+                        b.total,
+                        b.available,
+                        b.hold,
+                        b.available_change,
+                        b.hold_change,
+                        b.relation_event,
+                        b.relation_event_id,
+                        from_timestamp_ms(time)?,
+                    )));
+                }
+                ORDERS_TOPIC => {
+                    let o: OrderWs = serde_json::from_value(data)
+                        .context("Failed to deserialize order message")?;
+                    return Ok(PrivateEvent::Order(OrderEvent::new(
+                        o.order_id,
+                        o.client_oid,
+                        o.symbol,
+                        o.event_type,
+                        o.status,
+                        o.side,
+                        o.order_type,
+                        o.price,
+                        o.size,
+                        o.origin_size,
+                        o.filled_size,
+                        o.remain_size,
+                        o.canceled_size,
+                        o.match_price,
+                        o.match_size,
+                        o.trade_id,
+                        o.liquidity,
+                        DateTime::from_timestamp_nanos(o.ts),
+                    )));
+                }
+                _ => warn!("Ignoring message on unexpected topic {}", topic),
+            }
+        }
+    }
+}
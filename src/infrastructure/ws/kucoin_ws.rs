@@ -0,0 +1,183 @@
+use anyhow::{Context, Result};
+use futures::{SinkExt, StreamExt};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::net::TcpStream;
+use tokio::time::{Instant, Interval, MissedTickBehavior, interval_at, sleep_until, timeout};
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async};
+
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone)]
+pub struct WsToken {
+    pub token: String,
+    pub endpoint: String,
+    pub ping_interval: Duration,
+    pub ping_timeout: Duration,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct WsMessage {
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub message_type: String,
+    pub topic: Option<String>,
+    pub data: Option<serde_json::Value>,
+    pub code: Option<serde_json::Value>,
+}
+
+pub struct KuCoinWsConnection {
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    ping_timer: Interval,
+    ping_timeout: Duration,
+    pong_deadline: Option<Instant>,
+}
+
+impl KuCoinWsConnection {
+    pub async fn connect(token: &WsToken) -> Result<Self> {
+        let url = format!(
+            "{}?token={}&connectId={}",
+            token.endpoint,
+            urlencoding::encode(&token.token),
+            next_id()
+        );
+        let (stream, _) = timeout(HANDSHAKE_TIMEOUT, connect_async(url.as_str()))
+            .await
+            .context("Timed out connecting to KuCoin WebSocket")?
+            .context("Failed to connect to KuCoin WebSocket")?;
+
+        let mut ping_timer = interval_at(Instant::now() + token.ping_interval, token.ping_interval);
+        ping_timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let mut connection = Self {
+            stream,
+            ping_timer,
+            ping_timeout: token.ping_timeout,
+            pong_deadline: None,
+        };
+
+        let welcome = timeout(HANDSHAKE_TIMEOUT, connection.read_message())
+            .await
+            .context("Timed out waiting for KuCoin WebSocket welcome")??;
+        if welcome.message_type != "welcome" {
+            anyhow::bail!(
+                "Unexpected KuCoin WebSocket greeting: {}",
+                welcome.message_type
+            );
+        }
+
+        Ok(connection)
+    }
+
+    pub async fn subscribe(&mut self, topic: &str, private_channel: bool) -> Result<()> {
+        let id = next_id();
+        let request = serde_json::json!({
+            "id": id,
+            "type": "subscribe",
+            "topic": topic,
+            "privateChannel": private_channel,
+            "response": true,
+        });
+        self.send(request).await?;
+
+        let ack = timeout(HANDSHAKE_TIMEOUT, async {
+            loop {
+                let message = self.read_message().await?;
+                match message.message_type.as_str() {
+                    "ack" if message.id.as_deref() == Some(id.as_str()) => return Ok(message),
+                    "error" => anyhow::bail!(
+                        "KuCoin WebSocket rejected subscription to {}: {:?}",
+                        topic,
+                        message.data
+                    ),
+                    _ => continue,
+                }
+            }
+        })
+        .await
+        .with_context(|| format!("Timed out subscribing to {}", topic))?;
+
+        ack.map(|_| ())
+    }
+
+    // KuCoin expects a client ping every pingInterval regardless of inbound
+    // traffic; the connection is only considered dead when nothing at all
+    // arrives within pingTimeout of a ping.
+    pub async fn next_message(&mut self) -> Result<WsMessage> {
+        loop {
+            let pong_deadline = self.pong_deadline;
+            tokio::select! {
+                _ = self.ping_timer.tick() => {
+                    self.send(serde_json::json!({ "id": next_id(), "type": "ping" }))
+                        .await?;
+                    if self.pong_deadline.is_none() {
+                        self.pong_deadline = Some(Instant::now() + self.ping_timeout);
+                    }
+                }
+                _ = sleep_until(pong_deadline.unwrap_or_else(Instant::now)),
+                    if pong_deadline.is_some() =>
+                {
+                    anyhow::bail!("KuCoin WebSocket ping timeout");
+                }
+                message = read_message(&mut self.stream) => {
+                    let message = message?;
+                    self.pong_deadline = None;
+                    match message.message_type.as_str() {
+                        "message" => return Ok(message),
+                        "error" => anyhow::bail!(
+                            "KuCoin WebSocket error: code={:?}, data={:?}",
+                            message.code,
+                            message.data
+                        ),
+                        _ => continue,
+                    }
+                }
+            }
+        }
+    }
+
+    async fn read_message(&mut self) -> Result<WsMessage> {
+        read_message(&mut self.stream).await
+    }
+
+    async fn send(&mut self, value: serde_json::Value) -> Result<()> {
+        self.stream
+            .send(Message::Text(value.to_string().into()))
+            .await
+            .context("Failed to write to KuCoin WebSocket")
+    }
+}
+
+async fn read_message(
+    stream: &mut WebSocketStream<MaybeTlsStream<TcpStream>>,
+) -> Result<WsMessage> {
+    loop {
+        let frame = stream
+            .next()
+            .await
+            .context("KuCoin WebSocket closed")?
+            .context("Failed to read from KuCoin WebSocket")?;
+
+        match frame {
+            Message::Text(text) => {
+                return serde_json::from_str(text.as_str())
+                    .context("Failed to deserialize KuCoin WebSocket message");
+            }
+            Message::Ping(payload) => {
+                stream
+                    .send(Message::Pong(payload))
+                    .await
+                    .context("Failed to answer WebSocket ping")?;
+            }
+            Message::Close(frame) => anyhow::bail!("KuCoin WebSocket closed: {:?}", frame),
+            _ => continue,
+        }
+    }
+}
+
+fn next_id() -> String {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos().to_string())
+        .unwrap_or_default()
+}
@@ -0,0 +1,20 @@
+use crate::domain::entities::order_book::OrderBookUpdate;
+use crate::domain::entities::trade::Trade;
+use anyhow::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait Level2Stream: Send {
+    async fn next_update(&mut self) -> Result<OrderBookUpdate>;
+}
+
+#[async_trait]
+pub trait TradeStream: Send {
+    async fn next_trade(&mut self) -> Result<Trade>;
+}
+
+#[async_trait]
+pub trait MarketStreamClient: Send + Sync {
+    async fn connect_level2(&self, symbols: &[String]) -> Result<Box<dyn Level2Stream>>;
+    async fn connect_trades(&self, symbols: &[String]) -> Result<Box<dyn TradeStream>>;
+}
@@ -0,0 +1,5 @@
+pub mod kucoin_market_stream_client;
+pub mod kucoin_private_stream_client;
+pub mod kucoin_ws;
+pub mod market_stream_client;
+pub mod private_stream_client;
@@ -0,0 +1,19 @@
+use crate::domain::entities::balance_change::BalanceChange;
+use crate::domain::entities::order_event::OrderEvent;
+use anyhow::Result;
+use async_trait::async_trait;
+
+pub enum PrivateEvent {
+    Balance(BalanceChange),
+    Order(OrderEvent),
+}
+
+#[async_trait]
+pub trait PrivateStream: Send {
+    async fn next_event(&mut self) -> Result<PrivateEvent>;
+}
+
+#[async_trait]
+pub trait PrivateStreamClient: Send + Sync {
+    async fn connect(&self) -> Result<Box<dyn PrivateStream>>;
+}
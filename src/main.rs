@@ -1,17 +1,17 @@
-mod application;
-mod domain;
-mod infrastructure;
-
 use anyhow::Result;
 use dotenvy::dotenv;
 
-use application::scheduler::SchedulerService;
-use infrastructure::{
+use kcnmonitoring::application::scheduler::SchedulerService;
+use kcnmonitoring::infrastructure::{
     config::Config, db::postgres::connection::create_db_pool, di::container::Container,
-    logging::init_tracing,
+    http::query_api, logging::init_tracing,
 };
 
+const CRON_EVERY_15_SEC: &str = "*/15 * * * * *";
+const CRON_EVERY_MINUTE: &str = "0 * * * * *";
 const CRON_EVERY_5_MIN: &str = "0 */5 * * * *";
+const CRON_EVERY_HOUR: &str = "0 0 * * * *";
+const CRON_EVERY_DAY: &str = "0 0 0 * * *";
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -32,6 +32,22 @@ async fn main() -> Result<()> {
     let mut scheduler = SchedulerService::new().await?;
     tracing::info!("Scheduler created");
 
+    scheduler
+        .add_job(
+            CRON_EVERY_MINUTE,
+            "Exchange status",
+            container.job_factory.create_status_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_MINUTE,
+            "Futures exchange status",
+            container.job_factory.create_futures_status_job(),
+        )
+        .await?;
+
     scheduler
         .add_job(
             CRON_EVERY_5_MIN,
@@ -48,6 +64,14 @@ async fn main() -> Result<()> {
         )
         .await?;
 
+    scheduler
+        .add_job(
+            CRON_EVERY_HOUR,
+            "Markets fetcher",
+            container.job_factory.create_markets_job(),
+        )
+        .await?;
+
     scheduler
         .add_job(
             CRON_EVERY_5_MIN,
@@ -56,6 +80,230 @@ async fn main() -> Result<()> {
         )
         .await?;
 
+    scheduler
+        .add_job(
+            CRON_EVERY_15_SEC,
+            "Watchlist stats fetcher",
+            container.job_factory.create_watchlist_stats_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_MINUTE,
+            "Candles and indicators",
+            container.job_factory.create_candles_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_15_SEC,
+            "Trades sync",
+            container.job_factory.create_trades_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_MINUTE,
+            "Candle aggregation",
+            container.job_factory.create_candle_aggregation_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_HOUR,
+            "Candle consistency check",
+            container.job_factory.create_candle_consistency_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_HOUR,
+            "Liquidity scoring",
+            container.job_factory.create_liquidity_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_DAY,
+            "Volatility and correlation",
+            container.job_factory.create_risk_metrics_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_MINUTE,
+            "Triangular arbitrage scan",
+            container.job_factory.create_triangular_arbitrage_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_HOUR,
+            "Margin metadata fetcher",
+            container.job_factory.create_margin_metadata_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_HOUR,
+            "Lending rates fetcher",
+            container.job_factory.create_lending_rates_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_5_MIN,
+            "Fiat prices fetcher",
+            container.job_factory.create_fiat_prices_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_5_MIN,
+            "Deposits sync",
+            container.job_factory.create_deposits_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_5_MIN,
+            "Withdrawals sync",
+            container.job_factory.create_withdrawals_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_5_MIN,
+            "Fills sync",
+            container.job_factory.create_fills_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_5_MIN,
+            "Orders sync",
+            container.job_factory.create_orders_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_5_MIN,
+            "Ledger sync",
+            container.job_factory.create_ledger_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_5_MIN,
+            "Balance snapshot",
+            container.job_factory.create_balances_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_HOUR,
+            "Sub-account sync",
+            container.job_factory.create_sub_accounts_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_5_MIN,
+            "Portfolio snapshot",
+            container.job_factory.create_portfolio_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_5_MIN,
+            "Portfolio valuation",
+            container.job_factory.create_portfolio_valuation_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_HOUR,
+            "Balance reconciliation",
+            container.job_factory.create_reconciliation_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_HOUR,
+            "Futures contracts fetcher",
+            container.job_factory.create_futures_contracts_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_HOUR,
+            "Funding rates fetcher",
+            container.job_factory.create_funding_rates_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_5_MIN,
+            "Mark prices fetcher",
+            container.job_factory.create_mark_prices_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_5_MIN,
+            "Open interest fetcher",
+            container.job_factory.create_open_interest_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_5_MIN,
+            "Announcement sync",
+            container.job_factory.create_announcements_job(),
+        )
+        .await?;
+
+    scheduler
+        .add_job(
+            CRON_EVERY_MINUTE,
+            "Order book snapshot",
+            container.job_factory.create_order_book_snapshots_job(),
+        )
+        .await?;
+
+    tokio::spawn(container.job_factory.create_private_feed_task());
+    tokio::spawn(container.job_factory.create_order_book_task());
+    tokio::spawn(container.job_factory.create_trade_feed_task());
+    tokio::spawn(query_api::serve(
+        container.config.query_api_addr.clone(),
+        container.query_api_state.clone(),
+    ));
+
     scheduler.start().await?;
 
     tokio::signal::ctrl_c()